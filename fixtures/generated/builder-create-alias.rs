@@ -0,0 +1,64 @@
+/// Builds a `CreateAliasRequest`; start with `CreateAliasRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct CreateAliasRequestBuilder {
+    description: Option<Description>,
+    function_name: Option<FunctionName>,
+    function_version: Option<Version>,
+    name: Option<Alias>,
+}
+
+impl CreateAliasRequest {
+    pub fn builder() -> CreateAliasRequestBuilder {
+        CreateAliasRequestBuilder::default()
+    }
+}
+
+impl CreateAliasRequestBuilder {
+    /// Description of the alias.
+    pub fn description<V: Into<Description>>(mut self, value: V) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Name of the Lambda function for which you want to create an alias.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Lambda function version for which you are creating the alias.
+    pub fn function_version<V: Into<Version>>(mut self, value: V) -> Self {
+        self.function_version = Some(value.into());
+        self
+    }
+
+    /// Name for the alias your creating.
+    pub fn name<V: Into<Alias>>(mut self, value: V) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<CreateAliasRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if self.function_version.is_none() {
+            missing.push("FunctionVersion");
+        }
+        if self.name.is_none() {
+            missing.push("Name");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "CreateAliasRequest", members: missing });
+        }
+        Ok(CreateAliasRequest {
+            description: self.description,
+            function_name: self.function_name.unwrap(),
+            function_version: self.function_version.unwrap(),
+            name: self.name.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
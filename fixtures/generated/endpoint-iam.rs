@@ -0,0 +1,34 @@
+/// Returns the endpoint for AWS Identity and Access Management in `region`.
+pub fn endpoint(region: &str) -> ::runtime::endpoint::Url {
+    match region {
+        "aws-global" => ::runtime::endpoint::Url::new("https", "iam.amazonaws.com"),
+        "cn-north-1" => ::runtime::endpoint::Url::new("https", "iam.cn-north-1.amazonaws.com.cn"),
+        "eu-west-1" => ::runtime::endpoint::Url::new("https", "iam.amazonaws.com"),
+        "us-east-1" => ::runtime::endpoint::Url::new("https", "iam.amazonaws.com"),
+        "us-gov-west-1" => ::runtime::endpoint::Url::new("https", "iam.us-gov-west-1.amazonaws.com"),
+        "us-west-2" => ::runtime::endpoint::Url::new("https", "iam.amazonaws.com"),
+        _ if region.starts_with("us-gov-") => ::runtime::endpoint::Url::new("https", &format!("iam.{}.amazonaws.com", region)),
+        _ if region.starts_with("cn-") => ::runtime::endpoint::Url::new("https", &format!("iam.{}.amazonaws.com.cn", region)),
+        _ => ::runtime::endpoint::Url::new("https", "iam.amazonaws.com"),
+    }
+}
+
+/// Returns the region requests to AWS Identity and Access Management in `region` are signed for.
+pub fn signing_region(region: &str) -> &str {
+    match region {
+        "aws-global" => "us-east-1",
+        "cn-north-1" => region,
+        "eu-west-1" => "us-east-1",
+        "us-east-1" => region,
+        "us-gov-west-1" => region,
+        "us-west-2" => "us-east-1",
+        _ if region.starts_with("us-gov-") => region,
+        _ if region.starts_with("cn-") => region,
+        _ => "us-east-1",
+    }
+}
+
+/// Returns the service name requests to AWS Identity and Access Management in `region` are signed with.
+pub fn signing_name(_region: &str) -> &'static str {
+    "iam"
+}
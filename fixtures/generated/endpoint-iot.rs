@@ -0,0 +1,33 @@
+/// Returns the endpoint for AWS IoT in `region`.
+pub fn endpoint(region: &str) -> ::runtime::endpoint::Url {
+    match region {
+        "cn-north-1" => ::runtime::endpoint::Url::new("https", "iot.cn-north-1.amazonaws.com.cn"),
+        "eu-west-1" => ::runtime::endpoint::Url::new("https", "iot.eu-west-1.amazonaws.com"),
+        "fips-us-east-1" => ::runtime::endpoint::Url::new("https", "iot-fips.us-east-1.amazonaws.com"),
+        "us-east-1" => ::runtime::endpoint::Url::new("https", "iot.us-east-1.amazonaws.com"),
+        "us-gov-west-1" => ::runtime::endpoint::Url::new("https", "iot.us-gov-west-1.amazonaws.com"),
+        "us-west-2" => ::runtime::endpoint::Url::new("https", "iot.us-west-2.amazonaws.com"),
+        _ if region.starts_with("us-gov-") => ::runtime::endpoint::Url::new("https", &format!("iot.{}.amazonaws.com", region)),
+        _ if region.starts_with("cn-") => ::runtime::endpoint::Url::new("https", &format!("iot.{}.amazonaws.com.cn", region)),
+        _ => ::runtime::endpoint::Url::new("https", &format!("iot.{}.amazonaws.com", region)),
+    }
+}
+
+/// Returns the region requests to AWS IoT in `region` are signed for.
+pub fn signing_region(region: &str) -> &str {
+    match region {
+        "fips-us-east-1" => "us-east-1",
+        _ => region,
+    }
+}
+
+/// Returns the service name requests to AWS IoT in `region` are signed with.
+pub fn signing_name(region: &str) -> &'static str {
+    match region {
+        "cn-north-1" => "iot",
+        "us-gov-west-1" => "iot",
+        _ if region.starts_with("us-gov-") => "iot",
+        _ if region.starts_with("cn-") => "iot",
+        _ => "execute-api",
+    }
+}
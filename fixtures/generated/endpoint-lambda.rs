@@ -0,0 +1,23 @@
+/// Returns the endpoint for AWS Lambda in `region`.
+pub fn endpoint(region: &str) -> ::runtime::endpoint::Url {
+    match region {
+        "cn-north-1" => ::runtime::endpoint::Url::new("https", "lambda.cn-north-1.amazonaws.com.cn"),
+        "eu-west-1" => ::runtime::endpoint::Url::new("https", "lambda.eu-west-1.amazonaws.com"),
+        "us-east-1" => ::runtime::endpoint::Url::new("https", "lambda.us-east-1.amazonaws.com"),
+        "us-gov-west-1" => ::runtime::endpoint::Url::new("https", "lambda.us-gov-west-1.amazonaws.com"),
+        "us-west-2" => ::runtime::endpoint::Url::new("https", "lambda.us-west-2.amazonaws.com"),
+        _ if region.starts_with("us-gov-") => ::runtime::endpoint::Url::new("https", &format!("lambda.{}.amazonaws.com", region)),
+        _ if region.starts_with("cn-") => ::runtime::endpoint::Url::new("https", &format!("lambda.{}.amazonaws.com.cn", region)),
+        _ => ::runtime::endpoint::Url::new("https", &format!("lambda.{}.amazonaws.com", region)),
+    }
+}
+
+/// Returns the region requests to AWS Lambda in `region` are signed for.
+pub fn signing_region(region: &str) -> &str {
+    region
+}
+
+/// Returns the service name requests to AWS Lambda in `region` are signed with.
+pub fn signing_name(_region: &str) -> &'static str {
+    "lambda"
+}
@@ -0,0 +1,21 @@
+    /// To create a function: The following example creates a function with a deployment package in
+    /// Amazon S3.
+    ///
+    /// ```rust,ignore
+    /// let output = client.create_function(&CreateFunctionRequest {
+    ///     code: FunctionCode {
+    ///         s3_bucket: Some("my-bucket".to_string()),
+    ///         s3_key: Some("function.zip".to_string()),
+    ///         ..Default::default()
+    ///     },
+    ///     description: Some("Process image objects from Amazon S3.".to_string()),
+    ///     function_name: "my-function".to_string(),
+    ///     handler: "index.handler".to_string(),
+    ///     memory_size: Some(256),
+    ///     publish: Some(true),
+    ///     role: "arn:aws:iam::123456789012:role/lambda-role".to_string(),
+    ///     runtime: Some(Runtime::Nodejs),
+    ///     timeout: Some(15),
+    ///     ..Default::default()
+    /// }).unwrap();
+    /// ```
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod examples {
+    use super::*;
+    use serde::{Serialize, Deserialize};
+    use serde_json::{self, Value};
+
+    fn without_nulls(value: Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(map.into_iter()
+                .filter(|&(_, ref v)| *v != Value::Null)
+                .map(|(k, v)| (k, without_nulls(v)))
+                .collect()),
+            Value::Array(array) => Value::Array(array.into_iter().map(without_nulls).collect()),
+            value => value,
+        }
+    }
+
+    fn round_trip<T: Serialize + Deserialize>(json: &str) {
+        let expected: Value = serde_json::from_str(json).unwrap();
+        let value: T = serde_json::from_str(json).unwrap();
+        assert_eq!(without_nulls(serde_json::to_value(&value)), expected);
+    }
+
+    #[test]
+    fn add_permission_add_permission_1474651469455() {
+        round_trip::<AddPermissionRequest>(r#"{"Action":"lambda:InvokeFunction","FunctionName":"MyFunction","Principal":"s3.amazonaws.com","SourceAccount":"123456789012","SourceArn":"arn:aws:s3:::examplebucket/*","StatementId":"ID-1"}"#);
+        round_trip::<AddPermissionResponse>(r#"{"Statement":"ID-1"}"#);
+    }
+
+    #[test]
+    fn create_function_to_create_a_function_1586492061186() {
+        round_trip::<CreateFunctionRequest>(r#"{"Code":{"S3Bucket":"my-bucket","S3Key":"function.zip"},"Description":"Process image objects from Amazon S3.","FunctionName":"my-function","Handler":"index.handler","MemorySize":256,"Publish":true,"Role":"arn:aws:iam::123456789012:role/lambda-role","Runtime":"nodejs","Timeout":15}"#);
+        round_trip::<FunctionConfiguration>(r#"{"CodeSha256":"YFgDgEKG3ugvF1+pX64gV6tu9qNuIYNUdgJm8nCxsSU=","CodeSize":5797206,"FunctionName":"my-function","Handler":"index.handler","MemorySize":256,"Runtime":"nodejs","Timeout":15,"Version":"1"}"#);
+    }
+
+    #[test]
+    fn list_functions_to_get_a_list_of_lambda_functions_1481650507425() {
+        round_trip::<ListFunctionsRequest>(r#"{"MaxItems":2}"#);
+        round_trip::<ListFunctionsResponse>(r#"{"Functions":[{"FunctionName":"helloworld","MemorySize":128,"Runtime":"python2.7"}],"NextMarker":"ZXhhbXBsZQ=="}"#);
+    }
+}
@@ -1,16 +1,23 @@
-#[derive(Debug)]
+#[derive(Debug, Default, Deserialize)]
 /// The AWS Lambda service encountered an internal error.
 pub struct ServiceException {
-    pub Type: String,
-    pub Message: String,
+    #[serde(rename = "Type")]
+    pub type_: StringType,
+    #[serde(rename = "Message")]
+    pub message: StringType,
+    #[doc(hidden)]
+    #[serde(default)]
+    pub __non_exhaustive: (),
 }
 
-impl ::std::error::Error for ServiceException {
-    pub fn description(&self) -> &str {
-        &format!("ServiceException: {} {}", self.Type, self.Message);
+impl ::std::fmt::Display for ServiceException {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "ServiceException: {:?} {:?}", self.type_, self.message)
     }
+}
 
-    pub fn cause(&self) -> Option<&Error> {
-        None
+impl ::std::error::Error for ServiceException {
+    fn description(&self) -> &str {
+        "ServiceException"
     }
 }
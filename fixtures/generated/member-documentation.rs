@@ -0,0 +1,6 @@
+    /// You can specify this optional query parameter to specify function version or alias name. For
+    /// example, if you specify function version 2 as the qualifier:
+    ///
+    /// `arn:aws:lambda:aws-region:acct-id:function:function-name:2`
+    #[serde(rename = "Qualifier")]
+    pub qualifier: Option<Qualifier>,
@@ -0,0 +1,237 @@
+//! The AWS Lambda client.
+
+use model::*;
+
+/// Every operation AWS Lambda supports.
+pub trait Lambda {
+    type Error;
+
+    /// Adds a permission to the resource policy associated with the specified AWS Lambda function.
+    /// You use resource policies to grant permissions to event sources that use "push" model. In
+    /// "push" model, event sources (such as Amazon S3 and custom applications) invoke your Lambda
+    /// function. Each permission you add to the resource policy allows an event source, permission
+    /// to invoke the Lambda function.
+    ///
+    /// For information about the push model, see [AWS Lambda: How it
+    /// Works](http://docs.aws.amazon.com/lambda/latest/dg/lambda-introduction.html).
+    ///
+    /// If you are using versioning feature (see [AWS Lambda Function Versioning and
+    /// Aliases](http://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases-v2.html)), a Lambda
+    /// function can have multiple ARNs that can be used to invoke the function. Note that, each
+    /// permission you add to resource policy using this API is specific to an ARN, specified using
+    /// the `Qualifier` parameter
+    ///
+    /// This operation requires permission for the `lambda:AddPermission` action.
+    fn add_permission(&self, input: &AddPermissionRequest) -> Result<AddPermissionResponse, Self::Error>;
+
+    /// Creates an alias to the specified Lambda function version. For more information, see
+    /// [Introduction to AWS Lambda
+    /// Aliases](http://docs.aws.amazon.com/lambda/latest/dg/versioning-v2-intro-aliases.html)
+    ///
+    /// This requires permission for the lambda:CreateAlias action.
+    fn create_alias(&self, input: &CreateAliasRequest) -> Result<AliasConfiguration, Self::Error>;
+
+    /// Identifies a stream as an event source for a Lambda function. It can be either an Amazon
+    /// Kinesis stream or an Amazon DynamoDB stream. AWS Lambda invokes the specified function when
+    /// records are posted to the stream.
+    ///
+    /// This is the pull model, where AWS Lambda invokes the function. For more information, go to
+    /// [AWS Lambda: How it
+    /// Works](http://docs.aws.amazon.com/lambda/latest/dg/lambda-introduction.html) in the *AWS
+    /// Lambda Developer Guide*.
+    ///
+    /// This association between an Amazon Kinesis stream and a Lambda function is called the event
+    /// source mapping. You provide the configuration information (for example, which stream to read
+    /// from and which Lambda function to invoke) for the event source mapping in the request body.
+    ///
+    /// Each event source, such as an Amazon Kinesis or a DynamoDB stream, can be associated with
+    /// multiple AWS Lambda function. A given Lambda function can be associated with multiple AWS
+    /// event sources.
+    ///
+    /// This operation requires permission for the `lambda:CreateEventSourceMapping` action.
+    fn create_event_source_mapping(&self, input: &CreateEventSourceMappingRequest) -> Result<EventSourceMappingConfiguration, Self::Error>;
+
+    /// Creates a new Lambda function. The function metadata is created from the request parameters,
+    /// and the code for the function is provided by a .zip file in the request body. If the
+    /// function name already exists, the operation will fail. Note that the function name is
+    /// case-sensitive.
+    ///
+    /// This operation requires permission for the `lambda:CreateFunction` action.
+    fn create_function(&self, input: &CreateFunctionRequest) -> Result<FunctionConfiguration, Self::Error>;
+
+    /// Deletes specified Lambda function alias. For more information, see [Introduction to AWS
+    /// Lambda
+    /// Aliases](http://docs.aws.amazon.com/lambda/latest/dg/versioning-v2-intro-aliases.html)
+    ///
+    /// This requires permission for the lambda:DeleteAlias action.
+    fn delete_alias(&self, input: &DeleteAliasRequest) -> Result<(), Self::Error>;
+
+    /// Removes an event source mapping. This means AWS Lambda will no longer invoke the function
+    /// for events in the associated source.
+    ///
+    /// This operation requires permission for the `lambda:DeleteEventSourceMapping` action.
+    fn delete_event_source_mapping(&self, input: &DeleteEventSourceMappingRequest) -> Result<EventSourceMappingConfiguration, Self::Error>;
+
+    /// Deletes the specified Lambda function code and configuration.
+    ///
+    /// If you don't specify a function version, AWS Lambda will delete the function, including all
+    /// its versions, and any aliases pointing to the function versions.
+    ///
+    /// When you delete a function the associated resource policy is also deleted. You will need to
+    /// delete the event source mappings explicitly.
+    ///
+    /// For information about function versioning, see [AWS Lambda Function Versioning and
+    /// Aliases](http://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases-v2.html).
+    ///
+    /// This operation requires permission for the `lambda:DeleteFunction` action.
+    fn delete_function(&self, input: &DeleteFunctionRequest) -> Result<(), Self::Error>;
+
+    /// Returns the specified alias information such as the alias ARN, description, and function
+    /// version it is pointing to. For more information, see [Introduction to AWS Lambda
+    /// Aliases](http://docs.aws.amazon.com/lambda/latest/dg/versioning-v2-intro-aliases.html)
+    ///
+    /// This requires permission for the lambda:GetAlias action.
+    fn get_alias(&self, input: &GetAliasRequest) -> Result<AliasConfiguration, Self::Error>;
+
+    /// Returns configuration information for the specified event source mapping (see
+    /// CreateEventSourceMapping).
+    ///
+    /// This operation requires permission for the `lambda:GetEventSourceMapping` action.
+    fn get_event_source_mapping(&self, input: &GetEventSourceMappingRequest) -> Result<EventSourceMappingConfiguration, Self::Error>;
+
+    /// Returns the configuration information of the Lambda function and a presigned URL link to the
+    /// .zip file you uploaded with CreateFunction so you can download the .zip file. Note that the
+    /// URL is valid for up to 10 minutes. The configuration information is the same information you
+    /// provided as parameters when uploading the function.
+    ///
+    /// Using the optional `Qualifier` parameter, you can specify a specific function version for
+    /// which you want this information. If you don't specify this parameter, the API uses
+    /// unqualified function ARN which return information about the $LATEST version of the Lambda
+    /// function. For more information, see [AWS Lambda Function Versioning and
+    /// Aliases](http://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases-v2.html).
+    ///
+    /// This operation requires permission for the `lambda:GetFunction` action.
+    fn get_function(&self, input: &GetFunctionRequest) -> Result<GetFunctionResponse, Self::Error>;
+
+    /// Returns the configuration information of the Lambda function. This the same information you
+    /// provided as parameters when uploading the function by using CreateFunction.
+    ///
+    /// You can use the optional `Qualifier` parameter to retrieve configuration information for a
+    /// specific Lambda function version. If you don't provide it, the API returns information about
+    /// the $LATEST version of the function. For more information about versioning, see [AWS Lambda
+    /// Function Versioning and
+    /// Aliases](http://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases-v2.html).
+    ///
+    /// This operation requires permission for the `lambda:GetFunctionConfiguration` operation.
+    fn get_function_configuration(&self, input: &GetFunctionConfigurationRequest) -> Result<FunctionConfiguration, Self::Error>;
+
+    /// Returns the resource policy, containing a list of permissions that apply to a specific to an
+    /// ARN that you specify via the `Qualifier` paramter.
+    ///
+    /// For informration about adding permissions, see AddPermission.
+    ///
+    /// You need permission for the `lambda:GetPolicy action.`
+    fn get_policy(&self, input: &GetPolicyRequest) -> Result<GetPolicyResponse, Self::Error>;
+
+    /// Invokes a specific Lambda function version.
+    ///
+    /// If you don't provide the `Qualifier` parameter, it uses the unqualified function ARN which
+    /// results in invocation of the $LATEST version of the Lambda function (when you create a
+    /// Lambda function, the $LATEST is the version). The AWS Lambda versioning and aliases feature
+    /// allows you to publish multiple versions of a Lambda function and also create aliases for
+    /// each function version. So each your Lambda function version can be invoked using multiple
+    /// ARNs. For more information, see [AWS Lambda Function Versioning and
+    /// Aliases](http://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases-v2.html). Using the
+    /// `Qualifier` parameter, you can specify a function version or alias name to invoke specific
+    /// function version. If you specify function version, the API uses the qualified function ARN
+    /// to invoke a specific function version. If you specify alias name, the API uses the alias ARN
+    /// to invoke the function version to which the alias points.
+    ///
+    /// This operation requires permission for the `lambda:InvokeFunction` action.
+    fn invoke(&self, input: &InvocationRequest) -> Result<InvocationResponse, Self::Error>;
+
+    /// **Important:** This API is deprecated. We recommend you use `Invoke` API (see Invoke).
+    ///
+    /// Submits an invocation request to AWS Lambda. Upon receiving the request, Lambda executes the
+    /// specified function asynchronously. To see the logs generated by the Lambda function
+    /// execution, see the CloudWatch logs console.
+    ///
+    /// This operation requires permission for the `lambda:InvokeFunction` action.
+    #[deprecated(note = "deprecated in the service model")]
+    #[allow(deprecated)]
+    fn invoke_async(&self, input: &InvokeAsyncRequest) -> Result<InvokeAsyncResponse, Self::Error>;
+
+    /// Returns list of aliases created for a Lambda function. For each alias, the response includes
+    /// information such as the alias ARN, description, alias name, and the function version to
+    /// which it points. For more information, see [Introduction to AWS Lambda
+    /// Aliases](http://docs.aws.amazon.com/lambda/latest/dg/versioning-v2-intro-aliases.html)
+    ///
+    /// This requires permission for the lambda:ListAliases action.
+    fn list_aliases(&self, input: &ListAliasesRequest) -> Result<ListAliasesResponse, Self::Error>;
+
+    /// Returns a list of event source mappings you created using the `CreateEventSourceMapping`
+    /// (see CreateEventSourceMapping), where you identify a stream as an event source. This list
+    /// does not include Amazon S3 event sources.
+    ///
+    /// For each mapping, the API returns configuration information. You can optionally specify
+    /// filters to retrieve specific event source mappings.
+    ///
+    /// This operation requires permission for the `lambda:ListEventSourceMappings` action.
+    fn list_event_source_mappings(&self, input: &ListEventSourceMappingsRequest) -> Result<ListEventSourceMappingsResponse, Self::Error>;
+
+    /// Returns a list of your Lambda functions. For each function, the response includes the
+    /// function configuration information. You must use GetFunction to retrieve the code for your
+    /// function.
+    ///
+    /// This operation requires permission for the `lambda:ListFunctions` action.
+    fn list_functions(&self, input: &ListFunctionsRequest) -> Result<ListFunctionsResponse, Self::Error>;
+
+    /// List all versions of a function.
+    fn list_versions_by_function(&self, input: &ListVersionsByFunctionRequest) -> Result<ListVersionsByFunctionResponse, Self::Error>;
+
+    /// Publishes a version of your function from the current snapshot of HEAD. That is, AWS Lambda
+    /// takes a snapshot of the function code and configuration information from HEAD and publishes
+    /// a new version. The code and `handler` of this specific Lambda function version cannot be
+    /// modified after publication, but you can modify the configuration information.
+    fn publish_version(&self, input: &PublishVersionRequest) -> Result<FunctionConfiguration, Self::Error>;
+
+    /// You can remove individual permissions from an resource policy associated with a Lambda
+    /// function by providing a statement ID that you provided when you addded the permission. The
+    /// API removes corresponding permission that is associated with the specific ARN identified by
+    /// the `Qualifier` parameter.
+    ///
+    /// Note that removal of a permission will cause an active event source to lose permission to
+    /// the function.
+    ///
+    /// You need permission for the `lambda:RemovePermission` action.
+    fn remove_permission(&self, input: &RemovePermissionRequest) -> Result<(), Self::Error>;
+
+    /// Using this API you can update function version to which the alias points to and alias
+    /// description. For more information, see [Introduction to AWS Lambda
+    /// Aliases](http://docs.aws.amazon.com/lambda/latest/dg/versioning-v2-intro-aliases.html)
+    ///
+    /// This requires permission for the lambda:UpdateAlias action.
+    fn update_alias(&self, input: &UpdateAliasRequest) -> Result<AliasConfiguration, Self::Error>;
+
+    /// You can update an event source mapping. This is useful if you want to change the parameters
+    /// of the existing mapping without losing your position in the stream. You can change which
+    /// function will receive the stream records, but to change the stream itself, you must create a
+    /// new mapping.
+    ///
+    /// This operation requires permission for the `lambda:UpdateEventSourceMapping` action.
+    fn update_event_source_mapping(&self, input: &UpdateEventSourceMappingRequest) -> Result<EventSourceMappingConfiguration, Self::Error>;
+
+    /// Updates the code for the specified Lambda function. This operation must only be used on an
+    /// existing Lambda function and cannot be used to update the function configuration.
+    ///
+    /// This operation requires permission for the `lambda:UpdateFunctionCode` action.
+    fn update_function_code(&self, input: &UpdateFunctionCodeRequest) -> Result<FunctionConfiguration, Self::Error>;
+
+    /// Updates the configuration parameters for the specified Lambda function by using the values
+    /// provided in the request. You provide only the parameters you want to change. This operation
+    /// must only be used on an existing Lambda function and cannot be used to update the function's
+    /// code.
+    ///
+    /// This operation requires permission for the `lambda:UpdateFunctionConfiguration` action.
+    fn update_function_configuration(&self, input: &UpdateFunctionConfigurationRequest) -> Result<FunctionConfiguration, Self::Error>;
+}
@@ -0,0 +1,233 @@
+//! The exceptions AWS Lambda reports.
+
+use model::*;
+
+#[derive(Debug, Default, Deserialize)]
+/// You have exceeded your maximum total code size per account.
+/// [Limits](http://docs.aws.amazon.com/lambda/latest/dg/limits.html)
+pub struct CodeStorageExceededException {
+    #[serde(rename = "Type")]
+    pub type_: Option<StringType>,
+    pub message: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(default)]
+    pub __non_exhaustive: (),
+}
+
+impl ::std::fmt::Display for CodeStorageExceededException {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "CodeStorageExceededException: {:?} {:?}", self.type_, self.message)
+    }
+}
+
+impl ::std::error::Error for CodeStorageExceededException {
+    fn description(&self) -> &str {
+        "CodeStorageExceededException"
+    }
+}
+#[derive(Debug, Default, Deserialize)]
+/// One of the parameters in the request is invalid. For example, if you provided an IAM role for
+/// AWS Lambda to assume in the `CreateFunction` or the `UpdateFunctionConfiguration` API, that AWS
+/// Lambda is unable to assume you will get this exception.
+pub struct InvalidParameterValueException {
+    #[serde(rename = "Type")]
+    pub type_: Option<StringType>,
+    pub message: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(default)]
+    pub __non_exhaustive: (),
+}
+
+impl ::std::fmt::Display for InvalidParameterValueException {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "InvalidParameterValueException: {:?} {:?}", self.type_, self.message)
+    }
+}
+
+impl ::std::error::Error for InvalidParameterValueException {
+    fn description(&self) -> &str {
+        "InvalidParameterValueException"
+    }
+}
+#[derive(Debug, Default, Deserialize)]
+/// The request body could not be parsed as JSON.
+pub struct InvalidRequestContentException {
+    #[serde(rename = "Type")]
+    pub type_: Option<StringType>,
+    pub message: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(default)]
+    pub __non_exhaustive: (),
+}
+
+impl ::std::fmt::Display for InvalidRequestContentException {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "InvalidRequestContentException: {:?} {:?}", self.type_, self.message)
+    }
+}
+
+impl ::std::error::Error for InvalidRequestContentException {
+    fn description(&self) -> &str {
+        "InvalidRequestContentException"
+    }
+}
+#[derive(Debug, Default, Deserialize)]
+/// Lambda function access policy is limited to 20 KB.
+pub struct PolicyLengthExceededException {
+    #[serde(rename = "Type")]
+    pub type_: Option<StringType>,
+    pub message: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(default)]
+    pub __non_exhaustive: (),
+}
+
+impl ::std::fmt::Display for PolicyLengthExceededException {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "PolicyLengthExceededException: {:?} {:?}", self.type_, self.message)
+    }
+}
+
+impl ::std::error::Error for PolicyLengthExceededException {
+    fn description(&self) -> &str {
+        "PolicyLengthExceededException"
+    }
+}
+#[derive(Debug, Default, Deserialize)]
+/// The request payload exceeded the `Invoke` request body JSON input limit. For more information,
+/// see [Limits](http://docs.aws.amazon.com/lambda/latest/dg/limits.html)
+pub struct RequestTooLargeException {
+    #[serde(rename = "Type")]
+    pub type_: Option<StringType>,
+    pub message: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(default)]
+    pub __non_exhaustive: (),
+}
+
+impl ::std::fmt::Display for RequestTooLargeException {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "RequestTooLargeException: {:?} {:?}", self.type_, self.message)
+    }
+}
+
+impl ::std::error::Error for RequestTooLargeException {
+    fn description(&self) -> &str {
+        "RequestTooLargeException"
+    }
+}
+#[derive(Debug, Default, Deserialize)]
+/// The resource already exists.
+pub struct ResourceConflictException {
+    #[serde(rename = "Type")]
+    pub type_: Option<StringType>,
+    pub message: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(default)]
+    pub __non_exhaustive: (),
+}
+
+impl ::std::fmt::Display for ResourceConflictException {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "ResourceConflictException: {:?} {:?}", self.type_, self.message)
+    }
+}
+
+impl ::std::error::Error for ResourceConflictException {
+    fn description(&self) -> &str {
+        "ResourceConflictException"
+    }
+}
+#[derive(Debug, Default, Deserialize)]
+/// The resource (for example, a Lambda function or access policy statement) specified in the
+/// request does not exist.
+pub struct ResourceNotFoundException {
+    #[serde(rename = "Message")]
+    pub message: Option<StringType>,
+    #[serde(rename = "Type")]
+    pub type_: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(default)]
+    pub __non_exhaustive: (),
+}
+
+impl ::std::fmt::Display for ResourceNotFoundException {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "ResourceNotFoundException: {:?} {:?}", self.message, self.type_)
+    }
+}
+
+impl ::std::error::Error for ResourceNotFoundException {
+    fn description(&self) -> &str {
+        "ResourceNotFoundException"
+    }
+}
+#[derive(Debug, Default, Deserialize)]
+/// The AWS Lambda service encountered an internal error.
+pub struct ServiceException {
+    #[serde(rename = "Message")]
+    pub message: Option<StringType>,
+    #[serde(rename = "Type")]
+    pub type_: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(default)]
+    pub __non_exhaustive: (),
+}
+
+impl ::std::fmt::Display for ServiceException {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "ServiceException: {:?} {:?}", self.message, self.type_)
+    }
+}
+
+impl ::std::error::Error for ServiceException {
+    fn description(&self) -> &str {
+        "ServiceException"
+    }
+}
+#[derive(Debug, Default, Deserialize)]
+pub struct TooManyRequestsException {
+    #[serde(rename = "Type")]
+    pub type_: Option<StringType>,
+    pub message: Option<StringType>,
+    /// The number of seconds the caller should wait before retrying.
+    #[serde(rename = "retryAfterSeconds")]
+    pub retry_after_seconds: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(default)]
+    pub __non_exhaustive: (),
+}
+
+impl ::std::fmt::Display for TooManyRequestsException {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "TooManyRequestsException: {:?} {:?} {:?}", self.type_, self.message, self.retry_after_seconds)
+    }
+}
+
+impl ::std::error::Error for TooManyRequestsException {
+    fn description(&self) -> &str {
+        "TooManyRequestsException"
+    }
+}
+#[derive(Debug, Default, Deserialize)]
+/// The content type of the `Invoke` request body is not JSON.
+pub struct UnsupportedMediaTypeException {
+    #[serde(rename = "Type")]
+    pub type_: Option<StringType>,
+    pub message: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(default)]
+    pub __non_exhaustive: (),
+}
+
+impl ::std::fmt::Display for UnsupportedMediaTypeException {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "UnsupportedMediaTypeException: {:?} {:?}", self.type_, self.message)
+    }
+}
+
+impl ::std::error::Error for UnsupportedMediaTypeException {
+    fn description(&self) -> &str {
+        "UnsupportedMediaTypeException"
+    }
+}
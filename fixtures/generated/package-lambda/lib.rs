@@ -0,0 +1,30 @@
+//! # AWS Lambda
+//!
+//! **Overview**
+//!
+//! This is the *AWS Lambda API Reference*. The AWS Lambda Developer Guide provides additional
+//! information. For the service overview, go to [What is AWS
+//! Lambda](http://docs.aws.amazon.com/lambda/latest/dg/welcome.html), and for information about how
+//! the service works, go to [AWS Lambda: How it
+//! Works](http://docs.aws.amazon.com/lambda/latest/dg/lambda-introduction.html) in the *AWS Lambda
+//! Developer Guide*.
+
+#![feature(custom_derive, plugin)]
+#![plugin(serde_macros)]
+
+extern crate aws_service_parser;
+extern crate serde;
+#[cfg(test)]
+extern crate serde_json;
+
+use aws_service_parser::runtime;
+
+pub mod model;
+pub mod error;
+pub mod protocol;
+pub mod client;
+
+pub use model::*;
+pub use error::*;
+pub use protocol::*;
+pub use client::*;
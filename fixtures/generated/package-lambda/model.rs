@@ -0,0 +1,3244 @@
+//! The shapes AWS Lambda operations take and return.
+
+pub type Action = String;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AddPermissionRequest {
+    /// The AWS Lambda action you want to allow in this statement. Each Lambda action is a string
+    /// starting with "lambda:" followed by the API name (see Operations). For example,
+    /// "lambda:CreateFunction". You can use wildcard ("lambda:\*") to grant permission for all AWS
+    /// Lambda actions.
+    #[serde(rename = "Action")]
+    pub action: Action,
+    /// Name of the Lambda function whose resource policy you are updating by adding a new
+    /// permission.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// The principal who is getting this permission. It can be Amazon S3 service Principal
+    /// ("s3.amazonaws.com") if you want Amazon S3 to invoke the function, an AWS account ID if you
+    /// are granting cross-account permission, or any valid AWS service principal such as
+    /// "sns.amazonaws.com". For example, you might want to allow a custom application in another
+    /// AWS account to push events to AWS Lambda by invoking your function.
+    #[serde(rename = "Principal")]
+    pub principal: Principal,
+    /// You can specify this optional query parameter to specify function version or alias name. The
+    /// permission will then apply to the specific qualified ARN. For example, if you specify
+    /// function version 2 as the qualifier, then permission applies only when request is made using
+    /// qualified function ARN:
+    ///
+    /// `arn:aws:lambda:aws-region:acct-id:function:function-name:2`
+    ///
+    /// If you specify alias name, for example "PROD", then the permission is valid only for
+    /// requests made using the alias ARN:
+    ///
+    /// `arn:aws:lambda:aws-region:acct-id:function:function-name:PROD`
+    ///
+    /// If the qualifier is not specified, the permission is valid only when requests is made using
+    /// unqualified function ARN.
+    ///
+    /// `arn:aws:lambda:aws-region:acct-id:function:function-name`
+    #[serde(rename = "Qualifier")]
+    pub qualifier: Option<Qualifier>,
+    /// The AWS account ID (without a hyphen) of the source owner. For example, if the `SourceArn`
+    /// identifies a bucket, then this is the bucket owner's account ID. You can use this additional
+    /// condition to ensure the bucket you specify is owned by a specific account (it is possible
+    /// the bucket owner deleted the bucket and some other AWS account created the bucket). You can
+    /// also use this condition to specify all sources (that is, you don't specify the `SourceArn`)
+    /// owned by a specific account.
+    #[serde(rename = "SourceAccount")]
+    pub source_account: Option<SourceOwner>,
+    /// This is optional; however, when granting Amazon S3 permission to invoke your function, you
+    /// should specify this field with the bucket Amazon Resource Name (ARN) as its value. This
+    /// ensures that only events generated from the specified bucket can invoke the function.
+    ///
+    /// **Important:** If you add a permission for the Amazon S3 principal without providing the
+    /// source ARN, any AWS account that creates a mapping to your function ARN can send events to
+    /// invoke your Lambda function from Amazon S3.
+    #[serde(rename = "SourceArn")]
+    pub source_arn: Option<Arn>,
+    /// A unique statement identifier.
+    #[serde(rename = "StatementId")]
+    pub statement_id: StatementId,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AddPermissionResponse {
+    /// The permission statement you specified in the request. The response returns the same as a
+    /// string using "\\" as an escape character in the JSON.
+    #[serde(rename = "Statement")]
+    pub statement: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type Alias = String;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AliasConfiguration {
+    /// Lambda function ARN that is qualified using alias name as the suffix. For example, if you
+    /// create an alias "BETA" pointing to a helloworld function version, the ARN is
+    /// `arn:aws:lambda:aws-regions:acct-id:function:helloworld:BETA`.
+    #[serde(rename = "AliasArn")]
+    pub alias_arn: Option<FunctionArn>,
+    /// Alias description.
+    #[serde(rename = "Description")]
+    pub description: Option<Description>,
+    /// Function version to which the alias points.
+    #[serde(rename = "FunctionVersion")]
+    pub function_version: Option<Version>,
+    /// Alias name.
+    #[serde(rename = "Name")]
+    pub name: Option<Alias>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type AliasList = Vec<AliasConfiguration>;
+pub type Arn = String;
+pub type BatchSize = i32;
+pub type Blob = Vec<u8>;
+pub type BlobStream = ::runtime::stream::ByteStream;
+pub type Boolean = bool;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CreateAliasRequest {
+    /// Description of the alias.
+    #[serde(rename = "Description")]
+    pub description: Option<Description>,
+    /// Name of the Lambda function for which you want to create an alias.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// Lambda function version for which you are creating the alias.
+    #[serde(rename = "FunctionVersion")]
+    pub function_version: Version,
+    /// Name for the alias your creating.
+    #[serde(rename = "Name")]
+    pub name: Alias,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CreateEventSourceMappingRequest {
+    /// The largest number of records that AWS Lambda will retrieve from your event source at the
+    /// time of invoking your function. Your function receives an event with all the retrieved
+    /// records. The default is 100 records.
+    #[serde(rename = "BatchSize")]
+    pub batch_size: Option<BatchSize>,
+    /// Indicates whether AWS Lambda should begin polling the event source. By default, `Enabled` is
+    /// true.
+    #[serde(rename = "Enabled")]
+    pub enabled: Option<Enabled>,
+    /// The Amazon Resource Name (ARN) of the Amazon Kinesis or the Amazon DynamoDB stream that is
+    /// the event source. Any record added to this stream could cause AWS Lambda to invoke your
+    /// Lambda function, it depends on the `BatchSize`. AWS Lambda POSTs the Amazon Kinesis event,
+    /// containing records, to your Lambda function as JSON.
+    #[serde(rename = "EventSourceArn")]
+    pub event_source_arn: Arn,
+    /// The Lambda function to invoke when AWS Lambda detects an event on the stream.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// The position in the stream where AWS Lambda should start reading. For more information, go
+    /// to
+    /// [ShardIteratorType](http://docs.aws.amazon.com/kinesis/latest/APIReference/API_GetShardIterator.html#Kinesis-GetShardIterator-request-ShardIteratorType)
+    /// in the *Amazon Kinesis API Reference*.
+    #[serde(rename = "StartingPosition")]
+    pub starting_position: Option<EventSourcePosition>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CreateFunctionRequest {
+    /// The code for the Lambda function.
+    #[serde(rename = "Code")]
+    pub code: FunctionCode,
+    /// A short, user-defined function description. Lambda does not use this value. Assign a
+    /// meaningful description as you see fit.
+    #[serde(rename = "Description")]
+    pub description: Option<Description>,
+    /// The name you want to assign to the function you are uploading. You can specify an
+    /// unqualified function name (for example, "Thumbnail") or you can specify Amazon Resource Name
+    /// (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length. The function names appear in the console and are returned
+    /// in the ListFunctions API. Function names are used to specify functions to other AWS Lambda
+    /// APIs, such as Invoke.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// The function within your code that Lambda calls to begin execution. For Node.js, it is the
+    /// *module-name*.*export* value in your function. For Java, it can be
+    /// `package.class-name::handler` or `package.class-name`. For more information, see [Lambda
+    /// Function Handler
+    /// (Java)](http://docs.aws.amazon.com/lambda/latest/dg/java-programming-model-handler-types.html).
+    #[serde(rename = "Handler")]
+    pub handler: Handler,
+    /// The amount of memory, in MB, your Lambda function is given. Lambda uses this memory size to
+    /// infer the amount of CPU and memory allocated to your function. Your function use-case
+    /// determines your CPU and memory requirements. For example, a database operation might need
+    /// less memory compared to an image processing function. The default value is 128 MB. The value
+    /// must be a multiple of 64 MB.
+    #[serde(rename = "MemorySize")]
+    pub memory_size: Option<MemorySize>,
+    /// This boolean parameter can be used to request AWS Lambda to create the Lambda function and
+    /// publish a version as an atomic operation.
+    #[serde(rename = "Publish")]
+    pub publish: Option<Boolean>,
+    /// The Amazon Resource Name (ARN) of the IAM role that Lambda assumes when it executes your
+    /// function to access any other Amazon Web Services (AWS) resources. For more information, see
+    /// [AWS Lambda: How it
+    /// Works](http://docs.aws.amazon.com/lambda/latest/dg/lambda-introduction.html)
+    #[serde(rename = "Role")]
+    pub role: RoleArn,
+    /// The runtime environment for the Lambda function you are uploading. Currently, Lambda
+    /// supports "java" and "nodejs" as the runtime.
+    #[serde(rename = "Runtime")]
+    pub runtime: Option<Runtime>,
+    /// The function execution time at which Lambda should terminate the function. Because the
+    /// execution time has cost implications, we recommend you set this value based on your expected
+    /// execution time. The default is 3 seconds.
+    #[serde(rename = "Timeout")]
+    pub timeout: Option<Timeout>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type Date = String;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DeleteAliasRequest {
+    /// The Lambda function name for which the alias is created.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// Name of the alias to delete.
+    #[serde(rename = "Name")]
+    pub name: Alias,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DeleteEventSourceMappingRequest {
+    /// The event source mapping ID.
+    #[serde(rename = "UUID")]
+    pub uuid: StringType,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DeleteFunctionRequest {
+    /// The Lambda function to delete.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// Using this optional parameter you can specify a function version (but not the $LATEST
+    /// version) to direct AWS Lambda to delete a specific function version. If the function version
+    /// has one or more aliases pointing to it, you will get an error because you cannot have
+    /// aliases pointing to it. You can delete any function version but not the $LATEST, that is,
+    /// you cannot specify $LATEST as the value of this parameter. The $LATEST version can be
+    /// deleted only when you want to delete all the function versions and aliases.
+    ///
+    /// You can only specify a function version and not alias name using this parameter. You cannot
+    /// delete a function version using its alias.
+    ///
+    /// If you don't specify this parameter, AWS Lambda will delete the function, including all its
+    /// versions and aliases.
+    #[serde(rename = "Qualifier")]
+    pub qualifier: Option<Qualifier>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type Description = String;
+pub type Enabled = bool;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EventSourceMappingConfiguration {
+    /// The largest number of records that AWS Lambda will retrieve from your event source at the
+    /// time of invoking your function. Your function receives an event with all the retrieved
+    /// records.
+    #[serde(rename = "BatchSize")]
+    pub batch_size: Option<BatchSize>,
+    /// The Amazon Resource Name (ARN) of the Amazon Kinesis stream that is the source of events.
+    #[serde(rename = "EventSourceArn")]
+    pub event_source_arn: Option<Arn>,
+    /// The Lambda function to invoke when AWS Lambda detects an event on the stream.
+    #[serde(rename = "FunctionArn")]
+    pub function_arn: Option<FunctionArn>,
+    /// The UTC time string indicating the last time the event mapping was updated.
+    #[serde(rename = "LastModified")]
+    pub last_modified: Option<Date>,
+    /// The result of the last AWS Lambda invocation of your Lambda function.
+    #[serde(rename = "LastProcessingResult")]
+    pub last_processing_result: Option<StringType>,
+    /// The state of the event source mapping. It can be "Creating", "Enabled", "Disabled",
+    /// "Enabling", "Disabling", "Updating", or "Deleting".
+    #[serde(rename = "State")]
+    pub state: Option<StringType>,
+    /// The reason the event source mapping is in its current state. It is either user-requested or
+    /// an AWS Lambda-initiated state transition.
+    #[serde(rename = "StateTransitionReason")]
+    pub state_transition_reason: Option<StringType>,
+    /// The AWS Lambda assigned opaque identifier for the mapping.
+    #[serde(rename = "UUID")]
+    pub uuid: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type EventSourceMappingsList = Vec<EventSourceMappingConfiguration>;
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EventSourcePosition {
+    #[serde(rename = "TRIM_HORIZON")]
+    TrimHorizon,
+    #[serde(rename = "LATEST")]
+    Latest,
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+pub type FunctionArn = String;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FunctionCode {
+    /// Amazon S3 bucket name where the .zip file containing your deployment package is stored. This
+    /// bucket must reside in the same AWS region where you are creating the Lambda function.
+    #[serde(rename = "S3Bucket")]
+    pub s3_bucket: Option<S3Bucket>,
+    /// The Amazon S3 object (the deployment package) key name you want to upload.
+    #[serde(rename = "S3Key")]
+    pub s3_key: Option<S3Key>,
+    /// The Amazon S3 object (the deployment package) version you want to upload.
+    #[serde(rename = "S3ObjectVersion")]
+    pub s3_object_version: Option<S3ObjectVersion>,
+    /// A base64-encoded .zip file containing your deployment package. For more information about
+    /// creating a .zip file, go to [Execution
+    /// Permissions](http://docs.aws.amazon.com/lambda/latest/dg/intro-permission-model.html#lambda-intro-execution-role.html)
+    /// in the *AWS Lambda Developer Guide*.
+    #[serde(rename = "ZipFile")]
+    pub zip_file: Option<Blob>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FunctionCodeLocation {
+    /// The presigned URL you can use to download the function's .zip file that you previously
+    /// uploaded. The URL is valid for up to 10 minutes.
+    #[serde(rename = "Location")]
+    pub location: Option<StringType>,
+    /// The repository from which you can download the function.
+    #[serde(rename = "RepositoryType")]
+    pub repository_type: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FunctionConfiguration {
+    /// It is the SHA256 hash of your function deployment package.
+    #[serde(rename = "CodeSha256")]
+    pub code_sha256: Option<StringType>,
+    /// The size, in bytes, of the function .zip file you uploaded.
+    #[serde(rename = "CodeSize")]
+    pub code_size: Option<Long>,
+    /// The user-provided description.
+    #[serde(rename = "Description")]
+    pub description: Option<Description>,
+    /// The Amazon Resource Name (ARN) assigned to the function.
+    #[serde(rename = "FunctionArn")]
+    pub function_arn: Option<FunctionArn>,
+    /// The name of the function.
+    #[serde(rename = "FunctionName")]
+    pub function_name: Option<FunctionName>,
+    /// The function Lambda calls to begin executing your function.
+    #[serde(rename = "Handler")]
+    pub handler: Option<Handler>,
+    /// The timestamp of the last time you updated the function.
+    #[serde(rename = "LastModified")]
+    pub last_modified: Option<Timestamp>,
+    /// The memory size, in MB, you configured for the function. Must be a multiple of 64 MB.
+    #[serde(rename = "MemorySize")]
+    pub memory_size: Option<MemorySize>,
+    /// The Amazon Resource Name (ARN) of the IAM role that Lambda assumes when it executes your
+    /// function to access any other Amazon Web Services (AWS) resources.
+    #[serde(rename = "Role")]
+    pub role: Option<RoleArn>,
+    /// The runtime environment for the Lambda function.
+    #[serde(rename = "Runtime")]
+    pub runtime: Option<Runtime>,
+    /// The function execution time at which Lambda should terminate the function. Because the
+    /// execution time has cost implications, we recommend you set this value based on your expected
+    /// execution time. The default is 3 seconds.
+    #[serde(rename = "Timeout")]
+    pub timeout: Option<Timeout>,
+    /// The version of the Lambda function.
+    #[serde(rename = "Version")]
+    pub version: Option<Version>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type FunctionList = Vec<FunctionConfiguration>;
+pub type FunctionName = String;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GetAliasRequest {
+    /// Function name for which the alias is created. An alias is a subresource that exists only in
+    /// the context of an existing Lambda function. So you must specify the function name.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// Name of the alias for which you want to retrieve information.
+    #[serde(rename = "Name")]
+    pub name: Alias,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GetEventSourceMappingRequest {
+    /// The AWS Lambda assigned ID of the event source mapping.
+    #[serde(rename = "UUID")]
+    pub uuid: StringType,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GetFunctionConfigurationRequest {
+    /// The name of the Lambda function for which you want to retrieve the configuration
+    /// information.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// Using this optional parameter you can specify function version or alias name. If you specify
+    /// function version, the API uses qualified function ARN and returns information about the
+    /// specific function version. if you specify alias name, the API uses alias ARN and returns
+    /// information about the function version to which the alias points.
+    ///
+    /// If you don't specify this parameter, the API uses unqualified function ARN, and returns
+    /// information about the $LATEST function version.
+    #[serde(rename = "Qualifier")]
+    pub qualifier: Option<Qualifier>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GetFunctionRequest {
+    /// The Lambda function name.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// Using this optional parameter to specify a function version or alias name. If you specify
+    /// function version, the API uses qualified function ARN for the request and returns
+    /// information about the specific Lambda function version. If you specify alias name, the API
+    /// uses alias ARN and returns information about the function version to which the alias points.
+    /// If you don't provide this parameter, the API uses unqualified function ARN and returns
+    /// information about the $LATEST version of the Lambda function.
+    #[serde(rename = "Qualifier")]
+    pub qualifier: Option<Qualifier>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GetFunctionResponse {
+    #[serde(rename = "Code")]
+    pub code: Option<FunctionCodeLocation>,
+    #[serde(rename = "Configuration")]
+    pub configuration: Option<FunctionConfiguration>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GetPolicyRequest {
+    /// Function name whose resource policy you want to retrieve.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// You can specify this optional query parameter to specify function version or alias name in
+    /// which case this API will return all permissions associated with the specific ARN. If you
+    /// don't provide this parameter, the API will return permissions that apply to the unqualified
+    /// function ARN.
+    #[serde(rename = "Qualifier")]
+    pub qualifier: Option<Qualifier>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GetPolicyResponse {
+    /// The resource policy associated with the specified function. The response returns the same as
+    /// a string using "\\" as an escape character in the JSON.
+    #[serde(rename = "Policy")]
+    pub policy: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type Handler = String;
+pub type HttpStatus = i32;
+pub type Integer = i32;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct InvocationRequest {
+    /// Using the `ClientContext` you can pass client-specific information to the Lambda function
+    /// you are invoking. You can then process the client information in your Lambda function as you
+    /// choose through the context variable. For an example of a ClientContext JSON, go to
+    /// [PutEvents](http://docs.aws.amazon.com/mobileanalytics/latest/ug/PutEvents.html) in the
+    /// *Amazon Mobile Analytics API Reference and User Guide*.
+    ///
+    /// The ClientContext JSON must be base64-encoded.
+    #[serde(rename = "ClientContext")]
+    pub client_context: Option<StringType>,
+    /// The Lambda function name.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// By default, the `Invoke` API assumes "RequestResponse" invocation type. You can optionally
+    /// request asynchronous execution by specifying "Event" as the `InvocationType`. You can also
+    /// use this parameter to request AWS Lambda to not execute the function but do some
+    /// verification, such as if the caller is authorized to invoke the function and if the inputs
+    /// are valid. You request this by specifying "DryRun" as the `InvocationType`. This is useful
+    /// in a cross-account scenario when you want to verify access to a function without running it.
+    #[serde(rename = "InvocationType")]
+    pub invocation_type: Option<InvocationType>,
+    /// You can set this optional parameter to "Tail" in the request only if you specify the
+    /// `InvocationType` parameter with value "RequestResponse". In this case, AWS Lambda returns
+    /// the base64-encoded last 4 KB of log data produced by your Lambda function in the
+    /// `x-amz-log-results` header.
+    #[serde(rename = "LogType")]
+    pub log_type: Option<LogType>,
+    /// JSON that you want to provide to your Lambda function as input.
+    #[serde(rename = "Payload")]
+    pub payload: Option<Blob>,
+    /// You can use this optional paramter to specify a Lambda function version or alias name. If
+    /// you specify function version, the API uses qualified function ARN to invoke a specific
+    /// Lambda function. If you specify alias name, the API uses the alias ARN to invoke the Lambda
+    /// function version to which the alias points.
+    ///
+    /// If you don't provide this parameter, then the API uses unqualified function ARN which
+    /// results in invocation of the $LATEST version.
+    #[serde(rename = "Qualifier")]
+    pub qualifier: Option<Qualifier>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct InvocationResponse {
+    /// Indicates whether an error occurred while executing the Lambda function. If an error
+    /// occurred this field will have one of two values; `Handled` or `Unhandled`. `Handled` errors
+    /// are errors that are reported by the function while the `Unhandled` errors are those detected
+    /// and reported by AWS Lambda. Unhandled errors include out of memory errors and function
+    /// timeouts. For information about how to report an `Handled` error, see [Programming
+    /// Model](http://docs.aws.amazon.com/lambda/latest/dg/programming-model.html).
+    #[serde(rename = "FunctionError")]
+    pub function_error: Option<StringType>,
+    /// It is the base64-encoded logs for the Lambda function invocation. This is present only if
+    /// the invocation type is "RequestResponse" and the logs were requested.
+    #[serde(rename = "LogResult")]
+    pub log_result: Option<StringType>,
+    /// It is the JSON representation of the object returned by the Lambda function. In This is
+    /// present only if the invocation type is "RequestResponse".
+    ///
+    /// In the event of a function error this field contains a message describing the error. For the
+    /// `Handled` errors the Lambda function will report this message. For `Unhandled` errors AWS
+    /// Lambda reports the message.
+    #[serde(rename = "Payload")]
+    pub payload: Option<Blob>,
+    /// The HTTP status code will be in the 200 range for successful request. For the
+    /// "RequestResonse" invocation type this status code will be 200. For the "Event" invocation
+    /// type this status code will be 202. For the "DryRun" invocation type the status code will be
+    /// 204\.
+    #[serde(rename = "StatusCode")]
+    pub status_code: Option<Integer>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum InvocationType {
+    Event,
+    RequestResponse,
+    DryRun,
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+#[deprecated(note = "deprecated in the service model")]
+#[derive(Debug, Default)]
+pub struct InvokeAsyncRequest {
+    /// The Lambda function name.
+    pub function_name: FunctionName,
+    /// JSON that you want to provide to your Lambda function as input.
+    pub invoke_args: BlobStream,
+    #[doc(hidden)]
+    pub __non_exhaustive: (),
+}
+#[deprecated(note = "deprecated in the service model")]
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct InvokeAsyncResponse {
+    /// It will be 202 upon success.
+    #[serde(rename = "Status")]
+    pub status: Option<HttpStatus>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListAliasesRequest {
+    /// Lambda function name for which the alias is created.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// If you specify this optional parameter, the API returns only the aliases pointing to the
+    /// specific Lambda function version, otherwise returns all aliases created for the Lambda
+    /// function.
+    #[serde(rename = "FunctionVersion")]
+    pub function_version: Option<Version>,
+    /// Optional string. An opaque pagination token returned from a previous ListAliases operation.
+    /// If present, indicates where to continue the listing.
+    #[serde(rename = "Marker")]
+    pub marker: Option<StringType>,
+    /// Optional integer. Specifies the maximum number of aliases to return in response. This
+    /// parameter value must be greater than 0.
+    #[serde(rename = "MaxItems")]
+    pub max_items: Option<MaxListItems>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListAliasesResponse {
+    /// An list of alises.
+    #[serde(rename = "Aliases")]
+    pub aliases: Option<AliasList>,
+    /// A string, present if there are more aliases.
+    #[serde(rename = "NextMarker")]
+    pub next_marker: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListEventSourceMappingsRequest {
+    /// The Amazon Resource Name (ARN) of the Amazon Kinesis stream.
+    #[serde(rename = "EventSourceArn")]
+    pub event_source_arn: Option<Arn>,
+    /// The name of the Lambda function.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: Option<FunctionName>,
+    /// Optional string. An opaque pagination token returned from a previous
+    /// `ListEventSourceMappings` operation. If present, specifies to continue the list from where
+    /// the returning call left off.
+    #[serde(rename = "Marker")]
+    pub marker: Option<StringType>,
+    /// Optional integer. Specifies the maximum number of event sources to return in response. This
+    /// value must be greater than 0.
+    #[serde(rename = "MaxItems")]
+    pub max_items: Option<MaxListItems>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListEventSourceMappingsResponse {
+    /// An array of `EventSourceMappingConfiguration` objects.
+    #[serde(rename = "EventSourceMappings")]
+    pub event_source_mappings: Option<EventSourceMappingsList>,
+    /// A string, present if there are more event source mappings.
+    #[serde(rename = "NextMarker")]
+    pub next_marker: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListFunctionsRequest {
+    /// Optional string. An opaque pagination token returned from a previous `ListFunctions`
+    /// operation. If present, indicates where to continue the listing.
+    #[serde(rename = "Marker")]
+    pub marker: Option<StringType>,
+    /// Optional integer. Specifies the maximum number of AWS Lambda functions to return in
+    /// response. This parameter value must be greater than 0.
+    #[serde(rename = "MaxItems")]
+    pub max_items: Option<MaxListItems>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListFunctionsResponse {
+    /// A list of Lambda functions.
+    #[serde(rename = "Functions")]
+    pub functions: Option<FunctionList>,
+    /// A string, present if there are more functions.
+    #[serde(rename = "NextMarker")]
+    pub next_marker: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListVersionsByFunctionRequest {
+    /// Function name whose versions to list. You can specify an unqualified function name (for
+    /// example, "Thumbnail") or you can specify Amazon Resource Name (ARN) of the function (for
+    /// example, "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows
+    /// you to specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note
+    /// that the length constraint applies only to the ARN. If you specify only the function name,
+    /// it is limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// Optional string. An opaque pagination token returned from a previous
+    /// `ListVersionsByFunction` operation. If present, indicates where to continue the listing.
+    #[serde(rename = "Marker")]
+    pub marker: Option<StringType>,
+    /// Optional integer. Specifies the maximum number of AWS Lambda function versions to return in
+    /// response. This parameter value must be greater than 0.
+    #[serde(rename = "MaxItems")]
+    pub max_items: Option<MaxListItems>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListVersionsByFunctionResponse {
+    /// A string, present if there are more function versions.
+    #[serde(rename = "NextMarker")]
+    pub next_marker: Option<StringType>,
+    /// A list of Lambda function versions.
+    #[serde(rename = "Versions")]
+    pub versions: Option<FunctionList>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LogType {
+    None,
+    Tail,
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+pub type Long = i64;
+pub type MaxListItems = i32;
+pub type MemorySize = i32;
+pub type Principal = String;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PublishVersionRequest {
+    /// The SHA256 hash of the deployment package you want to publish. This provides validation on
+    /// the code you are publishing. If you provide this parameter value must match the SHA256 of
+    /// the HEAD version for the publication to succeed.
+    #[serde(rename = "CodeSha256")]
+    pub code_sha256: Option<StringType>,
+    /// The description for the version you are publishing. If not provided, AWS Lambda copies the
+    /// description from the HEAD version.
+    #[serde(rename = "Description")]
+    pub description: Option<Description>,
+    /// The Lambda function name. You can specify an unqualified function name (for example,
+    /// "Thumbnail") or you can specify Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type Qualifier = String;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RemovePermissionRequest {
+    /// Lambda function whose resource policy you want to remove a permission from.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// You can specify this optional parameter to remove permission associated with a specific
+    /// function version or function alias. The value of this paramter is the function version or
+    /// alias name. If you don't specify this parameter, the API removes permission associated with
+    /// the unqualified function ARN.
+    #[serde(rename = "Qualifier")]
+    pub qualifier: Option<Qualifier>,
+    /// Statement ID of the permission to remove.
+    #[serde(rename = "StatementId")]
+    pub statement_id: StatementId,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type RoleArn = String;
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Runtime {
+    #[serde(rename = "nodejs")]
+    Nodejs,
+    #[serde(rename = "java8")]
+    Java8,
+    #[serde(rename = "python2.7")]
+    Python27,
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+pub type S3Bucket = String;
+pub type S3Key = String;
+pub type S3ObjectVersion = String;
+pub type SourceOwner = String;
+pub type StatementId = String;
+pub type StringType = String;
+pub type Timeout = i32;
+pub type Timestamp = String;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UpdateAliasRequest {
+    /// You can optionally change the description of the alias using this parameter.
+    #[serde(rename = "Description")]
+    pub description: Option<Description>,
+    /// The function name for which the alias is created.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// Using this parameter you can optionally change the Lambda function version to which the
+    /// alias to points to.
+    #[serde(rename = "FunctionVersion")]
+    pub function_version: Option<Version>,
+    /// The alias name.
+    #[serde(rename = "Name")]
+    pub name: Alias,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UpdateEventSourceMappingRequest {
+    /// The maximum number of stream records that can be sent to your Lambda function for a single
+    /// invocation.
+    #[serde(rename = "BatchSize")]
+    pub batch_size: Option<BatchSize>,
+    /// Specifies whether AWS Lambda should actively poll the stream or not. If disabled, AWS Lambda
+    /// will not poll the stream.
+    #[serde(rename = "Enabled")]
+    pub enabled: Option<Enabled>,
+    /// The Lambda function to which you want the stream records sent.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: Option<FunctionName>,
+    /// The event source mapping identifier.
+    #[serde(rename = "UUID")]
+    pub uuid: StringType,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UpdateFunctionCodeRequest {
+    /// The existing Lambda function name whose code you want to replace.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// This boolean parameter can be used to request AWS Lambda to update the Lambda function and
+    /// publish a version as an atomic operation.
+    #[serde(rename = "Publish")]
+    pub publish: Option<Boolean>,
+    /// Amazon S3 bucket name where the .zip file containing your deployment package is stored. This
+    /// bucket must reside in the same AWS region where you are creating the Lambda function.
+    #[serde(rename = "S3Bucket")]
+    pub s3_bucket: Option<S3Bucket>,
+    /// The Amazon S3 object (the deployment package) key name you want to upload.
+    #[serde(rename = "S3Key")]
+    pub s3_key: Option<S3Key>,
+    /// The Amazon S3 object (the deployment package) version you want to upload.
+    #[serde(rename = "S3ObjectVersion")]
+    pub s3_object_version: Option<S3ObjectVersion>,
+    /// Based64-encoded .zip file containing your packaged source code.
+    #[serde(rename = "ZipFile")]
+    pub zip_file: Option<Blob>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UpdateFunctionConfigurationRequest {
+    /// A short user-defined function description. AWS Lambda does not use this value. Assign a
+    /// meaningful description as you see fit.
+    #[serde(rename = "Description")]
+    pub description: Option<Description>,
+    /// The name of the Lambda function.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    #[serde(rename = "FunctionName")]
+    pub function_name: FunctionName,
+    /// The function that Lambda calls to begin executing your function. For Node.js, it is the
+    /// *module-name.export* value in your function.
+    #[serde(rename = "Handler")]
+    pub handler: Option<Handler>,
+    /// The amount of memory, in MB, your Lambda function is given. AWS Lambda uses this memory size
+    /// to infer the amount of CPU allocated to your function. Your function use-case determines
+    /// your CPU and memory requirements. For example, a database operation might need less memory
+    /// compared to an image processing function. The default value is 128 MB. The value must be a
+    /// multiple of 64 MB.
+    #[serde(rename = "MemorySize")]
+    pub memory_size: Option<MemorySize>,
+    /// The Amazon Resource Name (ARN) of the IAM role that Lambda will assume when it executes your
+    /// function.
+    #[serde(rename = "Role")]
+    pub role: Option<RoleArn>,
+    /// The function execution time at which AWS Lambda should terminate the function. Because the
+    /// execution time has cost implications, we recommend you set this value based on your expected
+    /// execution time. The default is 3 seconds.
+    #[serde(rename = "Timeout")]
+    pub timeout: Option<Timeout>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type Version = String;
+
+/// Builds a `AddPermissionRequest`; start with `AddPermissionRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct AddPermissionRequestBuilder {
+    action: Option<Action>,
+    function_name: Option<FunctionName>,
+    principal: Option<Principal>,
+    qualifier: Option<Qualifier>,
+    source_account: Option<SourceOwner>,
+    source_arn: Option<Arn>,
+    statement_id: Option<StatementId>,
+}
+
+impl AddPermissionRequest {
+    pub fn builder() -> AddPermissionRequestBuilder {
+        AddPermissionRequestBuilder::default()
+    }
+}
+
+impl AddPermissionRequestBuilder {
+    /// The AWS Lambda action you want to allow in this statement. Each Lambda action is a string
+    /// starting with "lambda:" followed by the API name (see Operations). For example,
+    /// "lambda:CreateFunction". You can use wildcard ("lambda:\*") to grant permission for all AWS
+    /// Lambda actions.
+    pub fn action<V: Into<Action>>(mut self, value: V) -> Self {
+        self.action = Some(value.into());
+        self
+    }
+
+    /// Name of the Lambda function whose resource policy you are updating by adding a new
+    /// permission.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// The principal who is getting this permission. It can be Amazon S3 service Principal
+    /// ("s3.amazonaws.com") if you want Amazon S3 to invoke the function, an AWS account ID if you
+    /// are granting cross-account permission, or any valid AWS service principal such as
+    /// "sns.amazonaws.com". For example, you might want to allow a custom application in another
+    /// AWS account to push events to AWS Lambda by invoking your function.
+    pub fn principal<V: Into<Principal>>(mut self, value: V) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// You can specify this optional query parameter to specify function version or alias name. The
+    /// permission will then apply to the specific qualified ARN. For example, if you specify
+    /// function version 2 as the qualifier, then permission applies only when request is made using
+    /// qualified function ARN:
+    ///
+    /// `arn:aws:lambda:aws-region:acct-id:function:function-name:2`
+    ///
+    /// If you specify alias name, for example "PROD", then the permission is valid only for
+    /// requests made using the alias ARN:
+    ///
+    /// `arn:aws:lambda:aws-region:acct-id:function:function-name:PROD`
+    ///
+    /// If the qualifier is not specified, the permission is valid only when requests is made using
+    /// unqualified function ARN.
+    ///
+    /// `arn:aws:lambda:aws-region:acct-id:function:function-name`
+    pub fn qualifier<V: Into<Qualifier>>(mut self, value: V) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// The AWS account ID (without a hyphen) of the source owner. For example, if the `SourceArn`
+    /// identifies a bucket, then this is the bucket owner's account ID. You can use this additional
+    /// condition to ensure the bucket you specify is owned by a specific account (it is possible
+    /// the bucket owner deleted the bucket and some other AWS account created the bucket). You can
+    /// also use this condition to specify all sources (that is, you don't specify the `SourceArn`)
+    /// owned by a specific account.
+    pub fn source_account<V: Into<SourceOwner>>(mut self, value: V) -> Self {
+        self.source_account = Some(value.into());
+        self
+    }
+
+    /// This is optional; however, when granting Amazon S3 permission to invoke your function, you
+    /// should specify this field with the bucket Amazon Resource Name (ARN) as its value. This
+    /// ensures that only events generated from the specified bucket can invoke the function.
+    ///
+    /// **Important:** If you add a permission for the Amazon S3 principal without providing the
+    /// source ARN, any AWS account that creates a mapping to your function ARN can send events to
+    /// invoke your Lambda function from Amazon S3.
+    pub fn source_arn<V: Into<Arn>>(mut self, value: V) -> Self {
+        self.source_arn = Some(value.into());
+        self
+    }
+
+    /// A unique statement identifier.
+    pub fn statement_id<V: Into<StatementId>>(mut self, value: V) -> Self {
+        self.statement_id = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<AddPermissionRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.action.is_none() {
+            missing.push("Action");
+        }
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if self.principal.is_none() {
+            missing.push("Principal");
+        }
+        if self.statement_id.is_none() {
+            missing.push("StatementId");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "AddPermissionRequest", members: missing });
+        }
+        Ok(AddPermissionRequest {
+            action: self.action.unwrap(),
+            function_name: self.function_name.unwrap(),
+            principal: self.principal.unwrap(),
+            qualifier: self.qualifier,
+            source_account: self.source_account,
+            source_arn: self.source_arn,
+            statement_id: self.statement_id.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `CreateAliasRequest`; start with `CreateAliasRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct CreateAliasRequestBuilder {
+    description: Option<Description>,
+    function_name: Option<FunctionName>,
+    function_version: Option<Version>,
+    name: Option<Alias>,
+}
+
+impl CreateAliasRequest {
+    pub fn builder() -> CreateAliasRequestBuilder {
+        CreateAliasRequestBuilder::default()
+    }
+}
+
+impl CreateAliasRequestBuilder {
+    /// Description of the alias.
+    pub fn description<V: Into<Description>>(mut self, value: V) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Name of the Lambda function for which you want to create an alias.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Lambda function version for which you are creating the alias.
+    pub fn function_version<V: Into<Version>>(mut self, value: V) -> Self {
+        self.function_version = Some(value.into());
+        self
+    }
+
+    /// Name for the alias your creating.
+    pub fn name<V: Into<Alias>>(mut self, value: V) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<CreateAliasRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if self.function_version.is_none() {
+            missing.push("FunctionVersion");
+        }
+        if self.name.is_none() {
+            missing.push("Name");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "CreateAliasRequest", members: missing });
+        }
+        Ok(CreateAliasRequest {
+            description: self.description,
+            function_name: self.function_name.unwrap(),
+            function_version: self.function_version.unwrap(),
+            name: self.name.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `CreateEventSourceMappingRequest`; start with `CreateEventSourceMappingRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct CreateEventSourceMappingRequestBuilder {
+    batch_size: Option<BatchSize>,
+    enabled: Option<Enabled>,
+    event_source_arn: Option<Arn>,
+    function_name: Option<FunctionName>,
+    starting_position: Option<EventSourcePosition>,
+}
+
+impl CreateEventSourceMappingRequest {
+    pub fn builder() -> CreateEventSourceMappingRequestBuilder {
+        CreateEventSourceMappingRequestBuilder::default()
+    }
+}
+
+impl CreateEventSourceMappingRequestBuilder {
+    /// The largest number of records that AWS Lambda will retrieve from your event source at the
+    /// time of invoking your function. Your function receives an event with all the retrieved
+    /// records. The default is 100 records.
+    pub fn batch_size<V: Into<BatchSize>>(mut self, value: V) -> Self {
+        self.batch_size = Some(value.into());
+        self
+    }
+
+    /// Indicates whether AWS Lambda should begin polling the event source. By default, `Enabled` is
+    /// true.
+    pub fn enabled<V: Into<Enabled>>(mut self, value: V) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// The Amazon Resource Name (ARN) of the Amazon Kinesis or the Amazon DynamoDB stream that is
+    /// the event source. Any record added to this stream could cause AWS Lambda to invoke your
+    /// Lambda function, it depends on the `BatchSize`. AWS Lambda POSTs the Amazon Kinesis event,
+    /// containing records, to your Lambda function as JSON.
+    pub fn event_source_arn<V: Into<Arn>>(mut self, value: V) -> Self {
+        self.event_source_arn = Some(value.into());
+        self
+    }
+
+    /// The Lambda function to invoke when AWS Lambda detects an event on the stream.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// The position in the stream where AWS Lambda should start reading. For more information, go
+    /// to
+    /// [ShardIteratorType](http://docs.aws.amazon.com/kinesis/latest/APIReference/API_GetShardIterator.html#Kinesis-GetShardIterator-request-ShardIteratorType)
+    /// in the *Amazon Kinesis API Reference*.
+    pub fn starting_position<V: Into<EventSourcePosition>>(mut self, value: V) -> Self {
+        self.starting_position = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<CreateEventSourceMappingRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.event_source_arn.is_none() {
+            missing.push("EventSourceArn");
+        }
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if self.starting_position.is_none() {
+            missing.push("StartingPosition");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "CreateEventSourceMappingRequest", members: missing });
+        }
+        Ok(CreateEventSourceMappingRequest {
+            batch_size: self.batch_size,
+            enabled: self.enabled,
+            event_source_arn: self.event_source_arn.unwrap(),
+            function_name: self.function_name.unwrap(),
+            starting_position: self.starting_position,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `CreateFunctionRequest`; start with `CreateFunctionRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct CreateFunctionRequestBuilder {
+    code: Option<FunctionCode>,
+    description: Option<Description>,
+    function_name: Option<FunctionName>,
+    handler: Option<Handler>,
+    memory_size: Option<MemorySize>,
+    publish: Option<Boolean>,
+    role: Option<RoleArn>,
+    runtime: Option<Runtime>,
+    timeout: Option<Timeout>,
+}
+
+impl CreateFunctionRequest {
+    pub fn builder() -> CreateFunctionRequestBuilder {
+        CreateFunctionRequestBuilder::default()
+    }
+}
+
+impl CreateFunctionRequestBuilder {
+    /// The code for the Lambda function.
+    pub fn code<V: Into<FunctionCode>>(mut self, value: V) -> Self {
+        self.code = Some(value.into());
+        self
+    }
+
+    /// A short, user-defined function description. Lambda does not use this value. Assign a
+    /// meaningful description as you see fit.
+    pub fn description<V: Into<Description>>(mut self, value: V) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// The name you want to assign to the function you are uploading. You can specify an
+    /// unqualified function name (for example, "Thumbnail") or you can specify Amazon Resource Name
+    /// (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length. The function names appear in the console and are returned
+    /// in the ListFunctions API. Function names are used to specify functions to other AWS Lambda
+    /// APIs, such as Invoke.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// The function within your code that Lambda calls to begin execution. For Node.js, it is the
+    /// *module-name*.*export* value in your function. For Java, it can be
+    /// `package.class-name::handler` or `package.class-name`. For more information, see [Lambda
+    /// Function Handler
+    /// (Java)](http://docs.aws.amazon.com/lambda/latest/dg/java-programming-model-handler-types.html).
+    pub fn handler<V: Into<Handler>>(mut self, value: V) -> Self {
+        self.handler = Some(value.into());
+        self
+    }
+
+    /// The amount of memory, in MB, your Lambda function is given. Lambda uses this memory size to
+    /// infer the amount of CPU and memory allocated to your function. Your function use-case
+    /// determines your CPU and memory requirements. For example, a database operation might need
+    /// less memory compared to an image processing function. The default value is 128 MB. The value
+    /// must be a multiple of 64 MB.
+    pub fn memory_size<V: Into<MemorySize>>(mut self, value: V) -> Self {
+        self.memory_size = Some(value.into());
+        self
+    }
+
+    /// This boolean parameter can be used to request AWS Lambda to create the Lambda function and
+    /// publish a version as an atomic operation.
+    pub fn publish<V: Into<Boolean>>(mut self, value: V) -> Self {
+        self.publish = Some(value.into());
+        self
+    }
+
+    /// The Amazon Resource Name (ARN) of the IAM role that Lambda assumes when it executes your
+    /// function to access any other Amazon Web Services (AWS) resources. For more information, see
+    /// [AWS Lambda: How it
+    /// Works](http://docs.aws.amazon.com/lambda/latest/dg/lambda-introduction.html)
+    pub fn role<V: Into<RoleArn>>(mut self, value: V) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// The runtime environment for the Lambda function you are uploading. Currently, Lambda
+    /// supports "java" and "nodejs" as the runtime.
+    pub fn runtime<V: Into<Runtime>>(mut self, value: V) -> Self {
+        self.runtime = Some(value.into());
+        self
+    }
+
+    /// The function execution time at which Lambda should terminate the function. Because the
+    /// execution time has cost implications, we recommend you set this value based on your expected
+    /// execution time. The default is 3 seconds.
+    pub fn timeout<V: Into<Timeout>>(mut self, value: V) -> Self {
+        self.timeout = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<CreateFunctionRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.code.is_none() {
+            missing.push("Code");
+        }
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if self.handler.is_none() {
+            missing.push("Handler");
+        }
+        if self.role.is_none() {
+            missing.push("Role");
+        }
+        if self.runtime.is_none() {
+            missing.push("Runtime");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "CreateFunctionRequest", members: missing });
+        }
+        Ok(CreateFunctionRequest {
+            code: self.code.unwrap(),
+            description: self.description,
+            function_name: self.function_name.unwrap(),
+            handler: self.handler.unwrap(),
+            memory_size: self.memory_size,
+            publish: self.publish,
+            role: self.role.unwrap(),
+            runtime: self.runtime,
+            timeout: self.timeout,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `DeleteAliasRequest`; start with `DeleteAliasRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct DeleteAliasRequestBuilder {
+    function_name: Option<FunctionName>,
+    name: Option<Alias>,
+}
+
+impl DeleteAliasRequest {
+    pub fn builder() -> DeleteAliasRequestBuilder {
+        DeleteAliasRequestBuilder::default()
+    }
+}
+
+impl DeleteAliasRequestBuilder {
+    /// The Lambda function name for which the alias is created.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Name of the alias to delete.
+    pub fn name<V: Into<Alias>>(mut self, value: V) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<DeleteAliasRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if self.name.is_none() {
+            missing.push("Name");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "DeleteAliasRequest", members: missing });
+        }
+        Ok(DeleteAliasRequest {
+            function_name: self.function_name.unwrap(),
+            name: self.name.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `DeleteEventSourceMappingRequest`; start with `DeleteEventSourceMappingRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct DeleteEventSourceMappingRequestBuilder {
+    uuid: Option<StringType>,
+}
+
+impl DeleteEventSourceMappingRequest {
+    pub fn builder() -> DeleteEventSourceMappingRequestBuilder {
+        DeleteEventSourceMappingRequestBuilder::default()
+    }
+}
+
+impl DeleteEventSourceMappingRequestBuilder {
+    /// The event source mapping ID.
+    pub fn uuid<V: Into<StringType>>(mut self, value: V) -> Self {
+        self.uuid = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<DeleteEventSourceMappingRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.uuid.is_none() {
+            missing.push("UUID");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "DeleteEventSourceMappingRequest", members: missing });
+        }
+        Ok(DeleteEventSourceMappingRequest {
+            uuid: self.uuid.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `DeleteFunctionRequest`; start with `DeleteFunctionRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct DeleteFunctionRequestBuilder {
+    function_name: Option<FunctionName>,
+    qualifier: Option<Qualifier>,
+}
+
+impl DeleteFunctionRequest {
+    pub fn builder() -> DeleteFunctionRequestBuilder {
+        DeleteFunctionRequestBuilder::default()
+    }
+}
+
+impl DeleteFunctionRequestBuilder {
+    /// The Lambda function to delete.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Using this optional parameter you can specify a function version (but not the $LATEST
+    /// version) to direct AWS Lambda to delete a specific function version. If the function version
+    /// has one or more aliases pointing to it, you will get an error because you cannot have
+    /// aliases pointing to it. You can delete any function version but not the $LATEST, that is,
+    /// you cannot specify $LATEST as the value of this parameter. The $LATEST version can be
+    /// deleted only when you want to delete all the function versions and aliases.
+    ///
+    /// You can only specify a function version and not alias name using this parameter. You cannot
+    /// delete a function version using its alias.
+    ///
+    /// If you don't specify this parameter, AWS Lambda will delete the function, including all its
+    /// versions and aliases.
+    pub fn qualifier<V: Into<Qualifier>>(mut self, value: V) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<DeleteFunctionRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "DeleteFunctionRequest", members: missing });
+        }
+        Ok(DeleteFunctionRequest {
+            function_name: self.function_name.unwrap(),
+            qualifier: self.qualifier,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `GetAliasRequest`; start with `GetAliasRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct GetAliasRequestBuilder {
+    function_name: Option<FunctionName>,
+    name: Option<Alias>,
+}
+
+impl GetAliasRequest {
+    pub fn builder() -> GetAliasRequestBuilder {
+        GetAliasRequestBuilder::default()
+    }
+}
+
+impl GetAliasRequestBuilder {
+    /// Function name for which the alias is created. An alias is a subresource that exists only in
+    /// the context of an existing Lambda function. So you must specify the function name.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Name of the alias for which you want to retrieve information.
+    pub fn name<V: Into<Alias>>(mut self, value: V) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<GetAliasRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if self.name.is_none() {
+            missing.push("Name");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "GetAliasRequest", members: missing });
+        }
+        Ok(GetAliasRequest {
+            function_name: self.function_name.unwrap(),
+            name: self.name.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `GetEventSourceMappingRequest`; start with `GetEventSourceMappingRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct GetEventSourceMappingRequestBuilder {
+    uuid: Option<StringType>,
+}
+
+impl GetEventSourceMappingRequest {
+    pub fn builder() -> GetEventSourceMappingRequestBuilder {
+        GetEventSourceMappingRequestBuilder::default()
+    }
+}
+
+impl GetEventSourceMappingRequestBuilder {
+    /// The AWS Lambda assigned ID of the event source mapping.
+    pub fn uuid<V: Into<StringType>>(mut self, value: V) -> Self {
+        self.uuid = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<GetEventSourceMappingRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.uuid.is_none() {
+            missing.push("UUID");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "GetEventSourceMappingRequest", members: missing });
+        }
+        Ok(GetEventSourceMappingRequest {
+            uuid: self.uuid.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `GetFunctionConfigurationRequest`; start with `GetFunctionConfigurationRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct GetFunctionConfigurationRequestBuilder {
+    function_name: Option<FunctionName>,
+    qualifier: Option<Qualifier>,
+}
+
+impl GetFunctionConfigurationRequest {
+    pub fn builder() -> GetFunctionConfigurationRequestBuilder {
+        GetFunctionConfigurationRequestBuilder::default()
+    }
+}
+
+impl GetFunctionConfigurationRequestBuilder {
+    /// The name of the Lambda function for which you want to retrieve the configuration
+    /// information.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Using this optional parameter you can specify function version or alias name. If you specify
+    /// function version, the API uses qualified function ARN and returns information about the
+    /// specific function version. if you specify alias name, the API uses alias ARN and returns
+    /// information about the function version to which the alias points.
+    ///
+    /// If you don't specify this parameter, the API uses unqualified function ARN, and returns
+    /// information about the $LATEST function version.
+    pub fn qualifier<V: Into<Qualifier>>(mut self, value: V) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<GetFunctionConfigurationRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "GetFunctionConfigurationRequest", members: missing });
+        }
+        Ok(GetFunctionConfigurationRequest {
+            function_name: self.function_name.unwrap(),
+            qualifier: self.qualifier,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `GetFunctionRequest`; start with `GetFunctionRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct GetFunctionRequestBuilder {
+    function_name: Option<FunctionName>,
+    qualifier: Option<Qualifier>,
+}
+
+impl GetFunctionRequest {
+    pub fn builder() -> GetFunctionRequestBuilder {
+        GetFunctionRequestBuilder::default()
+    }
+}
+
+impl GetFunctionRequestBuilder {
+    /// The Lambda function name.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Using this optional parameter to specify a function version or alias name. If you specify
+    /// function version, the API uses qualified function ARN for the request and returns
+    /// information about the specific Lambda function version. If you specify alias name, the API
+    /// uses alias ARN and returns information about the function version to which the alias points.
+    /// If you don't provide this parameter, the API uses unqualified function ARN and returns
+    /// information about the $LATEST version of the Lambda function.
+    pub fn qualifier<V: Into<Qualifier>>(mut self, value: V) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<GetFunctionRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "GetFunctionRequest", members: missing });
+        }
+        Ok(GetFunctionRequest {
+            function_name: self.function_name.unwrap(),
+            qualifier: self.qualifier,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `GetPolicyRequest`; start with `GetPolicyRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct GetPolicyRequestBuilder {
+    function_name: Option<FunctionName>,
+    qualifier: Option<Qualifier>,
+}
+
+impl GetPolicyRequest {
+    pub fn builder() -> GetPolicyRequestBuilder {
+        GetPolicyRequestBuilder::default()
+    }
+}
+
+impl GetPolicyRequestBuilder {
+    /// Function name whose resource policy you want to retrieve.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// You can specify this optional query parameter to specify function version or alias name in
+    /// which case this API will return all permissions associated with the specific ARN. If you
+    /// don't provide this parameter, the API will return permissions that apply to the unqualified
+    /// function ARN.
+    pub fn qualifier<V: Into<Qualifier>>(mut self, value: V) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<GetPolicyRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "GetPolicyRequest", members: missing });
+        }
+        Ok(GetPolicyRequest {
+            function_name: self.function_name.unwrap(),
+            qualifier: self.qualifier,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `InvocationRequest`; start with `InvocationRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct InvocationRequestBuilder {
+    client_context: Option<StringType>,
+    function_name: Option<FunctionName>,
+    invocation_type: Option<InvocationType>,
+    log_type: Option<LogType>,
+    payload: Option<Blob>,
+    qualifier: Option<Qualifier>,
+}
+
+impl InvocationRequest {
+    pub fn builder() -> InvocationRequestBuilder {
+        InvocationRequestBuilder::default()
+    }
+}
+
+impl InvocationRequestBuilder {
+    /// Using the `ClientContext` you can pass client-specific information to the Lambda function
+    /// you are invoking. You can then process the client information in your Lambda function as you
+    /// choose through the context variable. For an example of a ClientContext JSON, go to
+    /// [PutEvents](http://docs.aws.amazon.com/mobileanalytics/latest/ug/PutEvents.html) in the
+    /// *Amazon Mobile Analytics API Reference and User Guide*.
+    ///
+    /// The ClientContext JSON must be base64-encoded.
+    pub fn client_context<V: Into<StringType>>(mut self, value: V) -> Self {
+        self.client_context = Some(value.into());
+        self
+    }
+
+    /// The Lambda function name.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// By default, the `Invoke` API assumes "RequestResponse" invocation type. You can optionally
+    /// request asynchronous execution by specifying "Event" as the `InvocationType`. You can also
+    /// use this parameter to request AWS Lambda to not execute the function but do some
+    /// verification, such as if the caller is authorized to invoke the function and if the inputs
+    /// are valid. You request this by specifying "DryRun" as the `InvocationType`. This is useful
+    /// in a cross-account scenario when you want to verify access to a function without running it.
+    pub fn invocation_type<V: Into<InvocationType>>(mut self, value: V) -> Self {
+        self.invocation_type = Some(value.into());
+        self
+    }
+
+    /// You can set this optional parameter to "Tail" in the request only if you specify the
+    /// `InvocationType` parameter with value "RequestResponse". In this case, AWS Lambda returns
+    /// the base64-encoded last 4 KB of log data produced by your Lambda function in the
+    /// `x-amz-log-results` header.
+    pub fn log_type<V: Into<LogType>>(mut self, value: V) -> Self {
+        self.log_type = Some(value.into());
+        self
+    }
+
+    /// JSON that you want to provide to your Lambda function as input.
+    pub fn payload<V: Into<Blob>>(mut self, value: V) -> Self {
+        self.payload = Some(value.into());
+        self
+    }
+
+    /// You can use this optional paramter to specify a Lambda function version or alias name. If
+    /// you specify function version, the API uses qualified function ARN to invoke a specific
+    /// Lambda function. If you specify alias name, the API uses the alias ARN to invoke the Lambda
+    /// function version to which the alias points.
+    ///
+    /// If you don't provide this parameter, then the API uses unqualified function ARN which
+    /// results in invocation of the $LATEST version.
+    pub fn qualifier<V: Into<Qualifier>>(mut self, value: V) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<InvocationRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "InvocationRequest", members: missing });
+        }
+        Ok(InvocationRequest {
+            client_context: self.client_context,
+            function_name: self.function_name.unwrap(),
+            invocation_type: self.invocation_type,
+            log_type: self.log_type,
+            payload: self.payload,
+            qualifier: self.qualifier,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `InvokeAsyncRequest`; start with `InvokeAsyncRequest::builder()`.
+#[allow(deprecated)]
+#[derive(Debug, Default)]
+pub struct InvokeAsyncRequestBuilder {
+    function_name: Option<FunctionName>,
+    invoke_args: Option<BlobStream>,
+}
+
+#[allow(deprecated)]
+impl InvokeAsyncRequest {
+    pub fn builder() -> InvokeAsyncRequestBuilder {
+        InvokeAsyncRequestBuilder::default()
+    }
+}
+
+#[allow(deprecated)]
+impl InvokeAsyncRequestBuilder {
+    /// The Lambda function name.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// JSON that you want to provide to your Lambda function as input.
+    pub fn invoke_args<V: Into<BlobStream>>(mut self, value: V) -> Self {
+        self.invoke_args = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<InvokeAsyncRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if self.invoke_args.is_none() {
+            missing.push("InvokeArgs");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "InvokeAsyncRequest", members: missing });
+        }
+        Ok(InvokeAsyncRequest {
+            function_name: self.function_name.unwrap(),
+            invoke_args: self.invoke_args.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `ListAliasesRequest`; start with `ListAliasesRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct ListAliasesRequestBuilder {
+    function_name: Option<FunctionName>,
+    function_version: Option<Version>,
+    marker: Option<StringType>,
+    max_items: Option<MaxListItems>,
+}
+
+impl ListAliasesRequest {
+    pub fn builder() -> ListAliasesRequestBuilder {
+        ListAliasesRequestBuilder::default()
+    }
+}
+
+impl ListAliasesRequestBuilder {
+    /// Lambda function name for which the alias is created.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// If you specify this optional parameter, the API returns only the aliases pointing to the
+    /// specific Lambda function version, otherwise returns all aliases created for the Lambda
+    /// function.
+    pub fn function_version<V: Into<Version>>(mut self, value: V) -> Self {
+        self.function_version = Some(value.into());
+        self
+    }
+
+    /// Optional string. An opaque pagination token returned from a previous ListAliases operation.
+    /// If present, indicates where to continue the listing.
+    pub fn marker<V: Into<StringType>>(mut self, value: V) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Optional integer. Specifies the maximum number of aliases to return in response. This
+    /// parameter value must be greater than 0.
+    pub fn max_items<V: Into<MaxListItems>>(mut self, value: V) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<ListAliasesRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "ListAliasesRequest", members: missing });
+        }
+        Ok(ListAliasesRequest {
+            function_name: self.function_name.unwrap(),
+            function_version: self.function_version,
+            marker: self.marker,
+            max_items: self.max_items,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `ListEventSourceMappingsRequest`; start with `ListEventSourceMappingsRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct ListEventSourceMappingsRequestBuilder {
+    event_source_arn: Option<Arn>,
+    function_name: Option<FunctionName>,
+    marker: Option<StringType>,
+    max_items: Option<MaxListItems>,
+}
+
+impl ListEventSourceMappingsRequest {
+    pub fn builder() -> ListEventSourceMappingsRequestBuilder {
+        ListEventSourceMappingsRequestBuilder::default()
+    }
+}
+
+impl ListEventSourceMappingsRequestBuilder {
+    /// The Amazon Resource Name (ARN) of the Amazon Kinesis stream.
+    pub fn event_source_arn<V: Into<Arn>>(mut self, value: V) -> Self {
+        self.event_source_arn = Some(value.into());
+        self
+    }
+
+    /// The name of the Lambda function.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Optional string. An opaque pagination token returned from a previous
+    /// `ListEventSourceMappings` operation. If present, specifies to continue the list from where
+    /// the returning call left off.
+    pub fn marker<V: Into<StringType>>(mut self, value: V) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Optional integer. Specifies the maximum number of event sources to return in response. This
+    /// value must be greater than 0.
+    pub fn max_items<V: Into<MaxListItems>>(mut self, value: V) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<ListEventSourceMappingsRequest, ::runtime::builder::MissingMembers> {
+        Ok(ListEventSourceMappingsRequest {
+            event_source_arn: self.event_source_arn,
+            function_name: self.function_name,
+            marker: self.marker,
+            max_items: self.max_items,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `ListFunctionsRequest`; start with `ListFunctionsRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct ListFunctionsRequestBuilder {
+    marker: Option<StringType>,
+    max_items: Option<MaxListItems>,
+}
+
+impl ListFunctionsRequest {
+    pub fn builder() -> ListFunctionsRequestBuilder {
+        ListFunctionsRequestBuilder::default()
+    }
+}
+
+impl ListFunctionsRequestBuilder {
+    /// Optional string. An opaque pagination token returned from a previous `ListFunctions`
+    /// operation. If present, indicates where to continue the listing.
+    pub fn marker<V: Into<StringType>>(mut self, value: V) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Optional integer. Specifies the maximum number of AWS Lambda functions to return in
+    /// response. This parameter value must be greater than 0.
+    pub fn max_items<V: Into<MaxListItems>>(mut self, value: V) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<ListFunctionsRequest, ::runtime::builder::MissingMembers> {
+        Ok(ListFunctionsRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `ListVersionsByFunctionRequest`; start with `ListVersionsByFunctionRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct ListVersionsByFunctionRequestBuilder {
+    function_name: Option<FunctionName>,
+    marker: Option<StringType>,
+    max_items: Option<MaxListItems>,
+}
+
+impl ListVersionsByFunctionRequest {
+    pub fn builder() -> ListVersionsByFunctionRequestBuilder {
+        ListVersionsByFunctionRequestBuilder::default()
+    }
+}
+
+impl ListVersionsByFunctionRequestBuilder {
+    /// Function name whose versions to list. You can specify an unqualified function name (for
+    /// example, "Thumbnail") or you can specify Amazon Resource Name (ARN) of the function (for
+    /// example, "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows
+    /// you to specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note
+    /// that the length constraint applies only to the ARN. If you specify only the function name,
+    /// it is limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Optional string. An opaque pagination token returned from a previous
+    /// `ListVersionsByFunction` operation. If present, indicates where to continue the listing.
+    pub fn marker<V: Into<StringType>>(mut self, value: V) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Optional integer. Specifies the maximum number of AWS Lambda function versions to return in
+    /// response. This parameter value must be greater than 0.
+    pub fn max_items<V: Into<MaxListItems>>(mut self, value: V) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<ListVersionsByFunctionRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "ListVersionsByFunctionRequest", members: missing });
+        }
+        Ok(ListVersionsByFunctionRequest {
+            function_name: self.function_name.unwrap(),
+            marker: self.marker,
+            max_items: self.max_items,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `PublishVersionRequest`; start with `PublishVersionRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct PublishVersionRequestBuilder {
+    code_sha256: Option<StringType>,
+    description: Option<Description>,
+    function_name: Option<FunctionName>,
+}
+
+impl PublishVersionRequest {
+    pub fn builder() -> PublishVersionRequestBuilder {
+        PublishVersionRequestBuilder::default()
+    }
+}
+
+impl PublishVersionRequestBuilder {
+    /// The SHA256 hash of the deployment package you want to publish. This provides validation on
+    /// the code you are publishing. If you provide this parameter value must match the SHA256 of
+    /// the HEAD version for the publication to succeed.
+    pub fn code_sha256<V: Into<StringType>>(mut self, value: V) -> Self {
+        self.code_sha256 = Some(value.into());
+        self
+    }
+
+    /// The description for the version you are publishing. If not provided, AWS Lambda copies the
+    /// description from the HEAD version.
+    pub fn description<V: Into<Description>>(mut self, value: V) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// The Lambda function name. You can specify an unqualified function name (for example,
+    /// "Thumbnail") or you can specify Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<PublishVersionRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "PublishVersionRequest", members: missing });
+        }
+        Ok(PublishVersionRequest {
+            code_sha256: self.code_sha256,
+            description: self.description,
+            function_name: self.function_name.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `RemovePermissionRequest`; start with `RemovePermissionRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct RemovePermissionRequestBuilder {
+    function_name: Option<FunctionName>,
+    qualifier: Option<Qualifier>,
+    statement_id: Option<StatementId>,
+}
+
+impl RemovePermissionRequest {
+    pub fn builder() -> RemovePermissionRequestBuilder {
+        RemovePermissionRequestBuilder::default()
+    }
+}
+
+impl RemovePermissionRequestBuilder {
+    /// Lambda function whose resource policy you want to remove a permission from.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// You can specify this optional parameter to remove permission associated with a specific
+    /// function version or function alias. The value of this paramter is the function version or
+    /// alias name. If you don't specify this parameter, the API removes permission associated with
+    /// the unqualified function ARN.
+    pub fn qualifier<V: Into<Qualifier>>(mut self, value: V) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Statement ID of the permission to remove.
+    pub fn statement_id<V: Into<StatementId>>(mut self, value: V) -> Self {
+        self.statement_id = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<RemovePermissionRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if self.statement_id.is_none() {
+            missing.push("StatementId");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "RemovePermissionRequest", members: missing });
+        }
+        Ok(RemovePermissionRequest {
+            function_name: self.function_name.unwrap(),
+            qualifier: self.qualifier,
+            statement_id: self.statement_id.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `UpdateAliasRequest`; start with `UpdateAliasRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct UpdateAliasRequestBuilder {
+    description: Option<Description>,
+    function_name: Option<FunctionName>,
+    function_version: Option<Version>,
+    name: Option<Alias>,
+}
+
+impl UpdateAliasRequest {
+    pub fn builder() -> UpdateAliasRequestBuilder {
+        UpdateAliasRequestBuilder::default()
+    }
+}
+
+impl UpdateAliasRequestBuilder {
+    /// You can optionally change the description of the alias using this parameter.
+    pub fn description<V: Into<Description>>(mut self, value: V) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// The function name for which the alias is created.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Using this parameter you can optionally change the Lambda function version to which the
+    /// alias to points to.
+    pub fn function_version<V: Into<Version>>(mut self, value: V) -> Self {
+        self.function_version = Some(value.into());
+        self
+    }
+
+    /// The alias name.
+    pub fn name<V: Into<Alias>>(mut self, value: V) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<UpdateAliasRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if self.name.is_none() {
+            missing.push("Name");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "UpdateAliasRequest", members: missing });
+        }
+        Ok(UpdateAliasRequest {
+            description: self.description,
+            function_name: self.function_name.unwrap(),
+            function_version: self.function_version,
+            name: self.name.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `UpdateEventSourceMappingRequest`; start with `UpdateEventSourceMappingRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct UpdateEventSourceMappingRequestBuilder {
+    batch_size: Option<BatchSize>,
+    enabled: Option<Enabled>,
+    function_name: Option<FunctionName>,
+    uuid: Option<StringType>,
+}
+
+impl UpdateEventSourceMappingRequest {
+    pub fn builder() -> UpdateEventSourceMappingRequestBuilder {
+        UpdateEventSourceMappingRequestBuilder::default()
+    }
+}
+
+impl UpdateEventSourceMappingRequestBuilder {
+    /// The maximum number of stream records that can be sent to your Lambda function for a single
+    /// invocation.
+    pub fn batch_size<V: Into<BatchSize>>(mut self, value: V) -> Self {
+        self.batch_size = Some(value.into());
+        self
+    }
+
+    /// Specifies whether AWS Lambda should actively poll the stream or not. If disabled, AWS Lambda
+    /// will not poll the stream.
+    pub fn enabled<V: Into<Enabled>>(mut self, value: V) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// The Lambda function to which you want the stream records sent.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// The event source mapping identifier.
+    pub fn uuid<V: Into<StringType>>(mut self, value: V) -> Self {
+        self.uuid = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<UpdateEventSourceMappingRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.uuid.is_none() {
+            missing.push("UUID");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "UpdateEventSourceMappingRequest", members: missing });
+        }
+        Ok(UpdateEventSourceMappingRequest {
+            batch_size: self.batch_size,
+            enabled: self.enabled,
+            function_name: self.function_name,
+            uuid: self.uuid.unwrap(),
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `UpdateFunctionCodeRequest`; start with `UpdateFunctionCodeRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct UpdateFunctionCodeRequestBuilder {
+    function_name: Option<FunctionName>,
+    publish: Option<Boolean>,
+    s3_bucket: Option<S3Bucket>,
+    s3_key: Option<S3Key>,
+    s3_object_version: Option<S3ObjectVersion>,
+    zip_file: Option<Blob>,
+}
+
+impl UpdateFunctionCodeRequest {
+    pub fn builder() -> UpdateFunctionCodeRequestBuilder {
+        UpdateFunctionCodeRequestBuilder::default()
+    }
+}
+
+impl UpdateFunctionCodeRequestBuilder {
+    /// The existing Lambda function name whose code you want to replace.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// This boolean parameter can be used to request AWS Lambda to update the Lambda function and
+    /// publish a version as an atomic operation.
+    pub fn publish<V: Into<Boolean>>(mut self, value: V) -> Self {
+        self.publish = Some(value.into());
+        self
+    }
+
+    /// Amazon S3 bucket name where the .zip file containing your deployment package is stored. This
+    /// bucket must reside in the same AWS region where you are creating the Lambda function.
+    pub fn s3_bucket<V: Into<S3Bucket>>(mut self, value: V) -> Self {
+        self.s3_bucket = Some(value.into());
+        self
+    }
+
+    /// The Amazon S3 object (the deployment package) key name you want to upload.
+    pub fn s3_key<V: Into<S3Key>>(mut self, value: V) -> Self {
+        self.s3_key = Some(value.into());
+        self
+    }
+
+    /// The Amazon S3 object (the deployment package) version you want to upload.
+    pub fn s3_object_version<V: Into<S3ObjectVersion>>(mut self, value: V) -> Self {
+        self.s3_object_version = Some(value.into());
+        self
+    }
+
+    /// Based64-encoded .zip file containing your packaged source code.
+    pub fn zip_file<V: Into<Blob>>(mut self, value: V) -> Self {
+        self.zip_file = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<UpdateFunctionCodeRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "UpdateFunctionCodeRequest", members: missing });
+        }
+        Ok(UpdateFunctionCodeRequest {
+            function_name: self.function_name.unwrap(),
+            publish: self.publish,
+            s3_bucket: self.s3_bucket,
+            s3_key: self.s3_key,
+            s3_object_version: self.s3_object_version,
+            zip_file: self.zip_file,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+/// Builds a `UpdateFunctionConfigurationRequest`; start with `UpdateFunctionConfigurationRequest::builder()`.
+#[derive(Debug, Default)]
+pub struct UpdateFunctionConfigurationRequestBuilder {
+    description: Option<Description>,
+    function_name: Option<FunctionName>,
+    handler: Option<Handler>,
+    memory_size: Option<MemorySize>,
+    role: Option<RoleArn>,
+    timeout: Option<Timeout>,
+}
+
+impl UpdateFunctionConfigurationRequest {
+    pub fn builder() -> UpdateFunctionConfigurationRequestBuilder {
+        UpdateFunctionConfigurationRequestBuilder::default()
+    }
+}
+
+impl UpdateFunctionConfigurationRequestBuilder {
+    /// A short user-defined function description. AWS Lambda does not use this value. Assign a
+    /// meaningful description as you see fit.
+    pub fn description<V: Into<Description>>(mut self, value: V) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// The name of the Lambda function.
+    ///
+    /// You can specify an unqualified function name (for example, "Thumbnail") or you can specify
+    /// Amazon Resource Name (ARN) of the function (for example,
+    /// "arn:aws:lambda:us-west-2:account-id:function:ThumbNail"). AWS Lambda also allows you to
+    /// specify only the account ID qualifier (for example, "account-id:Thumbnail"). Note that the
+    /// length constraint applies only to the ARN. If you specify only the function name, it is
+    /// limited to 64 character in length.
+    pub fn function_name<V: Into<FunctionName>>(mut self, value: V) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// The function that Lambda calls to begin executing your function. For Node.js, it is the
+    /// *module-name.export* value in your function.
+    pub fn handler<V: Into<Handler>>(mut self, value: V) -> Self {
+        self.handler = Some(value.into());
+        self
+    }
+
+    /// The amount of memory, in MB, your Lambda function is given. AWS Lambda uses this memory size
+    /// to infer the amount of CPU allocated to your function. Your function use-case determines
+    /// your CPU and memory requirements. For example, a database operation might need less memory
+    /// compared to an image processing function. The default value is 128 MB. The value must be a
+    /// multiple of 64 MB.
+    pub fn memory_size<V: Into<MemorySize>>(mut self, value: V) -> Self {
+        self.memory_size = Some(value.into());
+        self
+    }
+
+    /// The Amazon Resource Name (ARN) of the IAM role that Lambda will assume when it executes your
+    /// function.
+    pub fn role<V: Into<RoleArn>>(mut self, value: V) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// The function execution time at which AWS Lambda should terminate the function. Because the
+    /// execution time has cost implications, we recommend you set this value based on your expected
+    /// execution time. The default is 3 seconds.
+    pub fn timeout<V: Into<Timeout>>(mut self, value: V) -> Self {
+        self.timeout = Some(value.into());
+        self
+    }
+
+    /// Fails with the names of any required members that were not set.
+    pub fn build(self) -> Result<UpdateFunctionConfigurationRequest, ::runtime::builder::MissingMembers> {
+        let mut missing = vec!();
+        if self.function_name.is_none() {
+            missing.push("FunctionName");
+        }
+        if !missing.is_empty() {
+            return Err(::runtime::builder::MissingMembers { shape: "UpdateFunctionConfigurationRequest", members: missing });
+        }
+        Ok(UpdateFunctionConfigurationRequest {
+            description: self.description,
+            function_name: self.function_name.unwrap(),
+            handler: self.handler,
+            memory_size: self.memory_size,
+            role: self.role,
+            timeout: self.timeout,
+            __non_exhaustive: (),
+        })
+    }
+}
+
+impl AddPermissionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for AddPermissionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.action;
+            let path = ::runtime::validate::member(path, "Action");
+            ::runtime::validate::pattern(&path, value, "(lambda:[*]|lambda:[a-zA-Z]+|[*])", violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+        if let Some(ref value) = self.source_account {
+            let path = ::runtime::validate::member(path, "SourceAccount");
+            ::runtime::validate::pattern(&path, value, "\\d{12}", violations);
+        }
+        if let Some(ref value) = self.source_arn {
+            let path = ::runtime::validate::member(path, "SourceArn");
+            ::runtime::validate::pattern(&path, value, "arn:aws:([a-zA-Z0-9\\-])+:([a-z]{2}-[a-z]+-\\d{1})?:(\\d{12})?:(.*)", violations);
+        }
+        {
+            let value = &self.statement_id;
+            let path = ::runtime::validate::member(path, "StatementId");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(100), violations);
+            ::runtime::validate::pattern(&path, value, "([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl CreateAliasRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for CreateAliasRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.description {
+            let path = ::runtime::validate::member(path, "Description");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(256), violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        {
+            let value = &self.function_version;
+            let path = ::runtime::validate::member(path, "FunctionVersion");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+            ::runtime::validate::pattern(&path, value, "(\\$LATEST|[0-9]+)", violations);
+        }
+        {
+            let value = &self.name;
+            let path = ::runtime::validate::member(path, "Name");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(?!^[0-9]+$)([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl CreateEventSourceMappingRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for CreateEventSourceMappingRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if self.starting_position.is_none() {
+            ::runtime::validate::missing(path, "StartingPosition", violations);
+        }
+        if let Some(ref value) = self.batch_size {
+            let path = ::runtime::validate::member(path, "BatchSize");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+        {
+            let value = &self.event_source_arn;
+            let path = ::runtime::validate::member(path, "EventSourceArn");
+            ::runtime::validate::pattern(&path, value, "arn:aws:([a-zA-Z0-9\\-])+:([a-z]{2}-[a-z]+-\\d{1})?:(\\d{12})?:(.*)", violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+    }
+}
+
+impl CreateFunctionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for CreateFunctionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if self.runtime.is_none() {
+            ::runtime::validate::missing(path, "Runtime", violations);
+        }
+        {
+            let value = &self.code;
+            let path = ::runtime::validate::member(path, "Code");
+            ::runtime::validate::Validate::check(value, &path, violations);
+        }
+        if let Some(ref value) = self.description {
+            let path = ::runtime::validate::member(path, "Description");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(256), violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        {
+            let value = &self.handler;
+            let path = ::runtime::validate::member(path, "Handler");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "[^\\s]+", violations);
+        }
+        if let Some(ref value) = self.memory_size {
+            let path = ::runtime::validate::member(path, "MemorySize");
+            ::runtime::validate::range(&path, *value as i64, Some(128), Some(1536), violations);
+        }
+        {
+            let value = &self.role;
+            let path = ::runtime::validate::member(path, "Role");
+            ::runtime::validate::pattern(&path, value, "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+", violations);
+        }
+        if let Some(ref value) = self.timeout {
+            let path = ::runtime::validate::member(path, "Timeout");
+            ::runtime::validate::range(&path, *value as i64, Some(1), None, violations);
+        }
+    }
+}
+
+impl DeleteAliasRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for DeleteAliasRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        {
+            let value = &self.name;
+            let path = ::runtime::validate::member(path, "Name");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(?!^[0-9]+$)([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl DeleteEventSourceMappingRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for DeleteEventSourceMappingRequest {
+    fn check(&self, _path: &str, _violations: &mut Vec<::runtime::validate::Violation>) {
+    }
+}
+
+impl DeleteFunctionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for DeleteFunctionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+    }
+}
+
+impl ::runtime::validate::Validate for FunctionCode {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.s3_bucket {
+            let path = ::runtime::validate::member(path, "S3Bucket");
+            ::runtime::validate::length(&path, value.chars().count(), Some(3), Some(63), violations);
+            ::runtime::validate::pattern(&path, value, "^[0-9A-Za-z\\.\\-_]*(?<!\\.)$", violations);
+        }
+        if let Some(ref value) = self.s3_key {
+            let path = ::runtime::validate::member(path, "S3Key");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+        }
+        if let Some(ref value) = self.s3_object_version {
+            let path = ::runtime::validate::member(path, "S3ObjectVersion");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+        }
+    }
+}
+
+impl GetAliasRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for GetAliasRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        {
+            let value = &self.name;
+            let path = ::runtime::validate::member(path, "Name");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(?!^[0-9]+$)([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl GetEventSourceMappingRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for GetEventSourceMappingRequest {
+    fn check(&self, _path: &str, _violations: &mut Vec<::runtime::validate::Violation>) {
+    }
+}
+
+impl GetFunctionConfigurationRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for GetFunctionConfigurationRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+    }
+}
+
+impl GetFunctionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for GetFunctionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+    }
+}
+
+impl GetPolicyRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for GetPolicyRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+    }
+}
+
+impl InvocationRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for InvocationRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl InvokeAsyncRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+#[allow(deprecated)]
+impl ::runtime::validate::Validate for InvokeAsyncRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+    }
+}
+
+impl ListAliasesRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for ListAliasesRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.function_version {
+            let path = ::runtime::validate::member(path, "FunctionVersion");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+            ::runtime::validate::pattern(&path, value, "(\\$LATEST|[0-9]+)", violations);
+        }
+        if let Some(ref value) = self.max_items {
+            let path = ::runtime::validate::member(path, "MaxItems");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+    }
+}
+
+impl ListEventSourceMappingsRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for ListEventSourceMappingsRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.event_source_arn {
+            let path = ::runtime::validate::member(path, "EventSourceArn");
+            ::runtime::validate::pattern(&path, value, "arn:aws:([a-zA-Z0-9\\-])+:([a-z]{2}-[a-z]+-\\d{1})?:(\\d{12})?:(.*)", violations);
+        }
+        if let Some(ref value) = self.function_name {
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.max_items {
+            let path = ::runtime::validate::member(path, "MaxItems");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+    }
+}
+
+impl ListFunctionsRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for ListFunctionsRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.max_items {
+            let path = ::runtime::validate::member(path, "MaxItems");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+    }
+}
+
+impl ListVersionsByFunctionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for ListVersionsByFunctionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.max_items {
+            let path = ::runtime::validate::member(path, "MaxItems");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+    }
+}
+
+impl PublishVersionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for PublishVersionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.description {
+            let path = ::runtime::validate::member(path, "Description");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(256), violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+    }
+}
+
+impl RemovePermissionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for RemovePermissionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+        {
+            let value = &self.statement_id;
+            let path = ::runtime::validate::member(path, "StatementId");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(100), violations);
+            ::runtime::validate::pattern(&path, value, "([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl UpdateAliasRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for UpdateAliasRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.description {
+            let path = ::runtime::validate::member(path, "Description");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(256), violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.function_version {
+            let path = ::runtime::validate::member(path, "FunctionVersion");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+            ::runtime::validate::pattern(&path, value, "(\\$LATEST|[0-9]+)", violations);
+        }
+        {
+            let value = &self.name;
+            let path = ::runtime::validate::member(path, "Name");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(?!^[0-9]+$)([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl UpdateEventSourceMappingRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for UpdateEventSourceMappingRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.batch_size {
+            let path = ::runtime::validate::member(path, "BatchSize");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+        if let Some(ref value) = self.function_name {
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+    }
+}
+
+impl UpdateFunctionCodeRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for UpdateFunctionCodeRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.s3_bucket {
+            let path = ::runtime::validate::member(path, "S3Bucket");
+            ::runtime::validate::length(&path, value.chars().count(), Some(3), Some(63), violations);
+            ::runtime::validate::pattern(&path, value, "^[0-9A-Za-z\\.\\-_]*(?<!\\.)$", violations);
+        }
+        if let Some(ref value) = self.s3_key {
+            let path = ::runtime::validate::member(path, "S3Key");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+        }
+        if let Some(ref value) = self.s3_object_version {
+            let path = ::runtime::validate::member(path, "S3ObjectVersion");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+        }
+    }
+}
+
+impl UpdateFunctionConfigurationRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for UpdateFunctionConfigurationRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.description {
+            let path = ::runtime::validate::member(path, "Description");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(256), violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.handler {
+            let path = ::runtime::validate::member(path, "Handler");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "[^\\s]+", violations);
+        }
+        if let Some(ref value) = self.memory_size {
+            let path = ::runtime::validate::member(path, "MemorySize");
+            ::runtime::validate::range(&path, *value as i64, Some(128), Some(1536), violations);
+        }
+        if let Some(ref value) = self.role {
+            let path = ::runtime::validate::member(path, "Role");
+            ::runtime::validate::pattern(&path, value, "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+", violations);
+        }
+        if let Some(ref value) = self.timeout {
+            let path = ::runtime::validate::member(path, "Timeout");
+            ::runtime::validate::range(&path, *value as i64, Some(1), None, violations);
+        }
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+    use serde::{Serialize, Deserialize};
+    use serde_json::{self, Value};
+
+    fn without_nulls(value: Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(map.into_iter()
+                .filter(|&(_, ref v)| *v != Value::Null)
+                .map(|(k, v)| (k, without_nulls(v)))
+                .collect()),
+            Value::Array(array) => Value::Array(array.into_iter().map(without_nulls).collect()),
+            value => value,
+        }
+    }
+
+    fn round_trip<T: Serialize + Deserialize>(json: &str) {
+        let expected: Value = serde_json::from_str(json).unwrap();
+        let value: T = serde_json::from_str(json).unwrap();
+        assert_eq!(without_nulls(serde_json::to_value(&value)), expected);
+    }
+}
@@ -0,0 +1,15 @@
+//! How requests to AWS Lambda are addressed, encoded and signed.
+
+/// The version of the service definition this package was generated from.
+pub const API_VERSION: &'static str = "2015-03-31";
+/// How requests are encoded: `rest-json`, `rest-xml`, `json`, `query` or `ec2`.
+pub const PROTOCOL: &'static str = "rest-json";
+
+/// Signs `request` for AWS Lambda with AWS Signature Version 4.
+/// `date` is the request time formatted as `20150830T123600Z`.
+pub fn sign(request: &mut ::runtime::signature::SignedRequest,
+            credentials: &::runtime::signature::Credentials,
+            region: &str,
+            date: &str) {
+    request.sign(credentials, region, "lambda", date);
+}
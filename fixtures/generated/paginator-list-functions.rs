@@ -0,0 +1,46 @@
+/// Pages through `ListFunctions`; see `Lambda::list_functions_pages()`.
+pub struct ListFunctionsPages<'a, C: 'a + Lambda> {
+    client: &'a C,
+    input: ListFunctionsRequest,
+    done: bool,
+}
+
+impl<'a, C: Lambda> ListFunctionsPages<'a, C> {
+    /// Asks for at most `size` results a page, as `MaxItems`.
+    pub fn page_size(mut self, size: MaxListItems) -> Self {
+        self.input.max_items = Some(size);
+        self
+    }
+
+    /// Iterates over the `Functions` of every page instead of the pages.
+    pub fn items(self) -> Box<Iterator<Item = Result<FunctionConfiguration, C::Error>> + 'a> {
+        Box::new(self.flat_map(|page| match page {
+            Ok(output) => {
+                let items = Some(output).and_then(|v| v.functions).unwrap_or(vec!());
+                items.into_iter().map(Ok).collect::<Vec<_>>()
+            }
+            Err(err) => vec!(Err(err)),
+        }))
+    }
+}
+
+impl<'a, C: Lambda> Iterator for ListFunctionsPages<'a, C> {
+    type Item = Result<ListFunctionsResponse, C::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let output = match self.client.list_functions(&self.input) {
+            Ok(output) => output,
+            Err(err) => {
+                self.done = true;
+                return Some(Err(err));
+            }
+        };
+        let marker = Some(&output).and_then(|v| v.next_marker.as_ref()).cloned();
+        self.done = marker.is_none() || marker == self.input.marker;
+        self.input.marker = marker;
+        Some(Ok(output))
+    }
+}
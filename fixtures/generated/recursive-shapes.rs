@@ -0,0 +1,67 @@
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EvaluateRequest {
+    #[serde(rename = "Expression")]
+    pub expression: Expression,
+    #[serde(rename = "Labels")]
+    pub labels: Option<LabelList>,
+    #[serde(rename = "Trace")]
+    pub trace: Option<Node>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EvaluateResponse {
+    #[serde(rename = "Result")]
+    pub result: Option<StringType>,
+    #[serde(rename = "Tree")]
+    pub tree: Option<Tree>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Expression {
+    #[serde(rename = "Left")]
+    pub left: Operand,
+    #[serde(rename = "Operator")]
+    pub operator: StringType,
+    #[serde(rename = "Right")]
+    pub right: Option<Operand>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type LabelList = Vec<StringType>;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Node {
+    #[serde(rename = "Next")]
+    pub next: Option<Box<Node>>,
+    #[serde(rename = "Value")]
+    pub value: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Operand {
+    #[serde(rename = "Expression")]
+    pub expression: Option<Box<Expression>>,
+    #[serde(rename = "Literal")]
+    pub literal: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type StringType = String;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Tree {
+    #[serde(rename = "Children")]
+    pub children: Option<TreeList>,
+    #[serde(rename = "Name")]
+    pub name: Option<StringType>,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
+}
+pub type TreeList = Vec<Tree>;
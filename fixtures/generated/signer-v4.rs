@@ -0,0 +1,8 @@
+/// Signs `request` for AWS Lambda with AWS Signature Version 4.
+/// `date` is the request time formatted as `20150830T123600Z`.
+pub fn sign(request: &mut ::runtime::signature::SignedRequest,
+            credentials: &::runtime::signature::Credentials,
+            region: &str,
+            date: &str) {
+    request.sign(credentials, region, "lambda", date);
+}
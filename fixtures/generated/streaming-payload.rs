@@ -0,0 +1,11 @@
+pub type BlobStream = ::runtime::stream::ByteStream;
+#[deprecated(note = "deprecated in the service model")]
+#[derive(Debug, Default)]
+pub struct InvokeAsyncRequest {
+    /// The Lambda function name.
+    pub function_name: FunctionName,
+    /// JSON that you want to provide to your Lambda function as input.
+    pub invoke_args: BlobStream,
+    #[doc(hidden)]
+    pub __non_exhaustive: (),
+}
@@ -1,6 +1,9 @@
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum WhereIsCarmenSanDiego {
     Berlin,
     Madrid,
     Toronto,
     Beijing,
-};
+    #[doc(hidden)]
+    __NonExhaustive,
+}
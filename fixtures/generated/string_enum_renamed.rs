@@ -0,0 +1,11 @@
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Runtime {
+    #[serde(rename = "nodejs")]
+    Nodejs,
+    #[serde(rename = "python2.7")]
+    Python27,
+    #[serde(rename = "nodejs4.3-edge")]
+    Nodejs43Edge,
+    #[doc(hidden)]
+    __NonExhaustive,
+}
@@ -1,5 +1,8 @@
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct GenieInABottle {
     pub owner: Option<Person>,
-    pub wishes: integer,
+    pub wishes: Integer,
+    #[doc(hidden)]
+    #[serde(skip_serializing, default)]
+    pub __non_exhaustive: (),
 }
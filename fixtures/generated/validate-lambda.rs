@@ -0,0 +1,644 @@
+impl AddPermissionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for AddPermissionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.action;
+            let path = ::runtime::validate::member(path, "Action");
+            ::runtime::validate::pattern(&path, value, "(lambda:[*]|lambda:[a-zA-Z]+|[*])", violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+        if let Some(ref value) = self.source_account {
+            let path = ::runtime::validate::member(path, "SourceAccount");
+            ::runtime::validate::pattern(&path, value, "\\d{12}", violations);
+        }
+        if let Some(ref value) = self.source_arn {
+            let path = ::runtime::validate::member(path, "SourceArn");
+            ::runtime::validate::pattern(&path, value, "arn:aws:([a-zA-Z0-9\\-])+:([a-z]{2}-[a-z]+-\\d{1})?:(\\d{12})?:(.*)", violations);
+        }
+        {
+            let value = &self.statement_id;
+            let path = ::runtime::validate::member(path, "StatementId");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(100), violations);
+            ::runtime::validate::pattern(&path, value, "([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl CreateAliasRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for CreateAliasRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.description {
+            let path = ::runtime::validate::member(path, "Description");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(256), violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        {
+            let value = &self.function_version;
+            let path = ::runtime::validate::member(path, "FunctionVersion");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+            ::runtime::validate::pattern(&path, value, "(\\$LATEST|[0-9]+)", violations);
+        }
+        {
+            let value = &self.name;
+            let path = ::runtime::validate::member(path, "Name");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(?!^[0-9]+$)([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl CreateEventSourceMappingRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for CreateEventSourceMappingRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if self.starting_position.is_none() {
+            ::runtime::validate::missing(path, "StartingPosition", violations);
+        }
+        if let Some(ref value) = self.batch_size {
+            let path = ::runtime::validate::member(path, "BatchSize");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+        {
+            let value = &self.event_source_arn;
+            let path = ::runtime::validate::member(path, "EventSourceArn");
+            ::runtime::validate::pattern(&path, value, "arn:aws:([a-zA-Z0-9\\-])+:([a-z]{2}-[a-z]+-\\d{1})?:(\\d{12})?:(.*)", violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+    }
+}
+
+impl CreateFunctionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for CreateFunctionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if self.runtime.is_none() {
+            ::runtime::validate::missing(path, "Runtime", violations);
+        }
+        {
+            let value = &self.code;
+            let path = ::runtime::validate::member(path, "Code");
+            ::runtime::validate::Validate::check(value, &path, violations);
+        }
+        if let Some(ref value) = self.description {
+            let path = ::runtime::validate::member(path, "Description");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(256), violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        {
+            let value = &self.handler;
+            let path = ::runtime::validate::member(path, "Handler");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "[^\\s]+", violations);
+        }
+        if let Some(ref value) = self.memory_size {
+            let path = ::runtime::validate::member(path, "MemorySize");
+            ::runtime::validate::range(&path, *value as i64, Some(128), Some(1536), violations);
+        }
+        {
+            let value = &self.role;
+            let path = ::runtime::validate::member(path, "Role");
+            ::runtime::validate::pattern(&path, value, "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+", violations);
+        }
+        if let Some(ref value) = self.timeout {
+            let path = ::runtime::validate::member(path, "Timeout");
+            ::runtime::validate::range(&path, *value as i64, Some(1), None, violations);
+        }
+    }
+}
+
+impl DeleteAliasRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for DeleteAliasRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        {
+            let value = &self.name;
+            let path = ::runtime::validate::member(path, "Name");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(?!^[0-9]+$)([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl DeleteEventSourceMappingRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for DeleteEventSourceMappingRequest {
+    fn check(&self, _path: &str, _violations: &mut Vec<::runtime::validate::Violation>) {
+    }
+}
+
+impl DeleteFunctionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for DeleteFunctionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+    }
+}
+
+impl ::runtime::validate::Validate for FunctionCode {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.s3_bucket {
+            let path = ::runtime::validate::member(path, "S3Bucket");
+            ::runtime::validate::length(&path, value.chars().count(), Some(3), Some(63), violations);
+            ::runtime::validate::pattern(&path, value, "^[0-9A-Za-z\\.\\-_]*(?<!\\.)$", violations);
+        }
+        if let Some(ref value) = self.s3_key {
+            let path = ::runtime::validate::member(path, "S3Key");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+        }
+        if let Some(ref value) = self.s3_object_version {
+            let path = ::runtime::validate::member(path, "S3ObjectVersion");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+        }
+    }
+}
+
+impl GetAliasRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for GetAliasRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        {
+            let value = &self.name;
+            let path = ::runtime::validate::member(path, "Name");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(?!^[0-9]+$)([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl GetEventSourceMappingRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for GetEventSourceMappingRequest {
+    fn check(&self, _path: &str, _violations: &mut Vec<::runtime::validate::Violation>) {
+    }
+}
+
+impl GetFunctionConfigurationRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for GetFunctionConfigurationRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+    }
+}
+
+impl GetFunctionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for GetFunctionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+    }
+}
+
+impl GetPolicyRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for GetPolicyRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+    }
+}
+
+impl InvocationRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for InvocationRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl InvokeAsyncRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+#[allow(deprecated)]
+impl ::runtime::validate::Validate for InvokeAsyncRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+    }
+}
+
+impl ListAliasesRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for ListAliasesRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.function_version {
+            let path = ::runtime::validate::member(path, "FunctionVersion");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+            ::runtime::validate::pattern(&path, value, "(\\$LATEST|[0-9]+)", violations);
+        }
+        if let Some(ref value) = self.max_items {
+            let path = ::runtime::validate::member(path, "MaxItems");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+    }
+}
+
+impl ListEventSourceMappingsRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for ListEventSourceMappingsRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.event_source_arn {
+            let path = ::runtime::validate::member(path, "EventSourceArn");
+            ::runtime::validate::pattern(&path, value, "arn:aws:([a-zA-Z0-9\\-])+:([a-z]{2}-[a-z]+-\\d{1})?:(\\d{12})?:(.*)", violations);
+        }
+        if let Some(ref value) = self.function_name {
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.max_items {
+            let path = ::runtime::validate::member(path, "MaxItems");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+    }
+}
+
+impl ListFunctionsRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for ListFunctionsRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.max_items {
+            let path = ::runtime::validate::member(path, "MaxItems");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+    }
+}
+
+impl ListVersionsByFunctionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for ListVersionsByFunctionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.max_items {
+            let path = ::runtime::validate::member(path, "MaxItems");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+    }
+}
+
+impl PublishVersionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for PublishVersionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.description {
+            let path = ::runtime::validate::member(path, "Description");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(256), violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+    }
+}
+
+impl RemovePermissionRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for RemovePermissionRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.qualifier {
+            let path = ::runtime::validate::member(path, "Qualifier");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(|[a-zA-Z0-9$_]+)", violations);
+        }
+        {
+            let value = &self.statement_id;
+            let path = ::runtime::validate::member(path, "StatementId");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(100), violations);
+            ::runtime::validate::pattern(&path, value, "([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl UpdateAliasRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for UpdateAliasRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.description {
+            let path = ::runtime::validate::member(path, "Description");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(256), violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.function_version {
+            let path = ::runtime::validate::member(path, "FunctionVersion");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+            ::runtime::validate::pattern(&path, value, "(\\$LATEST|[0-9]+)", violations);
+        }
+        {
+            let value = &self.name;
+            let path = ::runtime::validate::member(path, "Name");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "(?!^[0-9]+$)([a-zA-Z0-9-_]+)", violations);
+        }
+    }
+}
+
+impl UpdateEventSourceMappingRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for UpdateEventSourceMappingRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.batch_size {
+            let path = ::runtime::validate::member(path, "BatchSize");
+            ::runtime::validate::range(&path, *value as i64, Some(1), Some(10000), violations);
+        }
+        if let Some(ref value) = self.function_name {
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+    }
+}
+
+impl UpdateFunctionCodeRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for UpdateFunctionCodeRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.s3_bucket {
+            let path = ::runtime::validate::member(path, "S3Bucket");
+            ::runtime::validate::length(&path, value.chars().count(), Some(3), Some(63), violations);
+            ::runtime::validate::pattern(&path, value, "^[0-9A-Za-z\\.\\-_]*(?<!\\.)$", violations);
+        }
+        if let Some(ref value) = self.s3_key {
+            let path = ::runtime::validate::member(path, "S3Key");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+        }
+        if let Some(ref value) = self.s3_object_version {
+            let path = ::runtime::validate::member(path, "S3ObjectVersion");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(1024), violations);
+        }
+    }
+}
+
+impl UpdateFunctionConfigurationRequest {
+    /// Checks the request against the constraints of its shapes, without sending it.
+    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {
+        ::runtime::validate::validate(self)
+    }
+}
+
+impl ::runtime::validate::Validate for UpdateFunctionConfigurationRequest {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.description {
+            let path = ::runtime::validate::member(path, "Description");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(256), violations);
+        }
+        {
+            let value = &self.function_name;
+            let path = ::runtime::validate::member(path, "FunctionName");
+            ::runtime::validate::length(&path, value.chars().count(), Some(1), Some(140), violations);
+            ::runtime::validate::pattern(&path, value, "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?", violations);
+        }
+        if let Some(ref value) = self.handler {
+            let path = ::runtime::validate::member(path, "Handler");
+            ::runtime::validate::length(&path, value.chars().count(), None, Some(128), violations);
+            ::runtime::validate::pattern(&path, value, "[^\\s]+", violations);
+        }
+        if let Some(ref value) = self.memory_size {
+            let path = ::runtime::validate::member(path, "MemorySize");
+            ::runtime::validate::range(&path, *value as i64, Some(128), Some(1536), violations);
+        }
+        if let Some(ref value) = self.role {
+            let path = ::runtime::validate::member(path, "Role");
+            ::runtime::validate::pattern(&path, value, "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+", violations);
+        }
+        if let Some(ref value) = self.timeout {
+            let path = ::runtime::validate::member(path, "Timeout");
+            ::runtime::validate::range(&path, *value as i64, Some(1), None, violations);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+    /// Polls `ListFunctions` every 10 seconds, at most 30 times.
+    fn wait_until_all_functions_updated(&self, input: &ListFunctionsRequest) -> Result<(), ::runtime::waiter::WaiterError<Self::Error>>
+        where Self: Sized, Self::Error: ::runtime::waiter::ServiceError
+    {
+        ::runtime::waiter::wait(10, 30, || self.list_functions(input), |result| {
+            match *result {
+                Ok(ref output) => {
+                    if { let values = vec!(output).into_iter().filter_map(|v| v.functions.as_ref()).flat_map(|v| v.iter()).filter_map(|v| v.runtime.as_ref()).collect::<Vec<_>>(); !values.is_empty() && values.iter().all(|v| match **v { Runtime::Java8 => true, _ => false }) } {
+                        return Some(::runtime::waiter::State::Success);
+                    }
+                    if vec!(output).into_iter().filter_map(|v| v.functions.as_ref()).flat_map(|v| v.iter()).filter_map(|v| v.memory_size.as_ref()).collect::<Vec<_>>().iter().any(|v| **v == 128) {
+                        return Some(::runtime::waiter::State::Failure);
+                    }
+                }
+                Err(_) => {},
+            }
+            None
+        })
+    }
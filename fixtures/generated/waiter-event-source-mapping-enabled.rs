@@ -0,0 +1,20 @@
+    /// Waits for an event source mapping to finish enabling.
+    /// Polls `GetEventSourceMapping` every 5 seconds, at most 60 times.
+    fn wait_until_event_source_mapping_enabled(&self, input: &GetEventSourceMappingRequest) -> Result<(), ::runtime::waiter::WaiterError<Self::Error>>
+        where Self: Sized, Self::Error: ::runtime::waiter::ServiceError
+    {
+        ::runtime::waiter::wait(5, 60, || self.get_event_source_mapping(input), |result| {
+            match *result {
+                Ok(ref output) => {
+                    if vec!(output).into_iter().filter_map(|v| v.state.as_ref()).collect::<Vec<_>>().first().map_or(false, |v| **v == "Enabled") {
+                        return Some(::runtime::waiter::State::Success);
+                    }
+                    if vec!(output).into_iter().filter_map(|v| v.state.as_ref()).collect::<Vec<_>>().first().map_or(false, |v| **v == "Disabled") {
+                        return Some(::runtime::waiter::State::Failure);
+                    }
+                }
+                Err(_) => {},
+            }
+            None
+        })
+    }
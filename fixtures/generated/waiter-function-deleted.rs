@@ -0,0 +1,16 @@
+    /// Polls `DeleteFunction` every 2 seconds, at most 10 times.
+    fn wait_until_function_deleted(&self, input: &DeleteFunctionRequest) -> Result<(), ::runtime::waiter::WaiterError<Self::Error>>
+        where Self: Sized, Self::Error: ::runtime::waiter::ServiceError
+    {
+        ::runtime::waiter::wait(2, 10, || self.delete_function(input), |result| {
+            match *result {
+                Ok(_) => return Some(::runtime::waiter::State::Success),
+                Err(ref err) => {
+                    if ::runtime::waiter::ServiceError::code(err) == "ResourceNotFoundException" {
+                        return Some(::runtime::waiter::State::Success);
+                    }
+                }
+            }
+            None
+        })
+    }
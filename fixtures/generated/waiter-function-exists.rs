@@ -0,0 +1,16 @@
+    /// Polls `GetFunction` every second, at most 20 times.
+    fn wait_until_function_exists(&self, input: &GetFunctionRequest) -> Result<(), ::runtime::waiter::WaiterError<Self::Error>>
+        where Self: Sized, Self::Error: ::runtime::waiter::ServiceError
+    {
+        ::runtime::waiter::wait(1, 20, || self.get_function(input), |result| {
+            match *result {
+                Ok(_) => return Some(::runtime::waiter::State::Success),
+                Err(ref err) => {
+                    if ::runtime::waiter::ServiceError::code(err) == "ResourceNotFoundException" {
+                        return Some(::runtime::waiter::State::Retry);
+                    }
+                }
+            }
+            None
+        })
+    }
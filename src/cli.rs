@@ -0,0 +1,851 @@
+//! The `aws-service-parser` command line.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use serde_json::{self, Value};
+use ::generater::package::Runtime;
+use ::export::DotOptions;
+use ::generater::semver::Bump;
+use ::generator::{Diagnostic, Generator, SkipReason};
+use ::parser::ServiceDefinition;
+use ::parser::diff::Changes;
+use ::parser::load::LoadError;
+
+pub const USAGE: &'static str = "\
+usage: aws-service-parser <command> [--format text|json] <arguments>
+
+commands:
+    generate <model.json> -o <dir> [--endpoints <endpoints.json>] [--crate [--runtime <dir>]]
+             [--overlay <overlay.json>]... [--operation <name>]... [--omit-deprecated]
+        Write the generated module for a service into <dir>. With --crate, write a whole
+        Cargo package instead, depending on the aws-service-parser checkout in --runtime
+        so that it builds offline, or else on the published version. Each overlay renames
+        shapes, changes members, enums and documentation, removes operations or adds
+        derives before generating. With --operation, generate only those operations and
+        the shapes they use, and report how much was left out. With --omit-deprecated,
+        leave out what the model deprecates instead of marking it #[deprecated].
+    generate-all <data-dir> -o <dir> [--pin <service>=<api-version>]... [--jobs <n>]
+        Generate every service in a botocore data directory, at its latest API version
+        unless pinned, and report which were generated and which were skipped. Takes
+        the options of generate as well. Each output is named after the service's
+        directory, since services can share an endpoint prefix.
+    validate <model.json>...
+        Parse service definitions and report what is wrong with them, and which
+        examples were left out for not fitting their operation.
+    inspect <model.json>
+        List a service's metadata, operations, shapes, paginators and waiters.
+    diff <old.json> <new.json>
+        Report what changed between two versions of a service: operations, shapes,
+        members and whether they are required, enum values, constraints, HTTP bindings,
+        errors and their status codes.
+    semver <old.json> <new.json> [--version <current>] [--allow-major]
+        Classify the changes between two versions by what they do to the generated
+        crate and recommend the next version. Fails unless --allow-major is given when
+        the changes are breaking.
+    export dot <model.json> [--operation <name>] [--collapse-primitives] [-o <file>]
+        Write the graph of operations and shapes in Graphviz DOT, only for one operation
+        with --operation, and without nodes for strings, numbers and enums with
+        --collapse-primitives.
+    export openapi <model.json> [-o <file>]
+        Write a rest-json service as an OpenAPI 3.0 document.
+    export botocore <model.json> [-o <file>]
+        Write the model back out as a botocore service-2.json, with its keys sorted.
+        With -o, its paginators-1.json and waiters-2.json go next to <file>; on
+        standard output they are left out.
+    export json-schema <model.json> (--shape <structure> [-o <file>] | -o <dir>)
+        Write a JSON Schema (draft 2020-12) for one structure, or one for each
+        structure into <dir> as <structure>.json.
+
+A model named service-*.json is read with the paginators, waiters and examples next to it.
+
+exit status:
+    0  success
+    1  diff found differences
+    2  the command line is wrong
+    3  a service definition is invalid or cannot be generated
+    4  a file could not be read or written
+    5  semver found breaking changes without --allow-major
+";
+
+pub const SUCCESS: i32 = 0;
+pub const DIFFERENT: i32 = 1;
+pub const USAGE_ERROR: i32 = 2;
+pub const INVALID: i32 = 3;
+pub const IO_ERROR: i32 = 4;
+pub const BREAKING: i32 = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug)]
+struct Options {
+    format: Format,
+    output: Option<PathBuf>,
+    endpoints: Option<PathBuf>,
+    overlays: Vec<PathBuf>,
+    package: bool,
+    runtime: Option<PathBuf>,
+    pins: Vec<(String, String)>,
+    jobs: Option<usize>,
+    version: Option<String>,
+    allow_major: bool,
+    operations: Vec<String>,
+    omit_deprecated: bool,
+    collapse_primitives: bool,
+    shape: Option<String>,
+    paths: Vec<PathBuf>,
+}
+
+/// Why a command stopped, and the exit status that goes with it.
+#[derive(Debug)]
+struct Failure {
+    status: i32,
+    message: String,
+}
+
+impl Failure {
+    fn new(status: i32, message: String) -> Failure {
+        Failure {
+            status: status,
+            message: message,
+        }
+    }
+}
+
+impl From<LoadError> for Failure {
+    fn from(err: LoadError) -> Failure {
+        let status = match err {
+            LoadError::Io(..) => IO_ERROR,
+            LoadError::Parse(..) => INVALID,
+        };
+        Failure::new(status, err.to_string())
+    }
+}
+
+impl From<io::Error> for Failure {
+    fn from(err: io::Error) -> Failure {
+        let status = if err.kind() == ErrorKind::InvalidInput { INVALID } else { IO_ERROR };
+        Failure::new(status, err.to_string())
+    }
+}
+
+/// Runs the command line `args`, which leave out the program name, and returns the
+/// exit status. Results go to `out`; in text format, problems go to `err`.
+pub fn run<O: Write, E: Write>(args: &[String], out: &mut O, err: &mut E) -> i32 {
+    let command = match args.first() {
+        Some(command) => &command[..],
+        None => return usage(err, "missing command"),
+    };
+    if command == "help" || command == "-h" || command == "--help" {
+        let _ = write!(out, "{}", USAGE);
+        return SUCCESS;
+    }
+    let options = match parse_options(&args[1..]) {
+        Ok(options) => options,
+        Err(message) => return usage(err, &message),
+    };
+    let result = match command {
+        "generate" => generate(&options, out),
+        "generate-all" => generate_all(&options, out),
+        "validate" => validate(&options, out),
+        "inspect" => inspect(&options, out),
+        "diff" => diff(&options, out),
+        "semver" => semver(&options, out),
+        "export" => export(&options, out),
+        other => return usage(err, &format!("unknown command {}", other)),
+    };
+    match result {
+        Ok(status) => status,
+        Err(ref failure) if failure.status == USAGE_ERROR => usage(err, &failure.message),
+        Err(failure) => {
+            let _ = match options.format {
+                Format::Text => writeln!(err, "error: {}", failure.message),
+                Format::Json => writeln!(out, "{}", object(vec!(
+                    ("error", Value::String(failure.message)),
+                    ("status", Value::I64(failure.status as i64)),
+                ))),
+            };
+            failure.status
+        }
+    }
+}
+
+fn usage<E: Write>(err: &mut E, message: &str) -> i32 {
+    let _ = writeln!(err, "error: {}\nrun `aws-service-parser --help` for usage", message);
+    USAGE_ERROR
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        format: Format::Text,
+        output: None,
+        endpoints: None,
+        overlays: vec!(),
+        package: false,
+        runtime: None,
+        pins: vec!(),
+        jobs: None,
+        version: None,
+        allow_major: false,
+        operations: vec!(),
+        omit_deprecated: false,
+        collapse_primitives: false,
+        shape: None,
+        paths: vec!(),
+    };
+    let mut i = 0;
+    while i < args.len() {
+        let arg = &args[i];
+        i += 1;
+        let (flag, inline) = match arg.find('=') {
+            Some(at) if arg.starts_with("--") => (&arg[..at], Some(arg[at + 1..].to_string())),
+            _ => (&arg[..], None),
+        };
+        if !flag.starts_with('-') || flag == "-" {
+            options.paths.push(PathBuf::from(arg));
+            continue;
+        }
+        if let Some(switch) = match flag {
+            "--crate" => Some(&mut options.package),
+            "--allow-major" => Some(&mut options.allow_major),
+            "--omit-deprecated" => Some(&mut options.omit_deprecated),
+            "--collapse-primitives" => Some(&mut options.collapse_primitives),
+            _ => None,
+        } {
+            *switch = true;
+            continue;
+        }
+        let value = match inline {
+            Some(value) => value,
+            None if i < args.len() => {
+                i += 1;
+                args[i - 1].clone()
+            }
+            None => return Err(format!("{} needs a value", flag)),
+        };
+        match flag {
+            "--format" => {
+                options.format = match &value[..] {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    other => return Err(format!("unknown format {}", other)),
+                }
+            }
+            "-o" | "--output" => options.output = Some(PathBuf::from(value)),
+            "--endpoints" => options.endpoints = Some(PathBuf::from(value)),
+            "--overlay" => options.overlays.push(PathBuf::from(value)),
+            "--runtime" => options.runtime = Some(PathBuf::from(value)),
+            "--version" => options.version = Some(value),
+            "--operation" => options.operations.push(value),
+            "--shape" => options.shape = Some(value),
+            "--pin" => {
+                let at = try!(value.find('=').ok_or(format!("--pin needs <service>=<api-version>, not {}", value)));
+                options.pins.push((value[..at].to_string(), value[at + 1..].to_string()));
+            }
+            "--jobs" => {
+                options.jobs = match value.parse() {
+                    Ok(jobs) if jobs > 0 => Some(jobs),
+                    _ => return Err(format!("--jobs needs a positive number, not {}", value)),
+                }
+            }
+            other => return Err(format!("unknown option {}", other)),
+        }
+    }
+    Ok(options)
+}
+
+fn paths(options: &Options, count: usize, what: &str) -> Result<(), Failure> {
+    if options.paths.len() == count {
+        Ok(())
+    } else {
+        Err(Failure::new(USAGE_ERROR, format!("expected {}", what)))
+    }
+}
+
+/// The generator for the output options shared by `generate` and `generate-all`.
+fn generator(options: &Options) -> Result<Generator, Failure> {
+    let directory = try!(options.output.as_ref().ok_or(Failure::new(USAGE_ERROR, "missing -o <dir>".to_string())));
+    let mut generator = Generator::new()
+        .out_dir(directory)
+        .module_file(true)
+        .package(options.package)
+        .omit_deprecated(options.omit_deprecated)
+        .cargo_metadata(false);
+    if let Some(ref endpoints) = options.endpoints {
+        generator = generator.endpoints(endpoints);
+    }
+    if let Some(ref runtime) = options.runtime {
+        generator = generator.runtime(Runtime::Path(runtime.clone()));
+    }
+    for overlay in &options.overlays {
+        generator = generator.overlay(overlay);
+    }
+    for operation in &options.operations {
+        generator = generator.operation(&operation[..]);
+    }
+    Ok(generator)
+}
+
+fn diagnostic_status(diagnostic: &Diagnostic) -> i32 {
+    match *diagnostic {
+        Diagnostic::Io(..) => IO_ERROR,
+        _ => INVALID,
+    }
+}
+
+fn generate<O: Write>(options: &Options, out: &mut O) -> Result<i32, Failure> {
+    try!(paths(options, 1, "one model"));
+    let generator = try!(generator(options)).model(&options.paths[0]);
+    let generated = try!(generator.generate().map_err(|diagnostics| {
+        let status = diagnostics.iter().map(diagnostic_status).max().unwrap_or(INVALID);
+        let messages: Vec<String> = diagnostics.iter().map(|diagnostic| diagnostic.to_string()).collect();
+        Failure::new(status, messages.join("\n"))
+    }));
+
+    try!(match options.format {
+        Format::Text => {
+            let wrote: Vec<String> = generated.written.iter().map(|path| format!("wrote {}\n", path.display())).collect();
+            let left_out: Vec<String> = generated.subsets.iter().map(|subset| match subset.lines {
+                Some(lines) => format!("left {} operations, {} shapes and {} lines out of {}\n",
+                                       subset.operations, subset.shapes, lines, subset.service),
+                None => format!("left {} operations and {} shapes out of {}\n",
+                                subset.operations, subset.shapes, subset.service),
+            }).collect();
+            write!(out, "{}{}", wrote.concat(), left_out.concat())
+        }
+        Format::Json => writeln!(out, "{}", object(vec!(
+            ("read", Value::Array(generated.read.iter().map(path_value).collect())),
+            ("wrote", Value::Array(generated.written.iter().map(path_value).collect())),
+            ("leftOut", Value::Array(generated.subsets.iter().map(|subset| object(vec!(
+                ("service", string(&subset.service)),
+                ("operations", Value::I64(subset.operations as i64)),
+                ("shapes", Value::I64(subset.shapes as i64)),
+                ("lines", subset.lines.map_or(Value::Null, |lines| Value::I64(lines as i64))),
+            ))).collect())),
+        ))),
+    });
+    Ok(SUCCESS)
+}
+
+fn generate_all<O: Write>(options: &Options, out: &mut O) -> Result<i32, Failure> {
+    try!(paths(options, 1, "one data directory"));
+    let mut generator = try!(generator(options));
+    for &(ref service, ref version) in &options.pins {
+        generator = generator.pin(&service[..], &version[..]);
+    }
+    if let Some(jobs) = options.jobs {
+        generator = generator.jobs(jobs);
+    }
+    let report = try!(generator.generate_batch(&options.paths[0])
+                      .map_err(|diagnostic| Failure::new(diagnostic_status(&diagnostic), diagnostic.to_string())));
+
+    // Services without a definition are only reported; the others mean something went wrong.
+    let status = report.skipped.iter().map(|skipped| match skipped.reason {
+        SkipReason::NoServiceDefinition => SUCCESS,
+        SkipReason::MissingVersion(_) => INVALID,
+        SkipReason::Failed(_, ref diagnostic) => diagnostic_status(diagnostic),
+        SkipReason::Panicked(..) => INVALID,
+    }).max().unwrap_or(SUCCESS);
+    match options.format {
+        Format::Text => {
+            for generated in &report.generated {
+                try!(writeln!(out, "generated {} {}", generated.service, generated.api_version));
+            }
+            for skipped in &report.skipped {
+                try!(writeln!(out, "skipped {}: {}", skipped.service, skipped.reason));
+            }
+            try!(writeln!(out, "{} generated, {} skipped", report.generated.len(), report.skipped.len()));
+        }
+        Format::Json => {
+            let generated = report.generated.iter().map(|generated| object(vec!(
+                ("service", string(&generated.service)),
+                ("apiVersion", string(&generated.api_version)),
+                ("wrote", Value::Array(generated.written.iter().map(path_value).collect())),
+            ))).collect();
+            let skipped = report.skipped.iter().map(|skipped| object(vec!(
+                ("service", string(&skipped.service)),
+                ("reason", Value::String(skipped.reason.to_string())),
+            ))).collect();
+            try!(writeln!(out, "{}", object(vec!(
+                ("generated", Value::Array(generated)),
+                ("skipped", Value::Array(skipped)),
+                ("status", Value::I64(status as i64)),
+            ))));
+        }
+    }
+    Ok(status)
+}
+
+fn validate<O: Write>(options: &Options, out: &mut O) -> Result<i32, Failure> {
+    if options.paths.is_empty() {
+        return Err(Failure::new(USAGE_ERROR, "expected at least one model".to_string()));
+    }
+    let mut status = SUCCESS;
+    let mut results = vec!();
+    for model in &options.paths {
+        let (failure, dropped) = match ServiceDefinition::load(model) {
+            Ok(loaded) => {
+                let failure = loaded.service.check_names().err()
+                    .map(|err| Failure::new(INVALID, format!("{}: {}", model.display(), err)));
+                (failure, loaded.dropped.iter().map(|err| err.to_string()).collect())
+            }
+            Err(err) => (Some(Failure::from(err)), vec!()),
+        };
+        match options.format {
+            Format::Text => {
+                for dropped in &dropped {
+                    try!(writeln!(out, "dropped {}", dropped));
+                }
+                try!(match failure {
+                    Some(ref failure) => writeln!(out, "{}", failure.message),
+                    None => writeln!(out, "{}: ok", model.display()),
+                })
+            }
+            Format::Json => results.push(object(vec!(
+                ("model", path_value(model)),
+                ("valid", Value::Bool(failure.is_none())),
+                ("error", failure.as_ref().map_or(Value::Null, |f| Value::String(f.message.clone()))),
+                ("dropped", Value::Array(dropped.into_iter().map(Value::String).collect())),
+            ))),
+        }
+        if let Some(failure) = failure {
+            status = ::std::cmp::max(status, failure.status);
+        }
+    }
+    if options.format == Format::Json {
+        try!(writeln!(out, "{}", Value::Array(results)));
+    }
+    Ok(status)
+}
+
+fn inspect<O: Write>(options: &Options, out: &mut O) -> Result<i32, Failure> {
+    try!(paths(options, 1, "one model"));
+    let service = try!(ServiceDefinition::load(&options.paths[0])).service;
+    let metadata = &service.metadata;
+    match options.format {
+        Format::Text => {
+            try!(writeln!(out, "{} ({}, {}, {}, {})", metadata.serviceFullName, metadata.endpointPrefix,
+                          metadata.apiVersion, metadata.protocol, metadata.signatureVersion));
+            try!(writeln!(out, "\noperations ({}):", service.operations.len()));
+            for operation in &service.operations {
+                try!(writeln!(out, "    {} {} {}", operation.name, operation.http.method, operation.http.requestUri));
+            }
+            try!(writeln!(out, "\nshapes ({}):", service.shapes.len()));
+            for shape in &service.shapes {
+                try!(writeln!(out, "    {} {}", shape.name, shape.shape_type.kind()));
+            }
+            try!(writeln!(out, "\npaginators ({}):", service.paginators.len()));
+            for paginator in &service.paginators {
+                try!(writeln!(out, "    {}", paginator.operation));
+            }
+            try!(writeln!(out, "\nwaiters ({}):", service.waiters.len()));
+            for waiter in &service.waiters {
+                try!(writeln!(out, "    {} {}", waiter.name, waiter.operation));
+            }
+        }
+        Format::Json => {
+            let operations = service.operations.iter().map(|operation| object(vec!(
+                ("name", string(&operation.name)),
+                ("method", string(&operation.http.method)),
+                ("requestUri", string(&operation.http.requestUri)),
+                ("input", string(&operation.input.shape)),
+                ("output", operation.output.as_ref().map_or(Value::Null, |output| string(&output.shape))),
+            ))).collect();
+            let shapes = service.shapes.iter().map(|shape| object(vec!(
+                ("name", string(&shape.name)),
+                ("type", string(shape.shape_type.kind())),
+            ))).collect();
+            let paginators = service.paginators.iter().map(|p| string(&p.operation)).collect();
+            let waiters = service.waiters.iter().map(|waiter| object(vec!(
+                ("name", string(&waiter.name)),
+                ("operation", string(&waiter.operation)),
+            ))).collect();
+            try!(writeln!(out, "{}", object(vec!(
+                ("metadata", object(vec!(
+                    ("apiVersion", string(&metadata.apiVersion)),
+                    ("endpointPrefix", string(&metadata.endpointPrefix)),
+                    ("serviceFullName", string(&metadata.serviceFullName)),
+                    ("signatureVersion", string(&metadata.signatureVersion)),
+                    ("protocol", string(&metadata.protocol)),
+                ))),
+                ("operations", Value::Array(operations)),
+                ("shapes", Value::Array(shapes)),
+                ("paginators", Value::Array(paginators)),
+                ("waiters", Value::Array(waiters)),
+            ))));
+        }
+    }
+    Ok(SUCCESS)
+}
+
+fn diff<O: Write>(options: &Options, out: &mut O) -> Result<i32, Failure> {
+    try!(paths(options, 2, "an old and a new model"));
+    let old = try!(ServiceDefinition::load(&options.paths[0])).service;
+    let new = try!(ServiceDefinition::load(&options.paths[1])).service;
+    let diff = old.diff(&new);
+    match options.format {
+        Format::Text => try!(write!(out, "{}", diff)),
+        Format::Json => {
+            let changes = |changes: &Changes| object(vec!(
+                ("added", Value::Array(changes.added.iter().map(|name| string(name)).collect())),
+                ("removed", Value::Array(changes.removed.iter().map(|name| string(name)).collect())),
+                ("changed", Value::Array(changes.changed.iter().map(|name| string(name)).collect())),
+            ));
+            let details = diff.changes.iter().map(|change| {
+                let mut fields = vec!(("kind", string(change.kind())), ("message", Value::String(change.to_string())));
+                if let Some(operation) = change.operation() {
+                    fields.push(("operation", string(operation)));
+                }
+                if let Some(shape) = change.shape() {
+                    fields.push(("shape", string(shape)));
+                }
+                object(fields)
+            }).collect();
+            try!(writeln!(out, "{}", object(vec!(
+                ("operations", changes(&diff.operations)),
+                ("shapes", changes(&diff.shapes)),
+                ("changes", Value::Array(details)),
+            ))));
+        }
+    }
+    Ok(if diff.is_empty() { SUCCESS } else { DIFFERENT })
+}
+
+fn semver<O: Write>(options: &Options, out: &mut O) -> Result<i32, Failure> {
+    try!(paths(options, 2, "an old and a new model"));
+    let old = try!(ServiceDefinition::load(&options.paths[0])).service;
+    let new = try!(ServiceDefinition::load(&options.paths[1])).service;
+    let diff = old.diff(&new);
+    let bump = diff.bump();
+    let next = match (bump, options.version.as_ref()) {
+        (Some(bump), Some(version)) => Some(try!(bump.next(version).map_err(|message| Failure::new(USAGE_ERROR, message)))),
+        _ => None,
+    };
+    let status = if bump == Some(Bump::Major) && !options.allow_major { BREAKING } else { SUCCESS };
+    match options.format {
+        Format::Text => {
+            if diff.changes.is_empty() {
+                try!(writeln!(out, "no differences"));
+            }
+            for change in &diff.changes {
+                let (bump, reason) = change.bump();
+                try!(writeln!(out, "{}  {} ({})", bump, change, reason));
+            }
+            match (bump, next) {
+                (Some(bump), Some(next)) => try!(writeln!(out, "recommended bump: {} ({})", bump, next)),
+                (Some(bump), None) => try!(writeln!(out, "recommended bump: {}", bump)),
+                (None, _) => try!(writeln!(out, "recommended bump: none")),
+            }
+            if status == BREAKING {
+                try!(writeln!(out, "breaking changes need --allow-major"));
+            }
+        }
+        Format::Json => {
+            let changes = diff.changes.iter().map(|change| {
+                let (bump, reason) = change.bump();
+                object(vec!(
+                    ("bump", Value::String(bump.to_string())),
+                    ("kind", string(change.kind())),
+                    ("message", Value::String(change.to_string())),
+                    ("reason", string(reason)),
+                ))
+            }).collect();
+            try!(writeln!(out, "{}", object(vec!(
+                ("bump", bump.map_or(Value::Null, |bump| Value::String(bump.to_string()))),
+                ("changes", Value::Array(changes)),
+                ("next", next.map_or(Value::Null, Value::String)),
+                ("status", Value::I64(status as i64)),
+            ))));
+        }
+    }
+    Ok(status)
+}
+
+fn export<O: Write>(options: &Options, out: &mut O) -> Result<i32, Failure> {
+    try!(paths(options, 2, "a format and one model"));
+    let service = try!(ServiceDefinition::load(&options.paths[1])).service;
+    let mut exported = Vec::new();
+    match &options.paths[0].to_string_lossy()[..] {
+        "dot" => try!(service.write_dot(&mut exported, &DotOptions {
+            operation: match options.operations.len() {
+                0 => None,
+                1 => Some(options.operations[0].clone()),
+                _ => return Err(Failure::new(USAGE_ERROR, "export dot takes one --operation".to_string())),
+            },
+            collapse_primitives: options.collapse_primitives,
+        })),
+        "openapi" => try!(service.write_openapi(&mut exported)),
+        "botocore" => {
+            try!(service.write_botocore(&mut exported));
+            // Paginators and waiters go in files of their own next to the output, so
+            // they are left out on standard output.
+            if let Some(ref path) = options.output {
+                let directory = path.parent().unwrap_or(Path::new(""));
+                for (name, companion) in service.botocore_companions() {
+                    let mut companion_json = Vec::new();
+                    try!(serde_json::to_writer_pretty(&mut companion_json, &companion));
+                    try!(writeln!(companion_json, ""));
+                    try!(write_file(&directory.join(name), &companion_json));
+                }
+            }
+        }
+        "json-schema" => match (options.shape.as_ref(), options.output.as_ref()) {
+            (Some(shape), _) => try!(service.write_json_schema(&mut exported, shape)),
+            (None, Some(directory)) => {
+                try!(fs::create_dir_all(directory).map_err(|err| io_failure(directory, err)));
+                for (name, schema) in try!(service.json_schemas()) {
+                    let mut schema_json = Vec::new();
+                    try!(serde_json::to_writer_pretty(&mut schema_json, &schema));
+                    try!(writeln!(schema_json, ""));
+                    try!(write_file(&directory.join(format!("{}.json", name)), &schema_json));
+                }
+                return Ok(SUCCESS);
+            }
+            (None, None) => {
+                return Err(Failure::new(USAGE_ERROR, "json-schema needs --shape or an -o directory".to_string()))
+            }
+        },
+        other => return Err(Failure::new(USAGE_ERROR, format!("cannot export to {}", other))),
+    }
+    match options.output {
+        Some(ref path) => try!(write_file(path, &exported)),
+        None => try!(out.write_all(&exported)),
+    }
+    Ok(SUCCESS)
+}
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<(), Failure> {
+    File::create(path).and_then(|mut file| file.write_all(contents)).map_err(|err| io_failure(path, err))
+}
+
+fn io_failure(path: &Path, err: io::Error) -> Failure {
+    Failure::new(IO_ERROR, format!("{}: {}", path.display(), err))
+}
+
+fn object(pairs: Vec<(&str, Value)>) -> Value {
+    Value::Object(pairs.into_iter().map(|(key, value)| (key.to_string(), value)).collect::<BTreeMap<_, _>>())
+}
+
+fn string(s: &str) -> Value {
+    Value::String(s.to_string())
+}
+
+fn path_value(path: &PathBuf) -> Value {
+    Value::String(path.display().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+    use std::fs::File;
+    use std::io::Read;
+
+    fn run_args(args: &[&str]) -> (i32, String, String) {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let status = run(&args, &mut out, &mut err);
+        (status, String::from_utf8(out).unwrap(), String::from_utf8(err).unwrap())
+    }
+
+    #[test]
+    fn usage_errors() {
+        assert_eq!(run_args(&[]).0, USAGE_ERROR);
+        assert_eq!(run_args(&["frobnicate"]).0, USAGE_ERROR);
+        assert_eq!(run_args(&["inspect", "--format", "yaml", "x.json"]).0, USAGE_ERROR);
+        let (status, out, err) = run_args(&["generate", "fixtures/services/lambda-2015-03-31.json"]);
+        assert_eq!((status, &out[..]), (USAGE_ERROR, ""));
+        assert!(err.starts_with("error: missing -o <dir>\n"));
+        let (status, out, _) = run_args(&["--help"]);
+        assert_eq!((status, &out[..]), (SUCCESS, USAGE));
+    }
+
+    #[test]
+    fn validate_models() {
+        let (status, out, _) = run_args(&["validate", "fixtures/services/lambda-2015-03-31.json",
+                                          "fixtures/botocore/lambda/2015-03-31/service-2.json"]);
+        assert_eq!(status, SUCCESS);
+        assert_eq!(out, "fixtures/services/lambda-2015-03-31.json: ok\n\
+                         fixtures/botocore/lambda/2015-03-31/service-2.json: ok\n");
+
+        let (status, out, _) = run_args(&["validate", "fixtures/botocore/lambda-preview/2015-03-31/service-2.json"]);
+        assert_eq!(status, SUCCESS);
+        assert!(out.starts_with("dropped fixtures/botocore/lambda-preview/2015-03-31/examples-1.json: invalid example: "));
+        assert!(out.ends_with("\nfixtures/botocore/lambda-preview/2015-03-31/service-2.json: ok\n"));
+
+        let (status, out, _) = run_args(&["validate", "--format=json", "fixtures/services/stream-not-payload.json",
+                                          "fixtures/services/missing.json"]);
+        assert_eq!(status, IO_ERROR);
+        assert!(out.starts_with("[{\"dropped\":[],\"error\":\"fixtures/services/stream-not-payload.json: invalid payload: "));
+        assert!(out.contains("\"model\":\"fixtures/services/missing.json\",\"valid\":false}]"));
+    }
+
+    #[test]
+    fn inspect_model() {
+        let (status, out, _) = run_args(&["inspect", "fixtures/botocore/lambda/2015-03-31/service-2.json"]);
+        assert_eq!(status, SUCCESS);
+        assert!(out.starts_with("AWS Lambda (lambda, 2015-03-31, rest-json, v4)\n\noperations (24):\n"));
+        assert!(out.contains("    CreateFunction POST /2015-03-31/functions\n"));
+        assert!(out.contains("\nwaiters (3):\n"));
+
+        let (status, out, _) = run_args(&["inspect", "--format", "json", "fixtures/services/recursive.json"]);
+        assert_eq!(status, SUCCESS);
+        assert!(out.contains("{\"name\":\"Node\",\"type\":\"structure\"}"));
+    }
+
+    #[test]
+    fn diff_models() {
+        let lambda = "fixtures/services/lambda-2015-03-31.json";
+        assert_eq!(run_args(&["diff", lambda, lambda]), (SUCCESS, "no differences\n".to_string(), String::new()));
+        let (status, out, _) = run_args(&["diff", "--format", "json", lambda, "fixtures/services/recursive.json"]);
+        assert_eq!(status, DIFFERENT);
+        assert!(out.contains("\"added\":[\"EvaluateRequest\",\"EvaluateResponse\""));
+        assert!(out.contains("{\"kind\":\"operation-removed\",\"message\":\"operation AddPermission removed\",\
+                              \"operation\":\"AddPermission\"}"));
+
+        let (status, out, _) = run_args(&["diff", "fixtures/botocore/conditions/2015-06-01/service-2.json",
+                                          "fixtures/botocore/conditions/2016-01-01/service-2.json"]);
+        assert_eq!((status, &out[..]), (SUCCESS, "no differences\n"));
+    }
+
+    #[test]
+    fn semver_models() {
+        let old = "fixtures/botocore/conditions/2015-06-01/service-2.json";
+        let new = "fixtures/botocore/conditions/2016-01-01/service-2.json";
+        let (status, out, _) = run_args(&["semver", old, new, "--version", "1.2.3"]);
+        assert_eq!((status, &out[..]), (SUCCESS, "no differences\nrecommended bump: none\n"));
+
+        let lambda = "fixtures/services/lambda-2015-03-31.json";
+        let (status, out, _) = run_args(&["semver", lambda, old, "--version", "1.2.3"]);
+        assert_eq!(status, BREAKING);
+        assert!(out.starts_with("major  operation AddPermission removed (removes a method from the client trait)\n"));
+        assert!(out.contains("\nmajor  operation Evaluate added (adds a method every client implementation must have)\n"));
+        assert!(out.ends_with("\nrecommended bump: major (2.0.0)\nbreaking changes need --allow-major\n"));
+
+        let (status, out, _) = run_args(&["semver", "--format", "json", "--allow-major", lambda, old]);
+        assert_eq!(status, SUCCESS);
+        assert!(out.starts_with("{\"bump\":\"major\",\"changes\":[{\"bump\":\"major\",\"kind\":\"operation-removed\""));
+        assert!(out.ends_with(",\"next\":null,\"status\":0}\n"));
+        assert_eq!(run_args(&["semver", lambda, old, "--version", "2"]).0, USAGE_ERROR);
+    }
+
+    #[test]
+    fn export_dot() {
+        let (status, out, _) = run_args(&["export", "dot", "fixtures/services/recursive.json"]);
+        assert_eq!(status, SUCCESS);
+        assert!(out.starts_with("digraph \"Recursive Conditions\" {\n"));
+        let (status, out, _) = run_args(&["export", "dot", "--operation", "Evaluate", "--collapse-primitives",
+                                          "fixtures/services/recursive.json"]);
+        assert_eq!(status, SUCCESS);
+        assert!(!out.contains("\"String\" ["));
+        let (status, out, _) = run_args(&["export", "openapi", "fixtures/services/recursive.json"]);
+        assert_eq!(status, SUCCESS);
+        assert!(out.contains("\"openapi\": \"3.0.3\""));
+        let (status, out, _) = run_args(&["export", "json-schema", "--shape", "Tree", "fixtures/services/recursive.json"]);
+        assert_eq!(status, SUCCESS);
+        assert!(out.contains("\"$ref\": \"#/$defs/Tree\""));
+        assert_eq!(run_args(&["export", "json-schema", "fixtures/services/recursive.json"]).0, USAGE_ERROR);
+        let directory = env::temp_dir().join("aws-service-parser-cli-json-schema");
+        let (status, out, _) = run_args(&["export", "json-schema", "fixtures/services/recursive.json",
+                                          "-o", &directory.display().to_string()]);
+        assert_eq!((status, &out[..]), (SUCCESS, ""));
+        let mut schema = String::new();
+        File::open(directory.join("Operand.json")).unwrap().read_to_string(&mut schema).unwrap();
+        assert!(schema.contains("\"title\": \"Operand\""));
+        let (status, out, _) = run_args(&["export", "botocore", "fixtures/smithy/recursive.json"]);
+        assert_eq!(status, SUCCESS);
+        assert!(out.contains("\"serviceFullName\": \"Recursive Conditions\""));
+        let directory = env::temp_dir().join("aws-service-parser-cli-export-botocore");
+        fs::create_dir_all(&directory).unwrap();
+        let (status, _, _) = run_args(&["export", "botocore", "fixtures/botocore/lambda/2015-03-31/service-2.json",
+                                        "-o", &directory.join("service-2.json").display().to_string()]);
+        assert_eq!(status, SUCCESS);
+        let mut waiters = String::new();
+        File::open(directory.join("waiters-2.json")).unwrap().read_to_string(&mut waiters).unwrap();
+        assert!(waiters.contains("\"FunctionExists\": {"));
+        assert!(directory.join("paginators-1.json").exists());
+        assert_eq!(run_args(&["export", "yaml", "fixtures/services/recursive.json"]).0, USAGE_ERROR);
+        assert_eq!(run_args(&["export", "dot", "--operation", "Frobnicate", "fixtures/services/recursive.json"]).0,
+                   INVALID);
+        assert_eq!(run_args(&["export", "dot", "--operation", "Evaluate", "--operation", "Evaluate",
+                              "fixtures/services/recursive.json"]).0, USAGE_ERROR);
+    }
+
+    #[test]
+    fn generate_module() {
+        let directory = env::temp_dir().join("aws-service-parser-cli-generate");
+        let directory_arg = directory.display().to_string();
+        let (status, out, err) = run_args(&["generate", "fixtures/services/lambda-2015-03-31.json",
+                                            "-o", &directory_arg, "--endpoints", "fixtures/endpoints/endpoints.json"]);
+        assert_eq!((status, &err[..]), (SUCCESS, ""));
+        assert_eq!(out, format!("wrote {}\n", directory.join("lambda.rs").display()));
+        let mut generated = String::new();
+        File::open(directory.join("lambda.rs")).unwrap().read_to_string(&mut generated).unwrap();
+        assert!(generated.contains("pub fn endpoint(region: &str)"));
+
+        let (status, out, _) = run_args(&["generate", "fixtures/services/lambda-2015-03-31.json", "-o", &directory_arg,
+                                          "--crate", "--runtime", "../aws-service-parser"]);
+        assert_eq!(status, SUCCESS);
+        assert!(out.starts_with(&format!("wrote {}\n", directory.join("aws-lambda/Cargo.toml").display())));
+        assert_eq!(out.lines().count(), 7);
+
+        let (status, out, _) = run_args(&["generate", "--format", "json", "fixtures/services/stream-not-payload.json",
+                                          "-o", &directory_arg]);
+        assert_eq!(status, INVALID);
+        assert!(out.ends_with(",\"status\":3}\n"));
+
+        let (status, _, _) = run_args(&["generate", "fixtures/services/lambda-2015-03-31.json", "-o", &directory_arg,
+                                        "--overlay", "fixtures/overlays/lambda.json"]);
+        assert_eq!(status, SUCCESS);
+        let mut generated = String::new();
+        File::open(directory.join("lambda.rs")).unwrap().read_to_string(&mut generated).unwrap();
+        assert!(generated.contains("pub struct Function {"));
+        let (status, _, err) = run_args(&["generate", "fixtures/services/lambda-2015-03-31.json", "-o", &directory_arg,
+                                          "--overlay", "fixtures/overlays/lambda.json",
+                                          "--overlay", "fixtures/overlays/lambda.json"]);
+        assert_eq!(status, INVALID);
+        assert!(err.contains("invalid overlay: removeOperations: there is no operation AddPermission"));
+
+        let (status, out, _) = run_args(&["generate", "fixtures/services/lambda-2015-03-31.json", "-o", &directory_arg,
+                                          "--operation", "Invoke", "--operation=GetFunction"]);
+        assert_eq!(status, SUCCESS);
+        assert!(out.contains("\nleft 22 operations, "));
+        assert!(out.ends_with(" lines out of lambda\n"));
+        let (status, out, _) = run_args(&["generate", "--format", "json", "fixtures/services/lambda-2015-03-31.json",
+                                          "-o", &directory_arg, "--operation", "Invoke"]);
+        assert_eq!(status, SUCCESS);
+        assert!(out.contains("\"leftOut\":[{\"lines\":"));
+        assert!(out.contains("\"operations\":23,"));
+
+        let (status, _, _) = run_args(&["generate", "fixtures/services/lambda-2015-03-31.json", "-o", &directory_arg,
+                                        "--omit-deprecated"]);
+        assert_eq!(status, SUCCESS);
+        let mut generated = String::new();
+        File::open(directory.join("lambda.rs")).unwrap().read_to_string(&mut generated).unwrap();
+        assert!(generated.contains("fn invoke("));
+        assert!(!generated.contains("fn invoke_async("));
+    }
+
+    #[test]
+    fn generate_all() {
+        let directory = env::temp_dir().join("aws-service-parser-cli-generate-all").display().to_string();
+        let (status, out, _) = run_args(&["generate-all", "fixtures/botocore", "-o", &directory,
+                                          "--pin", "conditions=2015-06-01", "--jobs=2"]);
+        assert_eq!(status, INVALID);
+        assert_eq!(out.lines().next(), Some("generated conditions 2015-06-01"));
+        assert!(out.contains("\nskipped sdb: no service definition\n"));
+        assert!(out.ends_with("\n3 generated, 2 skipped\n"));
+
+        let (status, out, _) = run_args(&["generate-all", "--format", "json", "fixtures/botocore", "-o", &directory,
+                                          "--pin", "uploads=2015-01-01"]);
+        assert_eq!(status, INVALID);
+        assert!(out.contains("{\"reason\":\"pinned version 2015-01-01 is not there\",\"service\":\"uploads\"}"));
+        assert_eq!(run_args(&["generate-all", "fixtures/botocore", "-o", &directory, "--pin", "uploads"]).0, USAGE_ERROR);
+    }
+}
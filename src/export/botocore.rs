@@ -0,0 +1,382 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+use std::io::prelude::*;
+use serde_json::{self, Value};
+use ::parser::*;
+use ::parser::examples::Example;
+use ::parser::paginators::Paginator;
+use ::parser::path::Path;
+use ::parser::waiters::{Acceptor, AcceptorState, Matcher, Waiter};
+use super::schema::{object, string};
+
+impl ServiceDefinition {
+    /// The service definition in botocore's format, as `parse()` reads it, with keys in
+    /// sorted order. Paginators and waiters go in their own files, which
+    /// `botocore_companions()` gives; examples are written into the `examples` section.
+    pub fn to_botocore(&self) -> Value {
+        let mut examples: BTreeMap<String, Value> = BTreeMap::new();
+        for example in &self.examples {
+            let list = examples.entry(example.operation.clone()).or_insert_with(|| Value::Array(vec!()));
+            list.as_array_mut().unwrap().push(example.to_botocore());
+        }
+        object(vec!(
+            ("version", string(&self.version)),
+            ("metadata", self.metadata.to_botocore()),
+            ("documentation", string(&self.documentation)),
+            ("operations", Value::Object(self.operations.iter().map(|operation| {
+                (operation.name.clone(), operation.to_botocore())
+            }).collect())),
+            ("shapes", Value::Object(self.shapes.iter().map(|shape| (shape.name.clone(), shape.to_botocore())).collect())),
+            ("examples", Value::Object(examples)),
+        ))
+    }
+
+    /// Writes `to_botocore()` as indented JSON.
+    pub fn write_botocore<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        try!(serde_json::to_writer_pretty(out, &self.to_botocore()));
+        try!(writeln!(out, ""));
+        Ok(())
+    }
+
+    /// The files that go next to `service-2.json`, by name: `paginators-1.json` and
+    /// `waiters-2.json`, each only when the service has any.
+    pub fn botocore_companions(&self) -> Vec<(&'static str, Value)> {
+        let mut companions = vec!();
+        if !self.paginators.is_empty() {
+            companions.push(("paginators-1.json", object(vec!(
+                ("pagination", Value::Object(self.paginators.iter().map(|paginator| {
+                    (paginator.operation.clone(), paginator.to_botocore())
+                }).collect())),
+            ))));
+        }
+        if !self.waiters.is_empty() {
+            companions.push(("waiters-2.json", object(vec!(
+                ("version", Value::I64(2)),
+                ("waiters", Value::Object(self.waiters.iter().map(|waiter| (waiter.name.clone(), waiter.to_botocore()))
+                                                     .collect())),
+            ))));
+        }
+        companions
+    }
+}
+
+impl Metadata {
+    pub fn to_botocore(&self) -> Value {
+        let mut fields = vec!(
+            ("apiVersion", string(&self.apiVersion)),
+            ("endpointPrefix", string(&self.endpointPrefix)),
+            ("serviceFullName", string(&self.serviceFullName)),
+            ("signatureVersion", string(&self.signatureVersion)),
+            ("protocol", string(&self.protocol)),
+        );
+        fields.extend(self.globalEndpoint.as_ref().map(|endpoint| ("globalEndpoint", string(endpoint))));
+        fields.extend(self.signingName.as_ref().map(|name| ("signingName", string(name))));
+        object(fields)
+    }
+}
+
+impl Operation {
+    pub fn to_botocore(&self) -> Value {
+        let mut http = vec!(("method", string(&self.http.method)), ("requestUri", string(&self.http.requestUri)));
+        http.extend(self.http.responseCode.map(|code| ("responseCode", Value::I64(code as i64))));
+        let mut input = vec!(("shape", string(&self.input.shape)));
+        input.extend(flag("deprecated", self.input.deprecated));
+        let errors = self.errors.iter().map(|error| {
+            let mut fields = vec!(
+                ("shape", string(&error.shape)),
+                ("error", object(vec!(("httpStatusCode", Value::I64(error.error.httpStatusCode as i64))))),
+                ("exception", Value::Bool(error.exception)),
+            );
+            fields.extend(text("documentation", &error.documentation));
+            fields.extend(flag("deprecated", error.deprecated));
+            object(fields)
+        }).collect();
+
+        let mut fields = vec!(
+            ("name", string(&self.name)),
+            ("http", object(http)),
+            ("input", object(input)),
+            ("errors", Value::Array(errors)),
+        );
+        if let Some(ref output) = self.output {
+            let mut output_fields = vec!(("shape", string(&output.shape)));
+            output_fields.extend(text("documentation", &output.documentation));
+            output_fields.extend(flag("deprecated", output.deprecated));
+            fields.push(("output", object(output_fields)));
+        }
+        fields.extend(flag("deprecated", self.deprecated));
+        fields.extend(text("deprecatedMessage", &self.deprecatedMessage));
+        fields.extend(text("documentation", &self.documentation));
+        object(fields)
+    }
+}
+
+impl Shape {
+    pub fn to_botocore(&self) -> Value {
+        let mut value = self.shape_type.to_botocore();
+        if let Some(fields) = value.as_object_mut() {
+            for (key, field) in deprecation(&self.deprecated) {
+                fields.insert(key.to_string(), field);
+            }
+        }
+        value
+    }
+}
+
+impl ShapeType {
+    pub fn to_botocore(&self) -> Value {
+        let fields = match *self {
+            ShapeType::Blob(Blob(streaming)) => {
+                let mut fields = vec!(("type", string("blob")));
+                if streaming {
+                    fields.push(("streaming", Value::Bool(true)));
+                }
+                fields
+            }
+            ShapeType::Boolean => vec!(("type", string("boolean"))),
+            ShapeType::Double => vec!(("type", string("double"))),
+            ShapeType::Float => vec!(("type", string("float"))),
+            ShapeType::Integer(Integer { min, max }) => bounded("integer", min, max),
+            ShapeType::List(List { ref member, min, max }) => {
+                let mut fields = bounded("list", min, max);
+                fields.push(("member", object(vec!(("shape", string(member))))));
+                fields
+            }
+            ShapeType::Long => vec!(("type", string("long"))),
+            ShapeType::StringEnum(StringEnum(ref values)) => vec!(
+                ("type", string("string")),
+                ("enum", Value::Array(values.iter().map(|value| string(value)).collect())),
+            ),
+            ShapeType::StringPattern(StringPattern { ref pattern, min, max }) => {
+                let mut fields = bounded("string", min, max);
+                // `.*` is what the parser puts in when there is no pattern.
+                if pattern != ".*" {
+                    fields.push(("pattern", string(pattern)));
+                }
+                fields
+            }
+            ShapeType::Structure(Structure(ref members)) => structure(members),
+            ShapeType::Exception(ref exception) => {
+                let mut fields = structure(&exception.members);
+                fields.push(("error", object(vec!(("httpStatusCode", Value::I64(exception.status_code))))));
+                fields.push(("exception", Value::Bool(true)));
+                fields.extend(text("documentation", &exception.documentation));
+                fields
+            }
+            ShapeType::Timestamp => vec!(("type", string("timestamp"))),
+        };
+        object(fields)
+    }
+}
+
+impl Member {
+    /// The member as it appears in its structure's `members`. Whether it is required or
+    /// the payload is up to the structure.
+    pub fn to_botocore(&self) -> Value {
+        let mut fields = vec!(("shape", string(&self.shape)));
+        fields.extend(self.location.to_botocore());
+        fields.extend(text("documentation", &self.documentation));
+        fields.extend(deprecation(&self.deprecated));
+        object(fields)
+    }
+}
+
+impl Location {
+    /// The `location` and `locationName` of a member sent here; none for the body.
+    pub fn to_botocore(&self) -> Vec<(&'static str, Value)> {
+        let (location, name) = match *self {
+            Location::Body | Location::Payload => return vec!(),
+            Location::StatusCode => return vec!(("location", string("statusCode"))),
+            Location::URI(ref name) => ("uri", name),
+            Location::QueryString(ref name) => ("querystring", name),
+            Location::Header(ref name) => ("header", name),
+        };
+        vec!(("location", string(location)), ("locationName", string(name)))
+    }
+}
+
+impl Example {
+    pub fn to_botocore(&self) -> Value {
+        let mut fields = vec!(("input", Value::Object(self.input.clone())));
+        fields.extend(self.output.as_ref().map(|output| ("output", Value::Object(output.clone()))));
+        fields.extend(text("id", &self.id));
+        fields.extend(text("title", &self.title));
+        fields.extend(text("description", &self.description));
+        object(fields)
+    }
+}
+
+impl Paginator {
+    /// The paginator as it appears in `pagination`, keyed by its operation.
+    pub fn to_botocore(&self) -> Value {
+        let expressions = |paths: &[Path]| -> Vec<String> { paths.iter().map(|path| path.to_string()).collect() };
+        let mut fields = vec!(
+            ("input_token", one_or_many(&self.input_token)),
+            ("output_token", one_or_many(&expressions(&self.output_token))),
+        );
+        fields.extend(text("limit_key", &self.limit_key));
+        if !self.result_key.is_empty() {
+            fields.push(("result_key", one_or_many(&expressions(&self.result_key))));
+        }
+        fields.extend(self.more_results.as_ref().map(|path| ("more_results", string(&path.to_string()))));
+        object(fields)
+    }
+}
+
+impl Waiter {
+    /// The waiter as it appears in `waiters`, keyed by its name.
+    pub fn to_botocore(&self) -> Value {
+        let mut fields = vec!(
+            ("operation", string(&self.operation)),
+            ("delay", Value::I64(self.delay)),
+            ("maxAttempts", Value::I64(self.max_attempts)),
+            ("acceptors", Value::Array(self.acceptors.iter().map(Acceptor::to_botocore).collect())),
+        );
+        fields.extend(text("description", &self.description));
+        object(fields)
+    }
+}
+
+impl Acceptor {
+    pub fn to_botocore(&self) -> Value {
+        let state = match self.state {
+            AcceptorState::Success => "success",
+            AcceptorState::Failure => "failure",
+            AcceptorState::Retry => "retry",
+        };
+        let mut fields = vec!(("state", string(state)));
+        match self.matcher {
+            Matcher::Path { ref argument, ref expected } |
+            Matcher::PathAll { ref argument, ref expected } |
+            Matcher::PathAny { ref argument, ref expected } => {
+                let matcher = match self.matcher {
+                    Matcher::Path { .. } => "path",
+                    Matcher::PathAll { .. } => "pathAll",
+                    _ => "pathAny",
+                };
+                fields.push(("matcher", string(matcher)));
+                fields.push(("argument", string(&argument.to_string())));
+                fields.push(("expected", expected.clone()));
+            }
+            Matcher::Status(status) => {
+                fields.push(("matcher", string("status")));
+                fields.push(("expected", Value::I64(status)));
+            }
+            Matcher::Error(ref error) => {
+                fields.push(("matcher", string("error")));
+                fields.push(("expected", string(error)));
+            }
+        }
+        object(fields)
+    }
+}
+
+/// A paginator field holding one value on its own, as botocore mostly writes them.
+fn one_or_many(values: &[String]) -> Value {
+    match values.len() {
+        1 => string(&values[0]),
+        _ => Value::Array(values.iter().map(|value| string(value)).collect()),
+    }
+}
+
+fn structure(members: &[Member]) -> Vec<(&'static str, Value)> {
+    let mut fields = vec!(
+        ("type", string("structure")),
+        ("members", Value::Object(members.iter().map(|member| (member.name.clone(), member.to_botocore())).collect())),
+    );
+    let required: Vec<Value> = members.iter().filter(|member| member.required).map(|member| string(&member.name))
+                                      .collect();
+    if !required.is_empty() {
+        fields.push(("required", Value::Array(required)));
+    }
+    if let Some(payload) = members.iter().find(|member| member.location == Location::Payload) {
+        fields.push(("payload", string(&payload.name)));
+    }
+    fields
+}
+
+fn bounded(shape_type: &str, min: Option<i64>, max: Option<i64>) -> Vec<(&'static str, Value)> {
+    let mut fields = vec!(("type", string(shape_type)));
+    fields.extend(min.map(|min| ("min", Value::I64(min))));
+    fields.extend(max.map(|max| ("max", Value::I64(max))));
+    fields
+}
+
+fn flag(key: &'static str, value: Option<bool>) -> Option<(&'static str, Value)> {
+    value.map(|value| (key, Value::Bool(value)))
+}
+
+fn deprecation(deprecation: &Option<Deprecation>) -> Vec<(&'static str, Value)> {
+    match *deprecation {
+        Some(ref deprecation) => {
+            let mut fields = vec!(("deprecated", Value::Bool(true)));
+            fields.extend(text("deprecatedMessage", &deprecation.message));
+            fields
+        }
+        None => vec!(),
+    }
+}
+
+fn text(key: &'static str, value: &Option<String>) -> Option<(&'static str, Value)> {
+    value.as_ref().map(|value| (key, string(value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::Path;
+    use serde_json;
+    use ::parser::ServiceDefinition;
+    use ::testhelpers::fixture_service;
+
+    fn round_trip(service: &ServiceDefinition) -> ServiceDefinition {
+        let mut buffer = Vec::new();
+        service.write_botocore(&mut buffer).unwrap();
+        ServiceDefinition::parse(&buffer[..]).unwrap()
+    }
+
+    #[test]
+    fn lambda_round_trip() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(round_trip(&service), service);
+    }
+
+    #[test]
+    fn botocore_layout_round_trip() {
+        let service = ServiceDefinition::load(Path::new("fixtures/botocore/lambda/2015-03-31/service-2.json"))
+            .unwrap().service;
+        let directory = env::temp_dir().join("aws-service-parser-botocore-round-trip");
+        fs::create_dir_all(&directory).unwrap();
+        service.write_botocore(&mut File::create(directory.join("service-2.json")).unwrap()).unwrap();
+        let companions = service.botocore_companions();
+        let names: Vec<&str> = companions.iter().map(|&(name, _)| name).collect();
+        assert_eq!(names, vec!("paginators-1.json", "waiters-2.json"));
+        for (name, companion) in companions {
+            let mut file = File::create(directory.join(name)).unwrap();
+            serde_json::to_writer_pretty(&mut file, &companion).unwrap();
+            writeln!(file, "").unwrap();
+        }
+        let loaded = ServiceDefinition::load(&directory.join("service-2.json")).unwrap().service;
+        assert_eq!(loaded.paginators.len(), 4);
+        assert_eq!(loaded.waiters.len(), 3);
+        assert_eq!(loaded, service);
+    }
+
+    #[test]
+    fn deterministic() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let (mut first, mut second) = (Vec::new(), Vec::new());
+        service.write_botocore(&mut first).unwrap();
+        round_trip(&service).write_botocore(&mut second).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn smithy_to_botocore() {
+        let service = fixture_service("smithy/lambda");
+        assert_eq!(round_trip(&service), service);
+        assert_eq!(round_trip(&fixture_service("smithy/recursive")), fixture_service("services/recursive"));
+    }
+}
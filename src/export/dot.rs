@@ -0,0 +1,144 @@
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use ::parser::*;
+
+/// What `write_dot()` draws.
+#[derive(Debug, Default)]
+pub struct DotOptions {
+    /// Draw only this operation and the shapes it reaches.
+    pub operation: Option<String>,
+    /// Leave out nodes for shapes that refer to no others (strings, numbers, enums and
+    /// so on), listing them in the label of the structure that uses them instead.
+    pub collapse_primitives: bool,
+}
+
+impl ServiceDefinition {
+    /// Writes the graph of operations and shapes in Graphviz DOT. Operations point at
+    /// their input, output and error shapes, structures at their members' shapes and
+    /// lists at their element shape.
+    pub fn write_dot<W: Write>(&self, out: &mut W, options: &DotOptions) -> Result<(), Error> {
+        let operations: Vec<&Operation> = match options.operation {
+            Some(ref name) => vec!(try!(self.operation(name).ok_or(Error::new(ErrorKind::InvalidInput,
+                                                                             format!("unknown operation {}", name))))),
+            None => self.operations.iter().collect(),
+        };
+        let reachable = self.reachable(operations.iter().flat_map(|operation| operation_shapes(*operation)));
+        let drawn = |shape: &str| reachable.contains(shape) && !(options.collapse_primitives && self.is_primitive(shape));
+
+        try!(writeln!(out, "digraph {} {{", quote(&self.metadata.serviceFullName)));
+        try!(writeln!(out, "    rankdir=LR;"));
+        try!(writeln!(out, "    node [shape=box];"));
+        for operation in &operations {
+            try!(writeln!(out, "    {} [label={}, shape=ellipse];", quote(&operation_id(operation)), quote(&operation.name)));
+        }
+        for shape in self.shapes.iter().filter(|shape| drawn(&shape.name)) {
+            try!(writeln!(out, "    {} [label={}{}];", quote(&shape.name), self.label(shape, options),
+                          if let ShapeType::Exception(_) = shape.shape_type { ", color=red" } else { "" }));
+        }
+
+        for operation in &operations {
+            let id = quote(&operation_id(operation));
+            if drawn(&operation.input.shape) {
+                try!(writeln!(out, "    {} -> {} [label=input];", id, quote(&operation.input.shape)));
+            }
+            if let Some(ref output) = operation.output {
+                if drawn(&output.shape) {
+                    try!(writeln!(out, "    {} -> {} [label=output];", id, quote(&output.shape)));
+                }
+            }
+            for error in &operation.errors {
+                if drawn(&error.shape) {
+                    try!(writeln!(out, "    {} -> {} [label=error, style=dashed];", id, quote(&error.shape)));
+                }
+            }
+        }
+        for shape in self.shapes.iter().filter(|shape| drawn(&shape.name)) {
+            for (label, target) in shape.shape_type.references() {
+                if drawn(target) {
+                    try!(writeln!(out, "    {} -> {} [label={}];", quote(&shape.name), quote(target), quote(label)));
+                }
+            }
+        }
+        try!(writeln!(out, "}}"));
+        Ok(())
+    }
+
+    fn is_primitive(&self, shape: &str) -> bool {
+        self.shape(shape).map_or(false, |shape| shape.shape_type.references().is_empty())
+    }
+
+    /// The shape's name and, unless it is a structure, its kind. With collapsed
+    /// primitives, structures list their primitive members too.
+    fn label(&self, shape: &Shape, options: &DotOptions) -> String {
+        let members = match shape.shape_type {
+            ShapeType::Structure(Structure(ref members)) => members,
+            ShapeType::Exception(ref exception) => &exception.members,
+            ref other => return quote(&format!("{}\n({})", shape.name, other.kind())),
+        };
+        if !options.collapse_primitives {
+            return quote(&shape.name);
+        }
+        // `\l` ends a left-justified line; it goes in unescaped.
+        let mut label = format!("{}\\n", escape(&shape.name));
+        for member in members.iter().filter(|member| self.is_primitive(&member.shape)) {
+            let kind = self.shape(&member.shape).map_or("?", |shape| shape.shape_type.kind());
+            label.push_str(&escape(&format!("{}: {}", member.name, kind)));
+            label.push_str("\\l");
+        }
+        format!("\"{}\"", label)
+    }
+}
+
+fn operation_id(operation: &Operation) -> String {
+    format!("operation {}", operation.name)
+}
+
+fn operation_shapes(operation: &Operation) -> Vec<&str> {
+    let mut shapes = vec!(&operation.input.shape[..]);
+    shapes.extend(operation.output.as_ref().map(|output| &output.shape[..]));
+    shapes.extend(operation.errors.iter().map(|error| &error.shape[..]));
+    shapes
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", escape(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::testhelpers::{fixture_service, fixture_string};
+
+    #[test]
+    fn recursive() {
+        let service = fixture_service("services/recursive");
+        let mut buffer = Vec::new();
+        assert!(service.write_dot(&mut buffer, &DotOptions::default()).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert_eq!(fixture_string("generated/graph-recursive.dot"), actual);
+    }
+
+    #[test]
+    fn one_operation_collapsed() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let options = DotOptions {
+            operation: Some("GetPolicy".to_string()),
+            collapse_primitives: true,
+        };
+        let mut buffer = Vec::new();
+        assert!(service.write_dot(&mut buffer, &options).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert!(actual.contains("    \"operation GetPolicy\" [label=\"GetPolicy\", shape=ellipse];\n"));
+        assert!(actual.contains("    \"GetPolicyRequest\" [label=\"GetPolicyRequest\\nFunctionName: string\\lQualifier: string\\l\"];\n"));
+        assert!(actual.contains("    \"operation GetPolicy\" -> \"ServiceException\" [label=error, style=dashed];\n"));
+        assert!(!actual.contains("\"FunctionName\" ["));
+        assert!(!actual.contains("CreateFunction"));
+
+        let options = DotOptions { operation: Some("Frobnicate".to_string()), collapse_primitives: false };
+        assert!(service.write_dot(&mut Vec::new(), &options).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use serde_json::{self, Value};
+use ::parser::*;
+use super::schema::{Dialect, string};
+
+const DRAFT: &'static str = "https://json-schema.org/draft/2020-12/schema";
+
+impl ServiceDefinition {
+    /// A JSON Schema (draft 2020-12) document for a structure shape, on its own: the
+    /// structure and every shape it reaches are in `$defs`, and the document is a
+    /// `$ref` to the structure. Members sent in the URI or headers are properties too.
+    pub fn json_schema(&self, structure: &str) -> Result<Value, Error> {
+        match self.shape(structure).map(|shape| &shape.shape_type) {
+            Some(&ShapeType::Structure(_)) => {}
+            _ => return Err(Error::new(ErrorKind::InvalidInput, format!("{} is not a structure", structure))),
+        }
+        let definitions: BTreeMap<String, Value> = self.reachable(vec!(structure)).into_iter()
+            .filter_map(|name| self.shape(name))
+            .map(|shape| (shape.name.clone(), Dialect::JsonSchema.schema(shape)))
+            .collect();
+        let mut document = Dialect::JsonSchema.reference(structure);
+        {
+            let fields = document.as_object_mut().unwrap();
+            fields.insert("$schema".to_string(), string(DRAFT));
+            fields.insert("title".to_string(), string(structure));
+            fields.insert("$defs".to_string(), Value::Object(definitions));
+        }
+        Ok(document)
+    }
+
+    /// `json_schema()` for every structure, by name. Exceptions are left out: nobody
+    /// sends them.
+    pub fn json_schemas(&self) -> Result<BTreeMap<String, Value>, Error> {
+        let mut schemas = BTreeMap::new();
+        for shape in &self.shapes {
+            if let ShapeType::Structure(_) = shape.shape_type {
+                schemas.insert(shape.name.clone(), try!(self.json_schema(&shape.name)));
+            }
+        }
+        Ok(schemas)
+    }
+
+    /// Writes `json_schema()` as indented JSON.
+    pub fn write_json_schema<W: Write>(&self, out: &mut W, structure: &str) -> Result<(), Error> {
+        try!(serde_json::to_writer_pretty(out, &try!(self.json_schema(structure))));
+        try!(writeln!(out, ""));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::testhelpers::{fixture_service, fixture_string};
+    use serde_json::{self, Value};
+
+    #[test]
+    fn recursive() {
+        let service = fixture_service("services/recursive");
+        let mut buffer = Vec::new();
+        assert!(service.write_json_schema(&mut buffer, "EvaluateRequest").is_ok());
+        let actual: Value = serde_json::from_str(&String::from_utf8(buffer).unwrap()).unwrap();
+        let expected: Value = serde_json::from_str(&fixture_string("generated/schema-EvaluateRequest.json")).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn lambda() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let schema = service.json_schema("CreateFunctionRequest").unwrap();
+        assert_eq!(schema.find("$ref"), Some(&Value::String("#/$defs/CreateFunctionRequest".to_string())));
+        let definitions = schema.find("$defs").unwrap();
+        let request = definitions.find("CreateFunctionRequest").unwrap();
+        assert_eq!(request.find("required").unwrap().as_array().unwrap().len(), 5);
+        assert_eq!(request.find("additionalProperties"), Some(&Value::Bool(false)));
+        assert_eq!(definitions.find_path(&["MemorySize", "minimum"]), Some(&Value::I64(128)));
+        assert_eq!(definitions.find_path(&["MemorySize", "maximum"]), Some(&Value::I64(1536)));
+        assert!(definitions.find_path(&["Runtime", "enum"]).unwrap().as_array().unwrap()
+                           .contains(&Value::String("nodejs".to_string())));
+        assert!(definitions.find_path(&["FunctionName", "pattern"]).is_some());
+        assert_eq!(definitions.find_path(&["Blob", "contentEncoding"]), Some(&Value::String("base64".to_string())));
+        assert!(definitions.find("GetPolicyRequest").is_none());
+
+        let schemas = service.json_schemas().unwrap();
+        assert!(schemas.contains_key("GetPolicyRequest"));
+        assert!(!schemas.contains_key("ServiceException"));
+        assert!(service.json_schema("ServiceException").is_err());
+        assert!(service.json_schema("MemorySize").is_err());
+    }
+}
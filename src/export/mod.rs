@@ -0,0 +1,9 @@
+//! Writes service definitions out in formats other tools read.
+
+pub mod botocore;
+pub mod dot;
+pub mod openapi;
+pub mod json_schema;
+mod schema;
+
+pub use self::dot::DotOptions;
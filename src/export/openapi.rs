@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use serde_json::{self, Value};
+use ::parser::*;
+use ::parser::operation::HTTP;
+use super::schema::{Dialect, description, object, string};
+
+impl ServiceDefinition {
+    /// Translates the service into an OpenAPI 3.0 document: one path item per
+    /// `requestUri`, with an operation for each method, and a component schema per shape.
+    ///
+    /// Only `rest-json` services are addressed by path; other protocols are an
+    /// `InvalidInput` error, as are two operations on the same path and method.
+    pub fn openapi(&self) -> Result<Value, Error> {
+        if self.metadata.protocol != "rest-json" {
+            return Err(Error::new(ErrorKind::InvalidInput,
+                                  format!("OpenAPI export needs a rest-json service, not {}", self.metadata.protocol)));
+        }
+        let mut paths = BTreeMap::new();
+        for operation in &self.operations {
+            let path = path(&operation.http);
+            let method = operation.http.method.to_lowercase();
+            let item = paths.entry(path.clone()).or_insert_with(BTreeMap::new);
+            if let Some(other) = item.get(&method).and_then(|other: &Value| other.find("operationId")) {
+                return Err(Error::new(ErrorKind::InvalidInput,
+                                      format!("operations {} and {} are both {} {}", other.as_string().unwrap_or("?"),
+                                              operation.name, operation.http.method, path)));
+            }
+            item.insert(method, try!(self.openapi_operation(operation)));
+        }
+
+        let mut info = object(vec!(
+            ("title", string(&self.metadata.serviceFullName)),
+            ("version", string(&self.metadata.apiVersion)),
+        ));
+        if let Some(description) = description(&self.documentation) {
+            info.as_object_mut().unwrap().insert("description".to_string(), description);
+        }
+        let host = match self.metadata.globalEndpoint {
+            Some(ref endpoint) => endpoint.clone(),
+            None => format!("{}.{{region}}.amazonaws.com", self.metadata.endpointPrefix),
+        };
+        let mut server = object(vec!(("url", Value::String(format!("https://{}", host)))));
+        if self.metadata.globalEndpoint.is_none() {
+            server.as_object_mut().unwrap().insert("variables".to_string(), object(vec!(
+                ("region", object(vec!(("default", string("us-east-1"))))),
+            )));
+        }
+
+        let schemas = self.shapes.iter().map(|shape| (shape.name.clone(), Dialect::OpenApi.schema(shape))).collect();
+        Ok(object(vec!(
+            ("openapi", string("3.0.3")),
+            ("info", info),
+            ("servers", Value::Array(vec!(server))),
+            ("paths", Value::Object(paths.into_iter().map(|(path, item)| (path, Value::Object(item))).collect())),
+            ("components", object(vec!(("schemas", Value::Object(schemas))))),
+        )))
+    }
+
+    /// Writes `openapi()` as indented JSON.
+    pub fn write_openapi<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        try!(serde_json::to_writer_pretty(out, &try!(self.openapi())));
+        try!(writeln!(out, ""));
+        Ok(())
+    }
+
+    fn openapi_operation(&self, operation: &Operation) -> Result<Value, Error> {
+        let mut fields = vec!(("operationId", string(&operation.name)));
+        if let Some(description) = operation.documentation.as_ref().and_then(|doc| description(doc)) {
+            fields.push(("description", description));
+        }
+        if operation.deprecated == Some(true) {
+            fields.push(("deprecated", Value::Bool(true)));
+        }
+
+        let input = try!(self.structure_members(&operation.input.shape));
+        let parameters: Vec<Value> = input.iter().filter_map(|member| parameter(member)).collect();
+        if !parameters.is_empty() {
+            fields.push(("parameters", Value::Array(parameters)));
+        }
+        if let Some((media_type, schema)) = self.body(&operation.input.shape, &input) {
+            fields.push(("requestBody", object(vec!(
+                ("required", Value::Bool(input.iter().any(|member| is_body(member) && member.required))),
+                ("content", object(vec!((media_type, object(vec!(("schema", schema))))))),
+            ))));
+        }
+
+        let mut responses = BTreeMap::new();
+        let status = operation.http.responseCode.unwrap_or(200).to_string();
+        let mut response = vec!(("description", string("Success")));
+        if let Some(ref output) = operation.output {
+            if let Some(description) = output.documentation.as_ref().and_then(|doc| description(doc)) {
+                response[0].1 = description;
+            }
+            let members = try!(self.structure_members(&output.shape));
+            let headers: BTreeMap<String, Value> = members.iter().filter_map(|member| match member.location {
+                Location::Header(ref name) => Some((name.clone(), object(vec!(("schema", reference(&member.shape)))))),
+                _ => None,
+            }).collect();
+            if !headers.is_empty() {
+                response.push(("headers", Value::Object(headers)));
+            }
+            if let Some((media_type, schema)) = self.body(&output.shape, &members) {
+                response.push(("content", object(vec!((media_type, object(vec!(("schema", schema))))))));
+            }
+        }
+        responses.insert(status, object(response));
+
+        let mut errors: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+        for error in &operation.errors {
+            let status = match self.shape(&error.shape).map(|shape| &shape.shape_type) {
+                Some(&ShapeType::Exception(ref exception)) => exception.status_code,
+                _ => error.error.httpStatusCode as i64,
+            };
+            errors.entry(status.to_string()).or_insert_with(Vec::new).push(&error.shape);
+        }
+        for (status, shapes) in errors {
+            let schema = if shapes.len() == 1 {
+                reference(shapes[0])
+            } else {
+                object(vec!(("oneOf", Value::Array(shapes.iter().map(|shape| reference(shape)).collect()))))
+            };
+            responses.insert(status, object(vec!(
+                ("description", Value::String(shapes.join(" or "))),
+                ("content", object(vec!(("application/json", object(vec!(("schema", schema))))))),
+            )));
+        }
+        fields.push(("responses", Value::Object(responses)));
+        Ok(object(fields))
+    }
+
+    /// The media type and schema of the body of a request or response with these
+    /// members: the payload member's shape if there is one, sent as is when it is a
+    /// blob; the structure itself if all of it is in the body; or else an object of
+    /// just the body members. `None` if nothing is in the body.
+    fn body(&self, structure: &str, members: &[Member]) -> Option<(&'static str, Value)> {
+        if let Some(payload) = members.iter().find(|member| member.location == Location::Payload) {
+            return Some(match self.shape(&payload.shape).map(|shape| &shape.shape_type) {
+                Some(&ShapeType::Blob(_)) => ("application/octet-stream", reference(&payload.shape)),
+                _ => ("application/json", reference(&payload.shape)),
+            });
+        }
+        let body: Vec<&Member> = members.iter().filter(|member| is_body(member)).collect();
+        if body.is_empty() {
+            None
+        } else if body.len() == members.len() {
+            Some(("application/json", reference(structure)))
+        } else {
+            Some(("application/json", Dialect::OpenApi.object_schema(&body)))
+        }
+    }
+
+    fn structure_members(&self, shape: &str) -> Result<&[Member], Error> {
+        match self.shape(shape).map(|shape| &shape.shape_type) {
+            Some(&ShapeType::Structure(Structure(ref members))) => Ok(members),
+            _ => Err(Error::new(ErrorKind::InvalidInput, format!("{} is not a structure", shape))),
+        }
+    }
+}
+
+/// The path part of `requestUri`: without any query string, and with greedy labels
+/// (`{Key+}`), which OpenAPI has no way to say, as plain ones.
+fn path(http: &HTTP) -> String {
+    let uri = http.requestUri.split('?').next().unwrap_or("");
+    uri.replace("+}", "}")
+}
+
+fn parameter(member: &Member) -> Option<Value> {
+    let (name, place, required) = match member.location {
+        Location::URI(ref name) => (name, "path", true),
+        Location::QueryString(ref name) => (name, "query", member.required),
+        Location::Header(ref name) => (name, "header", member.required),
+        _ => return None,
+    };
+    let mut fields = vec!(
+        ("name", string(name)),
+        ("in", string(place)),
+        ("required", Value::Bool(required)),
+        ("schema", reference(&member.shape)),
+    );
+    if let Some(description) = member.documentation.as_ref().and_then(|doc| description(doc)) {
+        fields.push(("description", description));
+    }
+    Some(object(fields))
+}
+
+fn is_body(member: &Member) -> bool {
+    member.location == Location::Body || member.location == Location::Payload
+}
+
+fn reference(shape: &str) -> Value {
+    Dialect::OpenApi.reference(shape)
+}
+
+#[cfg(test)]
+mod tests {
+    use ::testhelpers::{fixture_service, fixture_string};
+    use serde_json::{self, Value};
+
+    #[test]
+    fn recursive() {
+        let service = fixture_service("services/recursive");
+        let mut buffer = Vec::new();
+        assert!(service.write_openapi(&mut buffer).is_ok());
+        let actual: Value = serde_json::from_str(&String::from_utf8(buffer).unwrap()).unwrap();
+        let expected: Value = serde_json::from_str(&fixture_string("generated/openapi-recursive.json")).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn lambda() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let document = service.openapi().unwrap();
+        let operation = document.find_path(&["paths", "/2015-03-31/functions/{FunctionName}/policy", "get"]).unwrap();
+        assert_eq!(operation.find("operationId"), Some(&Value::String("GetPolicy".to_string())));
+        let parameters = operation.find("parameters").unwrap().as_array().unwrap();
+        let names: Vec<(&str, &str)> = parameters.iter().map(|parameter| {
+            (parameter.find("name").unwrap().as_string().unwrap(), parameter.find("in").unwrap().as_string().unwrap())
+        }).collect();
+        assert_eq!(names, vec!(("FunctionName", "path")));
+        let body = operation.find_path(&["requestBody", "content", "application/json", "schema"]).unwrap();
+        let properties: Vec<&String> = body.find("properties").unwrap().as_object().unwrap().keys().collect();
+        assert_eq!(properties, vec!("Qualifier"));
+        assert_eq!(operation.find_path(&["responses", "200", "content", "application/json", "schema", "$ref"]),
+                   Some(&Value::String("#/components/schemas/GetPolicyResponse".to_string())));
+        let not_found = operation.find_path(&["responses", "404", "content", "application/json", "schema"]).unwrap();
+        assert_eq!(not_found.find("$ref").unwrap().as_string(), Some("#/components/schemas/ResourceNotFoundException"));
+
+        let schemas = document.find_path(&["components", "schemas"]).unwrap();
+        assert_eq!(schemas.find_path(&["MemorySize", "minimum"]), Some(&Value::I64(128)));
+        assert_eq!(schemas.find_path(&["MemorySize", "maximum"]), Some(&Value::I64(1536)));
+        assert!(schemas.find_path(&["Runtime", "enum"]).unwrap().as_array().unwrap()
+                       .contains(&Value::String("nodejs".to_string())));
+        assert!(schemas.find_path(&["FunctionName", "pattern"]).is_some());
+        assert_eq!(schemas.find_path(&["CreateFunctionRequest", "required"]).unwrap().as_array().unwrap().len(), 5);
+
+        let invoke = document.find_path(&["paths", "/2015-03-31/functions/{FunctionName}/invocations", "post"]).unwrap();
+        assert!(invoke.find_path(&["requestBody", "content", "application/octet-stream"]).is_some());
+        let success = invoke.find_path(&["responses", "200"]).unwrap();
+        let headers: Vec<&String> = success.find("headers").unwrap().as_object().unwrap().keys().collect();
+        assert_eq!(headers, vec!("X-Amz-Function-Error", "X-Amz-Log-Result"));
+        assert!(success.find_path(&["content", "application/octet-stream"]).is_some());
+    }
+
+    #[test]
+    fn other_protocols() {
+        let mut service = fixture_service("services/recursive");
+        service.metadata.protocol = "query".to_string();
+        assert!(service.openapi().is_err());
+    }
+}
@@ -0,0 +1,115 @@
+//! Shapes as JSON schemas, for both the OpenAPI document and standalone JSON Schema.
+
+use serde_json::Value;
+use ::parser::*;
+use ::generater::doc::{Markdown, WIDTH};
+
+/// The flavour of schema to write. OpenAPI 3.0 schemas are a dialect of an older JSON
+/// Schema draft and keep the shapes in `components`; JSON Schema 2020-12 documents keep
+/// them in `$defs` and can say that a blob is base64.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Dialect {
+    OpenApi,
+    JsonSchema,
+}
+
+impl Dialect {
+    /// The schema for a shape, with `$ref`s for the shapes it refers to.
+    pub fn schema(self, shape: &Shape) -> Value {
+        let fields = match shape.shape_type {
+            ShapeType::Blob(Blob(streaming)) => match self {
+                Dialect::OpenApi => vec!(
+                    ("type", string("string")),
+                    ("format", string(if streaming { "binary" } else { "byte" })),
+                ),
+                Dialect::JsonSchema => vec!(("type", string("string")), ("contentEncoding", string("base64"))),
+            },
+            ShapeType::Boolean => vec!(("type", string("boolean"))),
+            ShapeType::Double => vec!(("type", string("number")), ("format", string("double"))),
+            ShapeType::Float => vec!(("type", string("number")), ("format", string("float"))),
+            ShapeType::Integer(Integer { min, max }) => {
+                let mut fields = vec!(("type", string("integer")), ("format", string("int32")));
+                fields.extend(min.map(|min| ("minimum", Value::I64(min))));
+                fields.extend(max.map(|max| ("maximum", Value::I64(max))));
+                fields
+            }
+            ShapeType::Long => vec!(("type", string("integer")), ("format", string("int64"))),
+            ShapeType::List(List { ref member, min, max }) => {
+                let mut fields = vec!(("type", string("array")), ("items", self.reference(member)));
+                fields.extend(min.map(|min| ("minItems", Value::I64(min))));
+                fields.extend(max.map(|max| ("maxItems", Value::I64(max))));
+                fields
+            }
+            ShapeType::StringEnum(StringEnum(ref values)) => vec!(
+                ("type", string("string")),
+                ("enum", Value::Array(values.iter().map(|value| string(value)).collect())),
+            ),
+            ShapeType::StringPattern(StringPattern { ref pattern, min, max }) => {
+                let mut fields = vec!(("type", string("string")));
+                // `.*` is what the parser puts in when the model has no pattern.
+                if pattern != ".*" {
+                    fields.push(("pattern", string(pattern)));
+                }
+                fields.extend(min.map(|min| ("minLength", Value::I64(min))));
+                fields.extend(max.map(|max| ("maxLength", Value::I64(max))));
+                fields
+            }
+            ShapeType::Structure(Structure(ref members)) => {
+                return self.object_schema(&members.iter().collect::<Vec<_>>());
+            }
+            ShapeType::Exception(ref exception) => {
+                let mut schema = self.object_schema(&exception.members.iter().collect::<Vec<_>>());
+                if let Some(description) = exception.documentation.as_ref().and_then(|doc| description(doc)) {
+                    schema.as_object_mut().unwrap().insert("description".to_string(), description);
+                }
+                return schema;
+            }
+            // rest-json sends timestamps as seconds since the epoch.
+            ShapeType::Timestamp => vec!(("type", string("number"))),
+        };
+        object(fields)
+    }
+
+    /// An object with these members as its properties. JSON Schema objects also
+    /// reject properties the model does not have, as the services do.
+    pub fn object_schema(self, members: &[&Member]) -> Value {
+        let mut fields = vec!(
+            ("type", string("object")),
+            ("properties", Value::Object(members.iter().map(|member| {
+                (member.name.clone(), self.reference(&member.shape))
+            }).collect())),
+        );
+        let required: Vec<Value> = members.iter().filter(|member| member.required)
+                                          .map(|member| string(&member.name)).collect();
+        if !required.is_empty() {
+            fields.push(("required", Value::Array(required)));
+        }
+        if self == Dialect::JsonSchema {
+            fields.push(("additionalProperties", Value::Bool(false)));
+        }
+        object(fields)
+    }
+
+    /// A `$ref` to the schema for a shape.
+    pub fn reference(self, shape: &str) -> Value {
+        let schemas = match self {
+            Dialect::OpenApi => "#/components/schemas/",
+            Dialect::JsonSchema => "#/$defs/",
+        };
+        object(vec!(("$ref", Value::String(format!("{}{}", schemas, shape)))))
+    }
+}
+
+/// Documentation as CommonMark, which descriptions in both are written in.
+pub fn description(html: &str) -> Option<Value> {
+    let lines = Markdown::from_html(html).render(WIDTH);
+    if lines.is_empty() { None } else { Some(Value::String(lines.join("\n"))) }
+}
+
+pub fn object(fields: Vec<(&str, Value)>) -> Value {
+    Value::Object(fields.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+}
+
+pub fn string(s: &str) -> Value {
+    Value::String(s.to_string())
+}
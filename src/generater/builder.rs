@@ -0,0 +1,142 @@
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use ::parser::*;
+use ::parser::cycles::Boxed;
+use super::deprecation::{write_allow_deprecated, write_deprecated};
+use super::doc::write_doc;
+use super::naming::{field_name, type_name};
+
+impl ServiceDefinition {
+    /// Writes a builder for every structure used as an operation input.
+    pub fn generate_builders<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        let mut inputs: Vec<&str> = self.operations.iter().map(|o| &o.input.shape[..]).collect();
+        inputs.sort();
+        inputs.dedup();
+        let boxed = self.boxed();
+        for (i, input) in inputs.into_iter().enumerate() {
+            let shape = try!(self.shape(input).ok_or(Error::new(ErrorKind::InvalidInput,
+                format!("unknown input shape {}", input))));
+            if let ShapeType::Structure(ref structure) = shape.shape_type {
+                if i > 0 {
+                    try!(writeln!(out, ""));
+                }
+                try!(structure.generate_builder(out, input, self, &boxed));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Structure {
+    /// Writes `{Shape}Builder`, with a setter per member and a `build()` that fails
+    /// when a required member was never set. `boxed` is `service.boxed()`.
+    pub fn generate_builder<W: Write>(&self, out: &mut W, shape: &str, service: &ServiceDefinition, boxed: &Boxed)
+                                      -> Result<(), Error> {
+        let name = type_name(shape);
+        let builder = format!("{}Builder", name);
+        if self.0.iter().any(|member| field_name(&member.name) == "build") {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("{}: a member setter would be named build", shape)));
+        }
+        let boxed = boxed.members(shape);
+        let field_type = |member: &Member| if boxed.contains(&member.name) {
+            format!("Box<{}>", type_name(&member.shape))
+        } else {
+            type_name(&member.shape)
+        };
+        let allow_deprecated = service.touches_deprecated(shape);
+
+        try!(writeln!(out, "/// Builds a `{}`; start with `{}::builder()`.", name, name));
+        try!(write_allow_deprecated(out, "", allow_deprecated));
+        try!(writeln!(out, "#[derive(Debug, Default)]"));
+        try!(writeln!(out, "pub struct {} {{", builder));
+        for member in &self.0 {
+            try!(writeln!(out, "    {}: Option<{}>,", field_name(&member.name), field_type(member)));
+        }
+        try!(writeln!(out, "}}\n"));
+
+        try!(write_allow_deprecated(out, "", allow_deprecated));
+        try!(writeln!(out, "impl {} {{", name));
+        try!(writeln!(out, "    pub fn builder() -> {} {{", builder));
+        try!(writeln!(out, "        {}::default()", builder));
+        try!(writeln!(out, "    }}"));
+        try!(writeln!(out, "}}\n"));
+
+        try!(write_allow_deprecated(out, "", allow_deprecated));
+        try!(writeln!(out, "impl {} {{", builder));
+        for member in &self.0 {
+            let field = field_name(&member.name);
+            if let Some(ref documentation) = member.documentation {
+                try!(write_doc(out, "    ", "///", documentation));
+            }
+            if let Some(ref deprecation) = member.deprecated {
+                try!(write_deprecated(out, "    ", deprecation));
+            }
+            try!(writeln!(out, "    pub fn {}<V: Into<{}>>(mut self, value: V) -> Self {{", field, type_name(&member.shape)));
+            if boxed.contains(&member.name) {
+                try!(writeln!(out, "        self.{} = Some(Box::new(value.into()));", field));
+            } else {
+                try!(writeln!(out, "        self.{} = Some(value.into());", field));
+            }
+            try!(writeln!(out, "        self"));
+            try!(writeln!(out, "    }}\n"));
+        }
+
+        let required: Vec<&Member> = self.0.iter().filter(|member| member.required).collect();
+        try!(writeln!(out, "    /// Fails with the names of any required members that were not set."));
+        try!(writeln!(out, "    pub fn build(self) -> Result<{}, ::runtime::builder::MissingMembers> {{", name));
+        if !required.is_empty() {
+            try!(writeln!(out, "        let mut missing = vec!();"));
+            for member in &required {
+                try!(writeln!(out, "        if self.{}.is_none() {{", field_name(&member.name)));
+                try!(writeln!(out, "            missing.push({:?});", member.name));
+                try!(writeln!(out, "        }}"));
+            }
+            try!(writeln!(out, "        if !missing.is_empty() {{"));
+            try!(writeln!(out, "            return Err(::runtime::builder::MissingMembers {{ shape: {:?}, members: missing }});", shape));
+            try!(writeln!(out, "        }}"));
+        }
+        try!(writeln!(out, "        Ok({} {{", name));
+        for member in &self.0 {
+            let field = field_name(&member.name);
+            if !service.is_option(member) {
+                try!(writeln!(out, "            {0}: self.{0}.unwrap(),", field));
+            } else {
+                try!(writeln!(out, "            {0}: self.{0},", field));
+            }
+        }
+        try!(writeln!(out, "            __non_exhaustive: (),"));
+        try!(writeln!(out, "        }})"));
+        try!(writeln!(out, "    }}"));
+        try!(writeln!(out, "}}"));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::parser::*;
+    use ::testhelpers::{fixture_service, fixture_string};
+
+    #[test]
+    fn create_alias() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let structure = match service.shape("CreateAliasRequest").unwrap().shape_type {
+            ShapeType::Structure(ref structure) => structure,
+            _ => panic!("Not a structure!"),
+        };
+        let mut buffer = Vec::new();
+        assert!(structure.generate_builder(&mut buffer, "CreateAliasRequest", &service, &service.boxed()).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert_eq!(fixture_string("generated/builder-create-alias.rs"), actual);
+    }
+
+    #[test]
+    fn every_input() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let mut buffer = Vec::new();
+        assert!(service.generate_builders(&mut buffer).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert_eq!(actual.matches("pub fn build(self)").count(), 24);
+        assert!(actual.contains("pub struct InvokeAsyncRequestBuilder {\n"));
+    }
+}
@@ -0,0 +1,339 @@
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use ::parser::*;
+use ::parser::cycles::Boxed;
+use ::parser::paginators::Paginator;
+use ::parser::path::{self, Path, Segment};
+use super::deprecation::{write_allow_deprecated, write_deprecated};
+use super::doc::write_doc;
+use super::naming::{camel_case, field_name, snake_case, type_name};
+
+impl Metadata {
+    /// The name of the generated client trait, e.g. `Lambda` or `CognitoIdp`.
+    pub fn client_name(&self) -> String {
+        camel_case(&self.endpointPrefix)
+    }
+}
+
+impl ServiceDefinition {
+    /// Writes the client trait, with one method per operation plus the paginator and
+    /// waiter helpers, followed by the page iterators for paginated operations.
+    pub fn generate_client<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        try!(self.check_names());
+        let client = self.metadata.client_name();
+        try!(writeln!(out, "/// Every operation {} supports.", self.metadata.serviceFullName));
+        try!(writeln!(out, "pub trait {} {{", client));
+        try!(writeln!(out, "    type Error;"));
+        let boxed = self.boxed();
+        for operation in &self.operations {
+            try!(writeln!(out, ""));
+            try!(operation.generate(out, self, &boxed));
+            if let Some(paginator) = self.paginator(&operation.name) {
+                try!(writeln!(out, ""));
+                try!(paginator.generate_method(out, operation, self));
+            }
+        }
+        for waiter in &self.waiters {
+            try!(writeln!(out, ""));
+            try!(waiter.generate(out, self));
+        }
+        try!(writeln!(out, "}}"));
+        for paginator in &self.paginators {
+            try!(writeln!(out, ""));
+            try!(paginator.generate_iterator(out, self));
+        }
+        Ok(())
+    }
+}
+
+impl Operation {
+    /// Writes the trait method, documented with any examples the service provides and
+    /// deprecated when the operation is. `boxed` is `service.boxed()`.
+    pub fn generate<W: Write>(&self, out: &mut W, service: &ServiceDefinition, boxed: &Boxed) -> Result<(), Error> {
+        if let Some(ref documentation) = self.documentation {
+            try!(write_doc(out, "    ", "///", documentation));
+        }
+        let examples = service.examples_for(&self.name);
+        if !examples.is_empty() {
+            if self.documentation.is_some() {
+                try!(writeln!(out, "    ///"));
+            }
+            try!(writeln!(out, "    /// # Examples"));
+            for example in examples {
+                try!(writeln!(out, "    ///"));
+                try!(example.generate_doc(out, service, boxed));
+            }
+        }
+        if let Some(ref deprecation) = self.deprecation() {
+            try!(write_deprecated(out, "    ", deprecation));
+        }
+        let touches_deprecated = service.touches_deprecated(&self.input.shape) ||
+            self.output.as_ref().map_or(false, |output| service.touches_deprecated(&output.shape));
+        try!(write_allow_deprecated(out, "    ", touches_deprecated));
+        let output = match self.output {
+            Some(ref output) => type_name(&output.shape),
+            None => "()".to_string(),
+        };
+        try!(writeln!(out, "    fn {}(&self, input: &{}) -> Result<{}, Self::Error>;",
+                      snake_case(&self.name), type_name(&self.input.shape), output));
+        Ok(())
+    }
+}
+
+impl Paginator {
+    /// The provided trait method that starts paging, e.g. `list_functions_pages()`.
+    /// It is deprecated along with its operation.
+    pub fn generate_method<W: Write>(&self, out: &mut W, operation: &Operation, service: &ServiceDefinition)
+                                     -> Result<(), Error> {
+        try!(writeln!(out, "    /// Iterates over every page of `{}` results.", self.operation));
+        if let Some(ref deprecation) = operation.deprecation() {
+            try!(write_deprecated(out, "    ", deprecation));
+        }
+        try!(write_allow_deprecated(out, "    ", service.operation_touches_deprecated(operation)));
+        try!(writeln!(out, "    fn {}_pages(&self, input: {}) -> {}Pages<Self> where Self: Sized {{",
+                      snake_case(&self.operation), type_name(&operation.input.shape), camel_case(&self.operation)));
+        try!(writeln!(out, "        {}Pages {{", camel_case(&self.operation)));
+        try!(writeln!(out, "            client: self,"));
+        try!(writeln!(out, "            input: input,"));
+        try!(writeln!(out, "            done: false,"));
+        try!(writeln!(out, "        }}"));
+        try!(writeln!(out, "    }}"));
+        Ok(())
+    }
+
+    /// The iterator that feeds each page's output tokens back into the next request.
+    pub fn generate_iterator<W: Write>(&self, out: &mut W, service: &ServiceDefinition) -> Result<(), Error> {
+        let client = service.metadata.client_name();
+        let operation = try!(service.operation(&self.operation).ok_or(invalid(&self.operation, "unknown operation")));
+        let output = try!(operation.output.as_ref().ok_or(invalid(&self.operation, "operation has no output")));
+        let input_members = match service.shape(&operation.input.shape).map(|shape| &shape.shape_type) {
+            Some(&ShapeType::Structure(Structure(ref members))) => members,
+            _ => return Err(invalid(&self.operation, "input is not a structure")),
+        };
+
+        let allow_deprecated = service.operation_touches_deprecated(operation);
+
+        try!(writeln!(out, "/// Pages through `{}`; see `{}::{}_pages()`.", self.operation, client, snake_case(&self.operation)));
+        let pages = format!("{}Pages", camel_case(&self.operation));
+        try!(write_allow_deprecated(out, "", allow_deprecated));
+        try!(writeln!(out, "pub struct {}<'a, C: 'a + {}> {{", pages, client));
+        try!(writeln!(out, "    client: &'a C,"));
+        try!(writeln!(out, "    input: {},", type_name(&operation.input.shape)));
+        try!(writeln!(out, "    done: bool,"));
+        try!(writeln!(out, "}}\n"));
+
+        let items = try!(self.items(service, &output.shape).map_err(|message| invalid(&self.operation, &message)));
+        if self.limit_key.is_some() || items.is_some() {
+            try!(write_allow_deprecated(out, "", allow_deprecated));
+            try!(writeln!(out, "impl<'a, C: {}> {}<'a, C> {{", client, pages));
+        }
+        if let Some(ref limit_key) = self.limit_key {
+            let member = try!(input_members.iter().find(|m| m.name == *limit_key)
+                .ok_or(invalid(&self.operation, &format!("{} is not a member of the input", limit_key))));
+            let size = if service.is_option(member) { "Some(size)" } else { "size" };
+            try!(writeln!(out, "    /// Asks for at most `size` results a page, as `{}`.", limit_key));
+            try!(writeln!(out, "    pub fn page_size(mut self, size: {}) -> Self {{", type_name(&member.shape)));
+            try!(writeln!(out, "        self.input.{} = {};", field_name(limit_key), size));
+            try!(writeln!(out, "        self"));
+            try!(writeln!(out, "    }}"));
+        }
+        if let Some((element, ref expression)) = items {
+            if self.limit_key.is_some() {
+                try!(writeln!(out, ""));
+            }
+            try!(writeln!(out, "    /// Iterates over the `{}` of every page instead of the pages.", self.result_key[0]));
+            try!(writeln!(out, "    pub fn items(self) -> Box<Iterator<Item = Result<{}, C::Error>> + 'a> {{", type_name(element)));
+            try!(writeln!(out, "        Box::new(self.flat_map(|page| match page {{"));
+            try!(writeln!(out, "            Ok(output) => {{"));
+            try!(writeln!(out, "                let items = {}.unwrap_or(vec!());", expression));
+            try!(writeln!(out, "                items.into_iter().map(Ok).collect::<Vec<_>>()"));
+            try!(writeln!(out, "            }}"));
+            try!(writeln!(out, "            Err(err) => vec!(Err(err)),"));
+            try!(writeln!(out, "        }}))"));
+            try!(writeln!(out, "    }}"));
+        }
+        if self.limit_key.is_some() || items.is_some() {
+            try!(writeln!(out, "}}\n"));
+        }
+
+        try!(write_allow_deprecated(out, "", allow_deprecated));
+        try!(writeln!(out, "impl<'a, C: {}> Iterator for {}<'a, C> {{", client, pages));
+        try!(writeln!(out, "    type Item = Result<{}, C::Error>;\n", type_name(&output.shape)));
+        try!(writeln!(out, "    fn next(&mut self) -> Option<Self::Item> {{"));
+        try!(writeln!(out, "        if self.done {{"));
+        try!(writeln!(out, "            return None;"));
+        try!(writeln!(out, "        }}"));
+        try!(writeln!(out, "        let output = match self.client.{}(&self.input) {{", snake_case(&self.operation)));
+        try!(writeln!(out, "            Ok(output) => output,"));
+        try!(writeln!(out, "            Err(err) => {{"));
+        try!(writeln!(out, "                self.done = true;"));
+        try!(writeln!(out, "                return Some(Err(err));"));
+        try!(writeln!(out, "            }}"));
+        try!(writeln!(out, "        }};"));
+
+        let tokens: Vec<String> = self.input_token.iter().map(|t| snake_case(t)).collect();
+        for (token, path) in tokens.iter().zip(self.output_token.iter()) {
+            let expression = try!(path_expression(service, &output.shape, path)
+                .map_err(|message| invalid(&self.operation, &message)));
+            try!(writeln!(out, "        let {} = {};", token, expression));
+        }
+        let exhausted: Vec<String> = tokens.iter().map(|t| format!("{}.is_none()", t)).collect();
+        // A service that hands back the token it was sent would otherwise be asked for the same page forever.
+        let repeated: Vec<String> = tokens.iter().zip(self.input_token.iter()).map(|(token, member_name)| {
+            if input_members.iter().any(|m| m.name == *member_name && !service.is_option(m)) {
+                format!("{}.as_ref() == Some(&self.input.{})", token, field_name(member_name))
+            } else {
+                format!("{} == self.input.{}", token, field_name(member_name))
+            }
+        }).collect();
+        let mut done = format!("{} || {}", exhausted.join(" && "), repeated.join(" && "));
+        if let Some(ref more_results) = self.more_results {
+            let expression = try!(path_expression(service, &output.shape, more_results)
+                .map_err(|message| invalid(&self.operation, &message)));
+            done = format!("!{}.unwrap_or(false) || {}", expression, done);
+        }
+        try!(writeln!(out, "        self.done = {};", done));
+        for (token, member_name) in tokens.iter().zip(self.input_token.iter()) {
+            let required = input_members.iter().any(|m| m.name == *member_name && !service.is_option(m));
+            if required {
+                try!(writeln!(out, "        if let Some({0}) = {0} {{", token));
+                try!(writeln!(out, "            self.input.{} = {};", field_name(member_name), token));
+                try!(writeln!(out, "        }}"));
+            } else {
+                try!(writeln!(out, "        self.input.{} = {};", field_name(member_name), token));
+            }
+        }
+        try!(writeln!(out, "        Some(Ok(output))"));
+        try!(writeln!(out, "    }}"));
+        try!(writeln!(out, "}}"));
+        Ok(())
+    }
+
+    /// The element type of the list the single `result_key` selects and the expression
+    /// that moves it out of a page, or `None` when there is no such list to iterate over.
+    fn items<'a>(&self, service: &'a ServiceDefinition, output: &str) -> Result<Option<(&'a str, String)>, String> {
+        let segments = match (self.result_key.len(), self.result_key.first()) {
+            (1, Some(result_key)) if result_key.alternatives.len() == 1 => &result_key.alternatives[0],
+            _ => return Ok(None),
+        };
+        let mut expression = "Some(output)".to_string();
+        let mut current = output;
+        for segment in segments {
+            let (target, member) = try!(path::step(service, current, segment));
+            match member {
+                Some(member) if service.is_option(member) => {
+                    expression.push_str(&format!(".and_then(|v| v.{})", field_name(&member.name)));
+                }
+                Some(member) => expression.push_str(&format!(".map(|v| v.{})", field_name(&member.name))),
+                None => return Ok(None),
+            }
+            current = target;
+        }
+        match service.shape(current).map(|shape| &shape.shape_type) {
+            Some(&ShapeType::List(List { ref member, .. })) => Ok(Some((&member[..], expression))),
+            _ => Ok(None),
+        }
+    }
+}
+
+fn invalid(operation: &str, message: &str) -> Error {
+    Error::new(ErrorKind::InvalidInput, format!("paginator {}: {}", operation, message))
+}
+
+/// Builds an expression of type `Option<T>` that reads `path` out of a local named `output`.
+/// Optional members are followed with `and_then`, so a missing member anywhere yields `None`.
+fn path_expression(service: &ServiceDefinition, root: &str, path: &Path) -> Result<String, String> {
+    let mut alternatives = vec!();
+    for segments in &path.alternatives {
+        let mut expression = "Some(&output)".to_string();
+        let mut current = root;
+        for segment in segments {
+            let (target, member) = try!(path::step(service, current, segment));
+            match (segment, member) {
+                (&Segment::Member(ref name), Some(member)) => {
+                    if !service.is_option(member) {
+                        expression.push_str(&format!(".map(|v| &v.{})", field_name(name)));
+                    } else {
+                        expression.push_str(&format!(".and_then(|v| v.{}.as_ref())", field_name(name)));
+                    }
+                }
+                (&Segment::Index(index), _) if index == -1 => expression.push_str(".and_then(|v| v.last())"),
+                (&Segment::Index(index), _) if index >= 0 => {
+                    expression.push_str(&format!(".and_then(|v| v.get({}))", index));
+                }
+                _ => return Err(format!("cannot follow {:?} from {} to a single token", segment, current)),
+            }
+            current = target;
+        }
+        alternatives.push(format!("{}.cloned()", expression));
+    }
+    let mut expression = alternatives.remove(0);
+    for alternative in alternatives {
+        expression = format!("{}.or_else(|| {})", expression, alternative);
+    }
+    Ok(expression)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::parser::paginators::Paginators;
+    use ::parser::examples::Examples;
+    use ::testhelpers::{fixture_reader, fixture_service, fixture_string};
+
+    #[test]
+    fn client_name() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(service.metadata.client_name(), "Lambda");
+        service.metadata.endpointPrefix = "cognito-idp".to_string();
+        assert_eq!(service.metadata.client_name(), "CognitoIdp");
+    }
+
+    #[test]
+    fn list_functions_pages() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        service.add_paginators(Paginators::parse(fixture_reader("paginators/lambda-2015-03-31")).unwrap()).unwrap();
+        let mut buffer = Vec::new();
+        assert!(service.paginator("ListFunctions").unwrap().generate_iterator(&mut buffer, &service).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert_eq!(fixture_string("generated/paginator-list-functions.rs"), actual);
+    }
+
+    #[test]
+    fn token_paths() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let expression = |root: &str, path: &str| path_expression(&service, root, &Path::parse(path).unwrap());
+        assert_eq!(expression("ResourceNotFoundException", "Message"),
+                   Ok("Some(&output).and_then(|v| v.message.as_ref()).cloned()".to_string()));
+        assert_eq!(expression("ListFunctionsResponse", "NextMarker || Functions[-1].FunctionName"),
+                   Ok("Some(&output).and_then(|v| v.next_marker.as_ref()).cloned()\
+                       .or_else(|| Some(&output).and_then(|v| v.functions.as_ref()).and_then(|v| v.last())\
+                       .and_then(|v| v.function_name.as_ref()).cloned())".to_string()));
+        assert_eq!(expression("ListFunctionsResponse", "Functions[].FunctionName"),
+                   Err("cannot follow Flatten from FunctionList to a single token".to_string()));
+    }
+
+    #[test]
+    fn client_trait() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        service.add_paginators(Paginators::parse(fixture_reader("paginators/lambda-2015-03-31")).unwrap()).unwrap();
+        let mut buffer = Vec::new();
+        assert!(service.generate_client(&mut buffer).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert!(actual.starts_with("/// Every operation AWS Lambda supports.\npub trait Lambda {\n    type Error;\n"));
+        assert!(actual.contains("    fn delete_alias(&self, input: &DeleteAliasRequest) -> Result<(), Self::Error>;\n"));
+        assert!(actual.contains("    fn list_functions_pages(&self, input: ListFunctionsRequest) -> ListFunctionsPages<Self> where Self: Sized {\n"));
+        assert!(actual.contains("pub struct ListAliasesPages<'a, C: 'a + Lambda> {\n"));
+    }
+
+    #[test]
+    fn example_docs() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert!(service.add_examples(Examples::parse(fixture_reader("examples/lambda-2015-03-31")).unwrap()).is_empty());
+        let mut buffer = Vec::new();
+        assert!(service.generate_client(&mut buffer).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert!(actual.contains("    /// # Examples\n    ///\n    /// add-permission: This example adds a permission"));
+        assert!(actual.contains("    /// let output = client.list_functions(&ListFunctionsRequest {\n    ///     max_items: Some(2),\n"));
+    }
+}
@@ -0,0 +1,19 @@
+use std::io::Error;
+use std::io::prelude::*;
+use ::parser::Deprecation;
+
+/// Writes `#[deprecated]` for an item the model deprecates, with the model's message
+/// as the note.
+pub fn write_deprecated<W: Write>(out: &mut W, indent: &str, deprecation: &Deprecation) -> Result<(), Error> {
+    let note = deprecation.message.as_ref().map_or("deprecated in the service model", |message| &message[..]);
+    writeln!(out, "{}#[deprecated(note = {:?})]", indent, note)
+}
+
+/// Writes `#[allow(deprecated)]` when generated code refers to deprecated items, so that
+/// only the code calling them warns.
+pub fn write_allow_deprecated<W: Write>(out: &mut W, indent: &str, allow: bool) -> Result<(), Error> {
+    if allow {
+        try!(writeln!(out, "{}#[allow(deprecated)]", indent));
+    }
+    Ok(())
+}
@@ -0,0 +1,511 @@
+//! Converts the HTML subset used in AWS `documentation` strings into rustdoc Markdown.
+
+use std::io::Error;
+use std::io::prelude::*;
+use ::parser::ServiceDefinition;
+
+/// The column generated doc comments wrap at, counting indentation and the `///`.
+pub const WIDTH: usize = 100;
+
+/// Writes `html` as doc comment lines, each starting with `indent` and `marker`
+/// (`///` for items, `//!` for modules).
+pub fn write_doc<W: Write>(out: &mut W, indent: &str, marker: &str, html: &str) -> Result<(), Error> {
+    let width = WIDTH.saturating_sub(indent.len() + marker.len() + 1);
+    for line in Markdown::from_html(html).render(width) {
+        if line.is_empty() {
+            try!(writeln!(out, "{}{}", indent, marker));
+        } else {
+            try!(writeln!(out, "{}{} {}", indent, marker, line));
+        }
+    }
+    Ok(())
+}
+
+impl ServiceDefinition {
+    /// Writes the service documentation as the generated module's `//!` doc.
+    pub fn generate_module_doc<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        write_doc(out, "", "//!", &self.documentation)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Kind {
+    Text,
+    Code,
+}
+
+/// A paragraph, heading, list item or code block. `prefix` starts the first line
+/// (a list marker or heading hashes) and `indent` is the width of later lines' indentation.
+#[derive(Debug)]
+struct Block {
+    kind: Kind,
+    prefix: String,
+    indent: usize,
+    text: String,
+}
+
+/// An inline element waiting for its closing tag.
+enum Open {
+    Marker(&'static str, usize),
+    Link(Option<String>, usize),
+    Code(String),
+}
+
+#[derive(Debug)]
+pub struct Markdown {
+    blocks: Vec<Block>,
+}
+
+/// Builds blocks from a stream of tags and text.
+struct Builder {
+    blocks: Vec<Block>,
+    text: String,
+    pending_space: bool,
+    after_open: bool,
+    open: Vec<Open>,
+    /// Whether each open list is ordered, its item count, and its indentation.
+    lists: Vec<(bool, usize, usize)>,
+    indent: usize,
+    prefix: String,
+    heading: bool,
+    /// `**Note:**` or `**Important:**`, to lead the next paragraph.
+    label: Option<&'static str>,
+    preformatted: Option<String>,
+}
+
+impl Markdown {
+    pub fn from_html(html: &str) -> Markdown {
+        let mut builder = Builder {
+            blocks: vec!(),
+            text: String::new(),
+            pending_space: false,
+            after_open: false,
+            open: vec!(),
+            lists: vec!(),
+            indent: 0,
+            prefix: String::new(),
+            heading: false,
+            label: None,
+            preformatted: None,
+        };
+        let mut rest = html;
+        while !rest.is_empty() {
+            match rest.find('<') {
+                Some(0) if !starts_tag(rest) => {
+                    builder.text("<");
+                    rest = &rest[1..];
+                }
+                Some(0) => match rest.find('>') {
+                    Some(end) => {
+                        builder.tag(&rest[1..end]);
+                        rest = &rest[end + 1..];
+                    }
+                    None => {
+                        builder.text(rest);
+                        rest = "";
+                    }
+                },
+                Some(start) => {
+                    builder.text(&decode_entities(&rest[..start]));
+                    rest = &rest[start..];
+                }
+                None => {
+                    builder.text(&decode_entities(rest));
+                    rest = "";
+                }
+            }
+        }
+        builder.flush();
+        Markdown { blocks: builder.blocks }
+    }
+
+    /// Renders the Markdown as lines no wider than `width` where the words allow.
+    pub fn render(&self, width: usize) -> Vec<String> {
+        let mut lines = vec!();
+        let mut previous_item = false;
+        for block in &self.blocks {
+            let item = block.prefix.ends_with(". ") || block.prefix.ends_with("* ");
+            if !lines.is_empty() && !(item && previous_item) {
+                lines.push(String::new());
+            }
+            previous_item = item;
+            match block.kind {
+                Kind::Code => {
+                    lines.push("```text".to_string());
+                    for line in block.text.trim_matches('\n').lines() {
+                        lines.push(line.trim_right().to_string());
+                    }
+                    lines.push("```".to_string());
+                }
+                Kind::Text => lines.extend(wrap(block, width).into_iter()),
+            }
+        }
+        lines
+    }
+}
+
+impl Builder {
+    fn tag(&mut self, tag: &str) {
+        let closing = tag.starts_with('/');
+        let tag = tag.trim_left_matches('/').trim_right_matches('/');
+        let (name, attributes) = match tag.find(char::is_whitespace) {
+            Some(i) => (&tag[..i], &tag[i..]),
+            None => (tag, ""),
+        };
+        let name = name.to_lowercase();
+
+        if self.preformatted.is_some() {
+            if closing && (name == "pre" || name == "programlisting") {
+                let code = self.preformatted.take().unwrap();
+                self.blocks.push(Block { kind: Kind::Code, prefix: String::new(), indent: 0, text: code });
+            }
+            return;
+        }
+
+        match (&name[..], closing) {
+            ("p", _) | ("br", _) | ("div", _) | ("dd", _) => self.flush(),
+            ("fullname", false) | ("h1", false) | ("h2", false) | ("h3", false) | ("h4", false) => {
+                self.flush();
+                self.heading = true;
+            }
+            ("fullname", true) | ("h1", true) | ("h2", true) | ("h3", true) | ("h4", true) => self.flush(),
+            ("note", false) => {
+                self.flush();
+                self.label = Some("**Note:**");
+            }
+            ("important", false) => {
+                self.flush();
+                self.label = Some("**Important:**");
+            }
+            ("note", true) | ("important", true) => {
+                self.flush();
+                self.label = None;
+            }
+            ("ul", false) | ("ol", false) => {
+                self.flush();
+                self.lists.push((name == "ol", 0, self.indent));
+            }
+            ("ul", true) | ("ol", true) => {
+                self.flush();
+                if let Some((_, _, base)) = self.lists.pop() {
+                    self.indent = base;
+                    self.prefix = spaces(base);
+                }
+            }
+            ("li", false) => {
+                self.flush();
+                let (marker, base) = match self.lists.last_mut() {
+                    Some(&mut (true, ref mut count, base)) => {
+                        *count += 1;
+                        (format!("{}. ", count), base)
+                    }
+                    Some(&mut (false, _, base)) => ("* ".to_string(), base),
+                    None => ("* ".to_string(), self.indent),
+                };
+                self.prefix = format!("{}{}", spaces(base), marker);
+                self.indent = base + marker.len();
+            }
+            ("li", true) => self.flush(),
+            ("dt", false) => {
+                self.flush();
+                self.open_marker("**");
+            }
+            ("dt", true) => {
+                self.close();
+                self.flush();
+            }
+            ("pre", false) | ("programlisting", false) => {
+                self.flush();
+                self.preformatted = Some(String::new());
+            }
+            ("b", false) | ("strong", false) => self.open_marker("**"),
+            ("i", false) | ("em", false) => self.open_marker("*"),
+            ("code", false) => self.open.push(Open::Code(String::new())),
+            ("a", false) => {
+                let start = self.start();
+                self.open.push(Open::Link(attribute(attributes, "href"), start));
+            }
+            ("b", true) | ("strong", true) | ("i", true) | ("em", true) | ("code", true) | ("a", true) => self.close(),
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if let Some(ref mut code) = self.preformatted {
+            code.push_str(text);
+            return;
+        }
+        if let Some(&mut Open::Code(ref mut code)) = self.open.last_mut() {
+            code.push_str(text);
+            return;
+        }
+        for c in text.chars() {
+            if c.is_whitespace() {
+                self.pending_space = true;
+                continue;
+            }
+            self.space();
+            match c {
+                '\\' | '`' | '*' | '_' | '[' | ']' | '<' => {
+                    self.text.push('\\');
+                    self.text.push(c);
+                }
+                c => self.text.push(c),
+            }
+        }
+    }
+
+    /// Emits a pending space, unless the text just opened an inline element.
+    fn space(&mut self) {
+        if self.text.is_empty() {
+            if let Some(label) = self.label.take() {
+                self.text.push_str(label);
+                self.pending_space = true;
+            }
+        }
+        if self.pending_space && !self.text.is_empty() && !self.after_open {
+            self.text.push(' ');
+        }
+        self.pending_space = false;
+        self.after_open = false;
+    }
+
+    /// Where an inline element's content starts, after any pending space.
+    fn start(&mut self) -> usize {
+        self.space();
+        self.after_open = true;
+        self.text.len()
+    }
+
+    fn open_marker(&mut self, marker: &'static str) {
+        self.space();
+        self.text.push_str(marker);
+        self.after_open = true;
+        let start = self.text.len();
+        self.open.push(Open::Marker(marker, start));
+    }
+
+    fn close(&mut self) {
+        match self.open.pop() {
+            Some(Open::Marker(marker, start)) => {
+                if self.text.len() == start {
+                    // Nothing to emphasize, drop the opening marker.
+                    let len = start - marker.len();
+                    self.text.truncate(len);
+                } else {
+                    self.text.push_str(marker);
+                }
+                self.after_open = false;
+            }
+            Some(Open::Link(href, start)) => {
+                let label = self.text[start..].to_string();
+                self.text.truncate(start);
+                match href {
+                    Some(ref href) if !label.is_empty() => self.text.push_str(&format!("[{}]({})", label, href)),
+                    Some(ref href) => self.text.push_str(&format!("<{}>", href)),
+                    None => self.text.push_str(&label),
+                }
+                self.after_open = false;
+            }
+            Some(Open::Code(code)) => {
+                let code = code.split_whitespace().collect::<Vec<_>>().join(" ");
+                if !code.is_empty() {
+                    self.space();
+                    let mut ticks = "`".to_string();
+                    while code.contains(&ticks[..]) {
+                        ticks.push('`');
+                    }
+                    let padding = if code.starts_with('`') || code.ends_with('`') { " " } else { "" };
+                    self.text.push_str(&format!("{0}{1}{2}{1}{0}", ticks, padding, code));
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Ends the current block, keeping list item indentation for its continuation.
+    fn flush(&mut self) {
+        while !self.open.is_empty() {
+            self.close();
+        }
+        if !self.text.is_empty() {
+            let prefix = if self.heading { "# ".to_string() } else { self.prefix.clone() };
+            self.blocks.push(Block {
+                kind: Kind::Text,
+                prefix: prefix,
+                indent: self.indent,
+                text: self.text.clone(),
+            });
+            self.prefix = spaces(self.indent);
+        }
+        self.text.clear();
+        self.pending_space = false;
+        self.after_open = false;
+        self.heading = false;
+    }
+}
+
+fn wrap(block: &Block, width: usize) -> Vec<String> {
+    let mut lines = vec!();
+    let mut line = block.prefix.clone();
+    let mut empty = true;
+    for word in block.text.split(' ') {
+        if !empty && line.len() + 1 + word.len() > width {
+            lines.push(line);
+            line = spaces(block.indent);
+            empty = true;
+        }
+        if empty {
+            line.push_str(&escape_line_start(word));
+            empty = false;
+        } else {
+            line.push(' ');
+            line.push_str(word);
+        }
+    }
+    lines.push(line);
+    lines
+}
+
+/// Escapes a word that Markdown would read as a heading, quote or list marker
+/// if it started a line.
+fn escape_line_start(word: &str) -> String {
+    if word.starts_with('#') || word.starts_with('>') || word.starts_with('-') ||
+       word.starts_with('+') || word.starts_with('=') || word.starts_with('|') {
+        return format!("\\{}", word);
+    }
+    let digits = word.chars().take_while(|c| c.is_digit(10)).count();
+    if digits > 0 && (word[digits..].starts_with('.') || word[digits..].starts_with(')')) {
+        return format!("{}\\{}", &word[..digits], &word[digits..]);
+    }
+    word.to_string()
+}
+
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let start = match attributes.find(&format!("{}=", name)[..]) {
+        Some(i) => i + name.len() + 1,
+        None => return None,
+    };
+    let rest = &attributes[start..];
+    let quote = match rest.chars().next() {
+        Some(c) if c == '"' || c == '\'' => c,
+        _ => return rest.split_whitespace().next().map(|v| decode_entities(v)),
+    };
+    rest[1..].find(quote).map(|end| decode_entities(&rest[1..end + 1]))
+}
+
+/// Whether the `<` that `html` starts with opens a tag, rather than being a stray
+/// less-than sign such as in `a < b`.
+fn starts_tag(html: &str) -> bool {
+    html[1..].chars().next().map_or(false, |c| {
+        (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || c == '/' || c == '!'
+    })
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| &rest[1..end]);
+        let character = match entity {
+            Some("lt") => Some('<'),
+            Some("gt") => Some('>'),
+            Some("amp") => Some('&'),
+            Some("quot") => Some('"'),
+            Some("apos") => Some('\''),
+            Some("nbsp") => Some(' '),
+            Some(numeric) if numeric.starts_with("#x") || numeric.starts_with("#X") => {
+                u32::from_str_radix(&numeric[2..], 16).ok().and_then(::std::char::from_u32)
+            }
+            Some(numeric) if numeric.starts_with('#') => {
+                numeric[1..].parse().ok().and_then(::std::char::from_u32)
+            }
+            _ => None,
+        };
+        match (character, entity) {
+            (Some(c), Some(entity)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn spaces(count: usize) -> String {
+    (0..count).map(|_| ' ').collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::testhelpers::{fixture_service, fixture_string};
+
+    fn markdown(html: &str) -> String {
+        Markdown::from_html(html).render(60).join("\n")
+    }
+
+    #[test]
+    fn paragraphs() {
+        assert_eq!(markdown("<p>One.</p> <p> Two. </p>"), "One.\n\nTwo.");
+    }
+
+    #[test]
+    fn inline() {
+        assert_eq!(markdown("<p>Use <code>CreateFunction</code> or <b>update </b>it, <i>see</i> <a>Operations</a>.</p>"),
+                   "Use `CreateFunction` or **update** it, *see* Operations.");
+        assert_eq!(markdown("<a href=\"http://docs.aws.amazon.com/lambda\">the guide</a>"),
+                   "[the guide](http://docs.aws.amazon.com/lambda)");
+        assert_eq!(markdown("<code>a`b</code>"), "``a`b``");
+    }
+
+    #[test]
+    fn lists() {
+        assert_eq!(markdown("<p>Either:</p><ul><li><p>one</p></li><li>two<ol><li>nested</li></ol></li></ul><p>End</p>"),
+                   "Either:\n\n* one\n* two\n  1. nested\n\nEnd");
+    }
+
+    #[test]
+    fn notes_and_headings() {
+        assert_eq!(markdown("<fullname>AWS Lambda</fullname><note><p>Careful.</p></note>"),
+                   "# AWS Lambda\n\n**Note:** Careful.");
+    }
+
+    #[test]
+    fn code_blocks() {
+        assert_eq!(markdown("<pre><code>{\n  \"a\": 1\n}</code></pre>"), "```text\n{\n  \"a\": 1\n}\n```");
+    }
+
+    #[test]
+    fn entities_and_escaping() {
+        assert_eq!(markdown("<p>a &lt;b&gt; &amp; [c] *d* &#233;&#x41;&bogus;</p>"),
+                   "a \\<b> & \\[c\\] \\*d\\* éA&bogus;");
+    }
+
+    #[test]
+    fn stray_less_than() {
+        assert_eq!(markdown("<p>Between 1 < n <= 10 and <code>m</code> > 2.</p>"),
+                   "Between 1 \\< n \\<= 10 and `m` > 2.");
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(markdown("<ul><li>aaaa bbbb cccc dddd eeee ffff gggg hhhh iiii jjjj kkkk 123. llll</li></ul>"),
+                   "* aaaa bbbb cccc dddd eeee ffff gggg hhhh iiii jjjj kkkk\n  123\\. llll");
+    }
+
+    #[test]
+    fn module_doc() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let mut buffer = Vec::new();
+        assert!(service.generate_module_doc(&mut buffer).is_ok());
+        assert_eq!(fixture_string("generated/module-doc-lambda.rs"), String::from_utf8(buffer).unwrap());
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+use std::io::prelude::*;
+use ::parser::*;
+use ::parser::endpoints::{Endpoints, ResolvedEndpoint};
+
+impl Metadata {
+    /// Writes an `endpoint(region)` function for this service, a `signing_region(region)`
+    /// function for the region requests there are signed for, and a `signing_name(region)`
+    /// function for the service name they are signed with.
+    ///
+    /// Every region `endpoints` knows about for `endpointPrefix` gets its own match arm.
+    /// Any other region goes to the hostname template of the partition its name belongs
+    /// to, and failing that to the first partition's template, or to `globalEndpoint`
+    /// for global services. Regions are signed for as they are, and with the model's
+    /// signing name, unless the endpoint has a `credentialScope` naming another one.
+    pub fn generate_endpoint<W: Write>(&self, out: &mut W, endpoints: &Endpoints) -> Result<(), Error> {
+        let service = &self.endpointPrefix;
+
+        let mut known = BTreeMap::new();
+        for partition in &endpoints.partitions {
+            let mut regions = partition.regions.clone();
+            if let Some(service_endpoints) = partition.services.get(service) {
+                for (name, endpoint) in service_endpoints.endpoints.iter() {
+                    if !endpoint.deprecated {
+                        regions.push(name.to_string());
+                    }
+                }
+            }
+            for region in regions {
+                if let Some(resolved) = partition.resolve(service, &region, &[]) {
+                    known.insert(region, resolved);
+                }
+            }
+        }
+
+        // The first partition is the catch-all, so only the others need a prefix check.
+        let mut prefixed = vec!();
+        for partition in endpoints.partitions.iter().skip(1) {
+            for prefix in partition.region_prefixes() {
+                if let Some(template) = partition.resolve(service, "{}", &[]) {
+                    prefixed.push((prefix, template));
+                }
+            }
+        }
+        prefixed.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+        let fallback = endpoints.partitions.first().and_then(|p| p.resolve(service, "{}", &[]));
+
+        try!(writeln!(out, "/// Returns the endpoint for {} in `region`.", self.serviceFullName));
+        try!(writeln!(out, "pub fn endpoint(region: &str) -> ::runtime::endpoint::Url {{"));
+        try!(writeln!(out, "    match region {{"));
+        for (region, resolved) in known.iter() {
+            try!(writeln!(out, "        \"{}\" => {},", region, url(&resolved.protocol, &resolved.hostname)));
+        }
+        for &(ref prefix, ref template) in &prefixed {
+            try!(writeln!(out, "        _ if region.starts_with(\"{}\") => {},", prefix, url(&template.protocol, &template.hostname)));
+        }
+        match (self.globalEndpoint.as_ref(), fallback.as_ref()) {
+            (Some(global), _) => try!(writeln!(out, "        _ => {},", url("https", global))),
+            (None, Some(template)) => try!(writeln!(out, "        _ => {},", url(&template.protocol, &template.hostname))),
+            (None, None) => try!(writeln!(out, "        _ => {},", url("https", &format!("{}.{{}}.amazonaws.com", service)))),
+        }
+        try!(writeln!(out, "    }}"));
+        try!(writeln!(out, "}}\n"));
+
+        try!(writeln!(out, "/// Returns the region requests to {} in `region` are signed for.", self.serviceFullName));
+        try!(writeln!(out, "pub fn signing_region(region: &str) -> &str {{"));
+        // When other regions are signed for a scope, every arm is needed to keep the
+        // regions signed for as they are away from it.
+        let scoped_fallback = fallback.as_ref().map(|t| &t.signing_region[..]).and_then(|r| if r == "{}" { None } else { Some(r) });
+        let signed_for = |region: &str, signing_region: &str| if region == signing_region {
+            "region".to_string()
+        } else {
+            format!("\"{}\"", signing_region)
+        };
+        let mut arms = vec!();
+        for (region, resolved) in known.iter() {
+            if scoped_fallback.is_some() || resolved.signing_region != *region {
+                arms.push(format!("\"{}\" => {}", region, signed_for(region, &resolved.signing_region)));
+            }
+        }
+        for &(ref prefix, ref template) in &prefixed {
+            if scoped_fallback.is_some() || template.signing_region != "{}" {
+                arms.push(format!("_ if region.starts_with(\"{}\") => {}", prefix, signed_for("{}", &template.signing_region)));
+            }
+        }
+        if arms.is_empty() && scoped_fallback.is_none() {
+            try!(writeln!(out, "    region"));
+        } else {
+            try!(writeln!(out, "    match region {{"));
+            for arm in arms {
+                try!(writeln!(out, "        {},", arm));
+            }
+            match scoped_fallback {
+                Some(scope) => try!(writeln!(out, "        _ => \"{}\",", scope)),
+                None => try!(writeln!(out, "        _ => region,")),
+            }
+            try!(writeln!(out, "    }}"));
+        }
+        try!(writeln!(out, "}}\n"));
+
+        try!(writeln!(out, "/// Returns the service name requests to {} in `region` are signed with.", self.serviceFullName));
+        let name_for = |resolved: &ResolvedEndpoint| resolved.signing_name.clone().unwrap_or(self.signing_name().to_string());
+        let fallback_name = fallback.as_ref().map_or(self.signing_name().to_string(), |t| name_for(t));
+        let mut arms = vec!();
+        for (region, resolved) in known.iter() {
+            if name_for(resolved) != fallback_name {
+                arms.push(format!("\"{}\" => \"{}\"", region, name_for(resolved)));
+            }
+        }
+        for &(ref prefix, ref template) in &prefixed {
+            if name_for(template) != fallback_name {
+                arms.push(format!("_ if region.starts_with(\"{}\") => \"{}\"", prefix, name_for(template)));
+            }
+        }
+        if arms.is_empty() {
+            try!(writeln!(out, "pub fn signing_name(_region: &str) -> &'static str {{"));
+            try!(writeln!(out, "    \"{}\"", fallback_name));
+        } else {
+            try!(writeln!(out, "pub fn signing_name(region: &str) -> &'static str {{"));
+            try!(writeln!(out, "    match region {{"));
+            for arm in arms {
+                try!(writeln!(out, "        {},", arm));
+            }
+            try!(writeln!(out, "        _ => \"{}\",", fallback_name));
+            try!(writeln!(out, "    }}"));
+        }
+        try!(writeln!(out, "}}"));
+        Ok(())
+    }
+}
+
+/// The `Url` for a resolved hostname, or for a hostname template with `{}` in place of
+/// the region.
+fn url(protocol: &str, hostname: &str) -> String {
+    if hostname.contains("{}") {
+        format!("::runtime::endpoint::Url::new(\"{}\", &format!(\"{}\", region))", protocol, hostname)
+    } else {
+        format!("::runtime::endpoint::Url::new(\"{}\", \"{}\")", protocol, hostname)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::parser::*;
+    use ::parser::endpoints::Endpoints;
+    use ::testhelpers::{fixture_reader, fixture_string};
+
+    fn metadata(endpoint_prefix: &str, service_full_name: &str, global_endpoint: Option<&str>) -> Metadata {
+        Metadata {
+            apiVersion: "2015-03-31".to_string(),
+            endpointPrefix: endpoint_prefix.to_string(),
+            serviceFullName: service_full_name.to_string(),
+            globalEndpoint: global_endpoint.map(|s| s.to_string()),
+            signatureVersion: "v4".to_string(),
+            signingName: None,
+            protocol: "rest-json".to_string(),
+        }
+    }
+
+    fn generate(metadata: Metadata) -> String {
+        let endpoints = Endpoints::parse(fixture_reader("endpoints/endpoints")).unwrap();
+        let mut buffer = Vec::new();
+        assert!(metadata.generate_endpoint(&mut buffer, &endpoints).is_ok());
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn regional_service() {
+        let actual = generate(metadata("lambda", "AWS Lambda", None));
+        assert_eq!(fixture_string("generated/endpoint-lambda.rs"), actual);
+    }
+
+    #[test]
+    fn scoped_signing_name() {
+        let actual = generate(metadata("iot", "AWS IoT", None));
+        assert_eq!(fixture_string("generated/endpoint-iot.rs"), actual);
+    }
+
+    #[test]
+    fn global_service() {
+        let actual = generate(metadata("iam", "AWS Identity and Access Management", Some("iam.amazonaws.com")));
+        assert_eq!(fixture_string("generated/endpoint-iam.rs"), actual);
+    }
+}
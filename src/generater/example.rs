@@ -0,0 +1,233 @@
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use serde_json::Value;
+use ::parser::*;
+use ::parser::cycles::Boxed;
+use ::parser::examples::Example;
+use super::deprecation::write_allow_deprecated;
+use super::naming::{field_name, snake_case, type_name, variant_name};
+use super::doc::write_doc;
+
+impl Example {
+    /// Writes the rustdoc lines showing this example as a call on the client trait.
+    /// The block is `ignore`d since it needs a client implementation to run. `boxed`
+    /// is `service.boxed()`.
+    pub fn generate_doc<W: Write>(&self, out: &mut W, service: &ServiceDefinition, boxed: &Boxed) -> Result<(), Error> {
+        let operation = try!(service.operation(&self.operation).ok_or(self.invalid("unknown operation")));
+        let input = try!(literal(service, boxed, &operation.input.shape, &Value::Object(self.input.clone()), 0)
+            .map_err(|message| self.invalid(&message)));
+
+        let summary: Vec<&str> = self.title.iter().chain(self.description.iter()).map(|s| &s[..]).collect();
+        if !summary.is_empty() {
+            try!(write_doc(out, "    ", "///", &summary.join(": ")));
+            try!(writeln!(out, "    ///"));
+        }
+        try!(writeln!(out, "    /// ```rust,ignore"));
+        let call = format!("let output = client.{}(&{}).unwrap();", snake_case(&self.operation), input);
+        for line in call.lines() {
+            try!(writeln!(out, "    /// {}", line));
+        }
+        try!(writeln!(out, "    /// ```"));
+        Ok(())
+    }
+
+    /// Writes a unit test that deserializes the example input and output into the
+    /// generated types and checks they serialize back to the same JSON.
+    pub fn generate_test<W: Write>(&self, out: &mut W, service: &ServiceDefinition) -> Result<(), Error> {
+        let operation = try!(service.operation(&self.operation).ok_or(self.invalid("unknown operation")));
+        try!(writeln!(out, "    #[test]"));
+        try!(write_allow_deprecated(out, "    ", service.operation_touches_deprecated(operation)));
+        try!(writeln!(out, "    fn {}_{}() {{", snake_case(&self.operation), test_name(self.label())));
+        try!(writeln!(out, "        round_trip::<{}>({});", type_name(&operation.input.shape),
+                      raw_string(&Value::Object(self.input.clone()))));
+        if let (Some(output), Some(shape)) = (self.output.as_ref(), operation.output.as_ref()) {
+            try!(writeln!(out, "        round_trip::<{}>({});", type_name(&shape.shape), raw_string(&Value::Object(output.clone()))));
+        }
+        try!(writeln!(out, "    }}"));
+        Ok(())
+    }
+
+    /// Blobs are base64 in JSON and numeric timestamps become strings, so examples
+    /// holding either would not come back unchanged.
+    pub fn round_trips(&self, service: &ServiceDefinition) -> bool {
+        let operation = match service.operation(&self.operation) {
+            Some(operation) => operation,
+            None => return false,
+        };
+        let output = match (self.output.as_ref(), operation.output.as_ref()) {
+            (Some(output), Some(shape)) => round_trips(service, &shape.shape, &Value::Object(output.clone())),
+            _ => true,
+        };
+        output && round_trips(service, &operation.input.shape, &Value::Object(self.input.clone()))
+    }
+
+    fn invalid(&self, message: &str) -> Error {
+        Error::new(ErrorKind::InvalidInput, format!("{} example {}: {}", self.operation, self.label(), message))
+    }
+}
+
+impl ServiceDefinition {
+    /// Writes a test module with a serialization round trip for every example.
+    pub fn generate_example_tests<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        try!(writeln!(out, "#[cfg(test)]"));
+        try!(writeln!(out, "mod examples {{"));
+        try!(writeln!(out, "    use super::*;"));
+        try!(writeln!(out, "    use serde::{{Serialize, Deserialize}};"));
+        try!(writeln!(out, "    use serde_json::{{self, Value}};\n"));
+        try!(writeln!(out, "    fn without_nulls(value: Value) -> Value {{"));
+        try!(writeln!(out, "        match value {{"));
+        try!(writeln!(out, "            Value::Object(map) => Value::Object(map.into_iter()"));
+        try!(writeln!(out, "                .filter(|&(_, ref v)| *v != Value::Null)"));
+        try!(writeln!(out, "                .map(|(k, v)| (k, without_nulls(v)))"));
+        try!(writeln!(out, "                .collect()),"));
+        try!(writeln!(out, "            Value::Array(array) => Value::Array(array.into_iter().map(without_nulls).collect()),"));
+        try!(writeln!(out, "            value => value,"));
+        try!(writeln!(out, "        }}"));
+        try!(writeln!(out, "    }}\n"));
+        try!(writeln!(out, "    fn round_trip<T: Serialize + Deserialize>(json: &str) {{"));
+        try!(writeln!(out, "        let expected: Value = serde_json::from_str(json).unwrap();"));
+        try!(writeln!(out, "        let value: T = serde_json::from_str(json).unwrap();"));
+        try!(writeln!(out, "        assert_eq!(without_nulls(serde_json::to_value(&value)), expected);"));
+        try!(writeln!(out, "    }}"));
+        for example in self.examples.iter().filter(|example| example.round_trips(self)) {
+            try!(writeln!(out, ""));
+            try!(example.generate_test(out, self));
+        }
+        try!(writeln!(out, "}}"));
+        Ok(())
+    }
+}
+
+/// Renders `value` as a Rust expression of the type generated for `shape_name`.
+/// `indent` is the nesting depth, used to lay out struct literals.
+fn literal(service: &ServiceDefinition, boxed: &Boxed, shape_name: &str, value: &Value, indent: usize) -> Result<String, String> {
+    let shape = try!(service.shape(shape_name).ok_or(format!("unknown shape {}", shape_name)));
+    let mismatch = || format!("{:?} does not fit {}", value, shape_name);
+    match shape.shape_type {
+        ShapeType::Structure(Structure(ref members)) |
+        ShapeType::Exception(Exception { ref members, .. }) => {
+            let obj = try!(value.as_object().ok_or(mismatch()));
+            let padding = indentation(indent + 1);
+            let mut fields = vec!();
+            for (name, value) in obj.iter() {
+                let member = try!(members.iter().find(|m| m.name == *name)
+                    .ok_or(format!("{} is not a member of {}", name, shape_name)));
+                let mut value = try!(literal(service, boxed, &member.shape, value, indent + 1));
+                if boxed.contains(shape_name, name) {
+                    value = format!("Box::new({})", value);
+                }
+                if !service.is_option(member) {
+                    fields.push(format!("{}{}: {},", padding, field_name(name), value));
+                } else {
+                    fields.push(format!("{}{}: Some({}),", padding, field_name(name), value));
+                }
+            }
+            fields.push(format!("{}..Default::default()", padding));
+            Ok(format!("{} {{\n{}\n{}}}", type_name(shape_name), fields.join("\n"), indentation(indent)))
+        }
+        ShapeType::List(List { member: ref element, .. }) => {
+            let array = try!(value.as_array().ok_or(mismatch()));
+            let mut elements = vec!();
+            for value in array {
+                elements.push(try!(literal(service, boxed, element, value, indent)));
+            }
+            Ok(format!("vec!({})", elements.join(", ")))
+        }
+        ShapeType::StringEnum(_) => {
+            let value = try!(value.as_string().ok_or(mismatch()));
+            Ok(format!("{}::{}", type_name(shape_name), variant_name(value)))
+        }
+        ShapeType::StringPattern(_) => Ok(format!("{:?}.to_string()", try!(value.as_string().ok_or(mismatch())))),
+        ShapeType::Timestamp => match *value {
+            Value::String(ref s) => Ok(format!("{:?}.to_string()", s)),
+            ref value if value.is_number() => Ok(format!("\"{}\".to_string()", value)),
+            _ => Err(mismatch()),
+        },
+        ShapeType::Blob(Blob(streaming)) => {
+            let bytes = format!("b{:?}.to_vec()", try!(value.as_string().ok_or(mismatch())));
+            if streaming {
+                Ok(format!("{}::from({})", type_name(shape_name), bytes))
+            } else {
+                Ok(bytes)
+            }
+        }
+        ShapeType::Boolean => Ok(format!("{}", try!(value.as_boolean().ok_or(mismatch())))),
+        ShapeType::Integer(_) | ShapeType::Long => Ok(format!("{}", try!(value.as_i64().ok_or(mismatch())))),
+        ShapeType::Double | ShapeType::Float => Ok(format!("{:?}", try!(value.as_f64().ok_or(mismatch())))),
+    }
+}
+
+fn indentation(depth: usize) -> String {
+    (0..depth).map(|_| "    ").collect()
+}
+
+fn round_trips(service: &ServiceDefinition, shape_name: &str, value: &Value) -> bool {
+    let shape = match service.shape(shape_name) {
+        Some(shape) => shape,
+        None => return false,
+    };
+    match (&shape.shape_type, value) {
+        (&ShapeType::Structure(Structure(ref members)), &Value::Object(ref obj)) |
+        (&ShapeType::Exception(Exception { ref members, .. }), &Value::Object(ref obj)) => {
+            obj.iter().all(|(name, value)| {
+                members.iter().find(|m| m.name == *name).map_or(false, |m| round_trips(service, &m.shape, value))
+            })
+        }
+        (&ShapeType::List(List { member: ref element, .. }), &Value::Array(ref array)) => {
+            array.iter().all(|value| round_trips(service, element, value))
+        }
+        (&ShapeType::Blob(_), _) => false,
+        (&ShapeType::Timestamp, value) => value.is_string(),
+        _ => true,
+    }
+}
+
+/// Turns an example id or title into part of a test function name.
+fn test_name(label: &str) -> String {
+    let mut name = String::new();
+    for c in label.chars() {
+        if c.is_alphanumeric() {
+            name.extend(c.to_lowercase());
+        } else if !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    name.trim_matches('_').to_string()
+}
+
+/// A raw string literal holding `value` as compact JSON.
+fn raw_string(value: &Value) -> String {
+    let json = format!("{}", value);
+    let mut hashes = "#".to_string();
+    while json.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+    format!("r{0}\"{1}\"{0}", hashes, json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_name;
+    use ::parser::examples::Examples;
+    use ::testhelpers::{fixture_reader, fixture_service, fixture_string};
+
+    #[test]
+    fn test_names() {
+        assert_eq!(test_name("To create a function"), "to_create_a_function");
+        assert_eq!(test_name("add-permission-1474651469455"), "add_permission_1474651469455");
+    }
+
+    #[test]
+    fn doc_and_tests() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert!(service.add_examples(Examples::parse(fixture_reader("examples/lambda-2015-03-31")).unwrap()).is_empty());
+
+        let mut buffer = Vec::new();
+        assert!(service.examples_for("CreateFunction")[0].generate_doc(&mut buffer, &service, &service.boxed()).is_ok());
+        assert_eq!(fixture_string("generated/example-create-function.rs"), String::from_utf8(buffer).unwrap());
+
+        let mut buffer = Vec::new();
+        assert!(service.generate_example_tests(&mut buffer).is_ok());
+        assert_eq!(fixture_string("generated/example-tests.rs"), String::from_utf8(buffer).unwrap());
+    }
+}
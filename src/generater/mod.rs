@@ -1,3 +1,16 @@
+pub mod builder;
+pub mod client;
+pub mod deprecation;
+pub mod doc;
+pub mod endpoint;
+pub mod example;
+pub mod module;
+pub mod naming;
+pub mod package;
+pub mod semver;
 pub mod shape;
+pub mod signature;
+pub mod validate;
+pub mod waiter;
 
 pub use self::shape::*;
@@ -0,0 +1,55 @@
+use std::io::Error;
+use std::io::prelude::*;
+use ::parser::*;
+use ::parser::endpoints::Endpoints;
+
+impl ServiceDefinition {
+    /// Writes everything generated for the service as a single module file: the
+    /// service documentation followed by `generate_items()`.
+    pub fn generate_module<W: Write>(&self, out: &mut W, endpoints: Option<&Endpoints>) -> Result<(), Error> {
+        try!(self.generate_module_doc(out));
+        try!(writeln!(out, ""));
+        self.generate_items(out, endpoints)
+    }
+
+    /// Writes the shapes, request builders and validators, the signer, the endpoint
+    /// resolver when `endpoints` is given, the client trait and the example tests.
+    /// Unlike a module file, these can be pulled into a module with `include!`.
+    pub fn generate_items<W: Write>(&self, out: &mut W, endpoints: Option<&Endpoints>) -> Result<(), Error> {
+        try!(self.generate_shapes(out));
+        try!(writeln!(out, ""));
+        try!(self.generate_builders(out));
+        try!(writeln!(out, ""));
+        try!(self.generate_validators(out));
+        try!(writeln!(out, ""));
+        try!(self.metadata.generate_signer(out, endpoints.is_some()));
+        if let Some(endpoints) = endpoints {
+            try!(writeln!(out, ""));
+            try!(self.metadata.generate_endpoint(out, endpoints));
+        }
+        try!(writeln!(out, ""));
+        try!(self.generate_client(out));
+        try!(writeln!(out, ""));
+        self.generate_example_tests(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::testhelpers::fixture_service;
+
+    #[test]
+    fn lambda() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let mut buffer = Vec::new();
+        assert!(service.generate_module(&mut buffer, None).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert!(actual.starts_with("//! "));
+        for item in &["pub struct CreateFunctionRequest {", "pub struct CreateFunctionRequestBuilder {",
+                      "impl ::runtime::validate::Validate for FunctionCode {", "pub fn sign(",
+                      "pub trait Lambda {", "mod examples {"] {
+            assert!(actual.contains(item), "missing {}", item);
+        }
+        assert!(!actual.contains("pub fn endpoint("));
+    }
+}
@@ -0,0 +1,272 @@
+//! Turns names from service definitions into Rust identifiers.
+//!
+//! Names are split into words at separators (`-`, `.`, `$`, ...), at lowercase to
+//! uppercase changes and at digit to uppercase changes. A run of capitals is one
+//! word, so acronyms come out the same wherever they appear: `FunctionARN` and
+//! `ARNList` become `FunctionArn`/`function_arn` and `ArnList`/`arn_list`.
+
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind};
+use ::parser::*;
+
+/// Rust keywords, reserved words and the names generated code takes for granted,
+/// including those reserved since the 2018 edition so generated crates can move to it.
+const KEYWORDS: &'static [&'static str] = &[
+    "abstract", "alignof", "as", "async", "await", "become", "box", "break", "const",
+    "continue", "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for",
+    "if", "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "offsetof",
+    "override", "priv", "proc", "pub", "pure", "ref", "return", "self", "sizeof", "static",
+    "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Type names that would shadow the prelude types generated code refers to.
+const RESERVED_TYPES: &'static [&'static str] = &[
+    "Self", "String", "Vec", "Option", "Some", "None", "Result", "Ok", "Err", "Box", "Default",
+];
+
+fn words(name: &str) -> Vec<String> {
+    let mut words = vec!();
+    let mut word = String::new();
+    let chars: Vec<char> = name.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(word);
+                word = String::new();
+            }
+            continue;
+        }
+        let previous = word.chars().last();
+        if let Some(previous) = previous {
+            let next_is_lower = chars.get(i + 1).map_or(false, |n| n.is_lowercase());
+            let boundary = c.is_uppercase() && (previous.is_lowercase() || previous.is_numeric() ||
+                                                (previous.is_uppercase() && next_is_lower));
+            if boundary {
+                words.push(word);
+                word = String::new();
+            }
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// `ListFunctions` → `list_functions`, `type` → `type_`.
+pub fn snake_case(name: &str) -> String {
+    let snake: Vec<String> = words(name).iter().map(|word| word.to_lowercase()).collect();
+    let mut snake = snake.join("_");
+    if snake.is_empty() || snake.chars().next().map_or(false, |c| c.is_numeric()) {
+        snake = format!("_{}", snake);
+    }
+    if KEYWORDS.contains(&&snake[..]) {
+        snake.push('_');
+    }
+    snake
+}
+
+/// `cognito-idp` → `CognitoIdp`, `python2.7` → `Python27`.
+pub fn camel_case(name: &str) -> String {
+    let mut camel = String::new();
+    for word in words(name) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            camel.extend(first.to_uppercase());
+            camel.push_str(&chars.as_str().to_lowercase());
+        }
+    }
+    if camel.is_empty() || camel.chars().next().map_or(false, |c| c.is_numeric()) {
+        camel = format!("V{}", camel);
+    }
+    camel
+}
+
+/// The name of the type generated for a shape; `String` becomes `StringType`.
+pub fn type_name(shape: &str) -> String {
+    let name = camel_case(shape);
+    if RESERVED_TYPES.contains(&&name[..]) {
+        format!("{}Type", name)
+    } else {
+        name
+    }
+}
+
+/// The name of a struct field generated for a member.
+pub fn field_name(member: &str) -> String {
+    snake_case(member)
+}
+
+/// The name of an enum variant generated for one of a shape's string values.
+pub fn variant_name(value: &str) -> String {
+    let name = camel_case(value);
+    if name == "Self" {
+        "SelfValue".to_string()
+    } else {
+        name
+    }
+}
+
+/// The `#[serde(rename)]` attribute needed when `name` is not spelled like `original`.
+pub fn rename(original: &str, name: &str) -> Option<String> {
+    if original == name {
+        None
+    } else {
+        Some(format!("#[serde(rename = {:?})]", original))
+    }
+}
+
+/// Fails if two of `originals` convert to the same identifier, e.g. `FunctionArn`
+/// and `FunctionARN` in one structure.
+pub fn check_unique<'a, I, F>(what: &str, originals: I, convert: F) -> Result<(), Error>
+    where I: Iterator<Item=&'a str>, F: Fn(&str) -> String
+{
+    let mut seen: BTreeMap<String, &str> = BTreeMap::new();
+    for original in originals {
+        let name = convert(original);
+        if let Some(other) = seen.get(&name) {
+            return Err(Error::new(ErrorKind::InvalidInput,
+                format!("{}: {} and {} both become {}", what, other, original, name)));
+        }
+        seen.insert(name, original);
+    }
+    Ok(())
+}
+
+impl ServiceDefinition {
+    /// Checks that shapes and operations still have distinct names once they are
+    /// Rust identifiers, so a collision is reported rather than generated. Shapes
+    /// must also leave the names of the client trait, the builders and the page
+    /// iterators alone.
+    pub fn check_names(&self) -> Result<(), Error> {
+        try!(check_unique("shapes", self.shapes.iter().map(|s| &s.name[..]), type_name));
+        let mut generated = vec!((self.metadata.client_name(), "the client trait".to_string()));
+        for operation in &self.operations {
+            let input = &operation.input.shape;
+            if let Some(&Shape { shape_type: ShapeType::Structure(_), .. }) = self.shape(input) {
+                generated.push((format!("{}Builder", type_name(input)), format!("the builder for {}", input)));
+            }
+        }
+        for paginator in &self.paginators {
+            let pages = format!("{}Pages", camel_case(&paginator.operation));
+            generated.push((pages, format!("the pages of {}", paginator.operation)));
+        }
+        for shape in &self.shapes {
+            let name = type_name(&shape.name);
+            if let Some(&(_, ref what)) = generated.iter().find(|&&(ref generated, _)| *generated == name) {
+                return Err(Error::new(ErrorKind::InvalidInput,
+                    format!("shapes: {} and {} both become {}", shape.name, what, name)));
+            }
+        }
+        try!(check_unique("operations", self.operations.iter().map(|o| &o.name[..]), snake_case));
+        let waiters = self.waiters.iter().map(|w| &w.name[..]);
+        try!(check_unique("waiters", waiters, snake_case));
+        for shape in &self.shapes {
+            match shape.shape_type {
+                ShapeType::Structure(Structure(ref members)) |
+                ShapeType::Exception(Exception { ref members, .. }) => {
+                    try!(check_unique(&shape.name, members.iter().map(|m| &m.name[..]), field_name));
+                }
+                ShapeType::StringEnum(StringEnum(ref variants)) => {
+                    try!(check_unique(&shape.name, variants.iter().map(|v| &v[..]), variant_name));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::parser::*;
+    use ::parser::paginators::Paginators;
+    use ::testhelpers::{fixture_reader, fixture_service};
+
+    #[test]
+    fn snake() {
+        assert_eq!(snake_case("ListFunctions"), "list_functions");
+        assert_eq!(snake_case("Marker"), "marker");
+        assert_eq!(snake_case("S3Bucket"), "s3_bucket");
+        assert_eq!(snake_case("FunctionARN"), "function_arn");
+        assert_eq!(snake_case("ARNList"), "arn_list");
+        assert_eq!(snake_case("Type"), "type_");
+        assert_eq!(snake_case("match"), "match_");
+        assert_eq!(snake_case("Async"), "async_");
+        assert_eq!(snake_case("Await"), "await_");
+        assert_eq!(snake_case("Dyn"), "dyn_");
+        assert_eq!(snake_case("Try"), "try_");
+        assert_eq!(snake_case("2FA"), "_2_fa");
+    }
+
+    #[test]
+    fn camel() {
+        assert_eq!(camel_case("cognito-idp"), "CognitoIdp");
+        assert_eq!(camel_case("python2.7"), "Python27");
+        assert_eq!(camel_case("nodejs4.3-edge"), "Nodejs43Edge");
+        assert_eq!(camel_case("$LATEST"), "Latest");
+        assert_eq!(camel_case("FunctionARN"), "FunctionArn");
+        assert_eq!(camel_case("2015"), "V2015");
+    }
+
+    #[test]
+    fn reserved() {
+        assert_eq!(type_name("String"), "StringType");
+        assert_eq!(type_name("__string"), "StringType");
+        assert_eq!(type_name("Self"), "SelfType");
+        assert_eq!(variant_name("Self"), "SelfValue");
+        assert_eq!(type_name("FunctionConfiguration"), "FunctionConfiguration");
+    }
+
+    #[test]
+    fn renames() {
+        assert_eq!(rename("Marker", "marker"), Some("#[serde(rename = \"Marker\")]".to_string()));
+        assert_eq!(rename("nodejs", "nodejs"), None);
+    }
+
+    #[test]
+    fn lambda_names_are_unique() {
+        assert!(fixture_service("services/lambda-2015-03-31").check_names().is_ok());
+    }
+
+    #[test]
+    fn collision() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        service.shapes.push(Shape {
+            name: "Function-Configuration".to_string(),
+            shape_type: ShapeType::Boolean,
+            deprecated: None,
+        });
+        let err = service.check_names().unwrap_err();
+        assert_eq!(format!("{}", err), "shapes: FunctionConfiguration and Function-Configuration both become FunctionConfiguration");
+    }
+
+    #[test]
+    fn generated_collision() {
+        let collides = |name: &str| {
+            let mut service = fixture_service("services/lambda-2015-03-31");
+            service.add_paginators(Paginators::parse(fixture_reader("paginators/lambda-2015-03-31")).unwrap()).unwrap();
+            service.shapes.push(Shape {
+                name: name.to_string(),
+                shape_type: ShapeType::Boolean,
+                deprecated: None,
+            });
+            format!("{}", service.check_names().unwrap_err())
+        };
+        assert_eq!(collides("Lambda"), "shapes: Lambda and the client trait both become Lambda");
+        assert_eq!(collides("GetPolicyRequestBuilder"),
+                   "shapes: GetPolicyRequestBuilder and the builder for GetPolicyRequest both become GetPolicyRequestBuilder");
+        assert_eq!(collides("ListFunctionsPages"),
+                   "shapes: ListFunctionsPages and the pages of ListFunctions both become ListFunctionsPages");
+    }
+
+    #[test]
+    fn variant_collision() {
+        let variants = vec!("nodejs4.3", "nodejs43");
+        let err = check_unique("Runtime", variants.into_iter(), variant_name).unwrap_err();
+        assert_eq!(format!("{}", err), "Runtime: nodejs4.3 and nodejs43 both become Nodejs43");
+    }
+}
@@ -0,0 +1,252 @@
+//! Generates a whole Cargo package for a service, ready to build and publish on its own.
+
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use std::path::PathBuf;
+use ::parser::*;
+use ::parser::endpoints::Endpoints;
+use super::doc::{Markdown, WIDTH};
+use super::naming::snake_case;
+
+/// Where a generated package gets the support code in `runtime` from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Runtime {
+    /// A published version of this crate.
+    Version(String),
+    /// A checkout of this crate, which lets the package build offline.
+    Path(PathBuf),
+}
+
+impl Default for Runtime {
+    fn default() -> Runtime {
+        Runtime::Version(env!("CARGO_PKG_VERSION").to_string())
+    }
+}
+
+impl Metadata {
+    /// The package name, `aws-` and the endpoint prefix: `aws-lambda`, `aws-cognito-idp`.
+    pub fn package_name(&self) -> String {
+        package_name(&self.endpointPrefix)
+    }
+
+    /// The package version, the API version read as a date: `2015-03-31` → `2015.3.31`.
+    pub fn package_version(&self) -> Result<String, Error> {
+        let parts: Vec<u32> = self.apiVersion.split('-').filter_map(|part| part.parse().ok()).collect();
+        if parts.len() == 3 && self.apiVersion.split('-').count() == 3 {
+            Ok(format!("{}.{}.{}", parts[0], parts[1], parts[2]))
+        } else {
+            Err(Error::new(ErrorKind::InvalidInput, format!("apiVersion {} is not a date", self.apiVersion)))
+        }
+    }
+}
+
+/// `aws-` and `name` in lower case with hyphens: `cognito-idp` → `aws-cognito-idp`.
+pub fn package_name(name: &str) -> String {
+    format!("aws-{}", snake_case(name).trim_matches('_').replace("_", "-"))
+}
+
+impl ServiceDefinition {
+    /// Generates the files of a package for the service, as paths relative to the
+    /// package directory with their contents:
+    ///
+    /// * `Cargo.toml` and `README.md`,
+    /// * `src/lib.rs`, which re-exports the modules below,
+    /// * `src/model.rs` with the shapes, request builders, validators and example tests,
+    /// * `src/error.rs` with the exceptions,
+    /// * `src/protocol.rs` with the signer and, when `endpoints` is given, the endpoint resolver,
+    /// * `src/client.rs` with the client trait and page iterators.
+    pub fn generate_package(&self, endpoints: Option<&Endpoints>, runtime: &Runtime)
+                            -> Result<Vec<(PathBuf, Vec<u8>)>, Error> {
+        let mut files = vec!();
+        let mut out = Vec::new();
+        try!(self.generate_manifest(&mut out, runtime));
+        files.push((PathBuf::from("Cargo.toml"), out));
+
+        let mut out = Vec::new();
+        try!(self.generate_readme(&mut out));
+        files.push((PathBuf::from("README.md"), out));
+
+        let mut out = Vec::new();
+        try!(self.generate_lib(&mut out));
+        files.push((PathBuf::from("src/lib.rs"), out));
+
+        let mut out = Vec::new();
+        try!(writeln!(out, "//! The shapes {} operations take and return.\n", self.metadata.serviceFullName));
+        try!(self.generate_shapes_where(&mut out, |shape| !is_exception(shape)));
+        try!(writeln!(out, ""));
+        try!(self.generate_builders(&mut out));
+        try!(writeln!(out, ""));
+        try!(self.generate_validators(&mut out));
+        try!(writeln!(out, ""));
+        try!(self.generate_example_tests(&mut out));
+        files.push((PathBuf::from("src/model.rs"), out));
+
+        let mut out = Vec::new();
+        try!(writeln!(out, "//! The exceptions {} reports.", self.metadata.serviceFullName));
+        if self.shapes.iter().any(|shape| is_exception(&shape)) {
+            try!(writeln!(out, "\nuse model::*;\n"));
+            try!(self.generate_shapes_where(&mut out, is_exception));
+        }
+        files.push((PathBuf::from("src/error.rs"), out));
+
+        let mut out = Vec::new();
+        try!(self.generate_protocol(&mut out, endpoints));
+        files.push((PathBuf::from("src/protocol.rs"), out));
+
+        let mut out = Vec::new();
+        try!(writeln!(out, "//! The {} client.\n", self.metadata.serviceFullName));
+        try!(writeln!(out, "use model::*;\n"));
+        try!(self.generate_client(&mut out));
+        files.push((PathBuf::from("src/client.rs"), out));
+        Ok(files)
+    }
+
+    /// Writes `Cargo.toml`, depending on the support code through `runtime`.
+    pub fn generate_manifest<W: Write>(&self, out: &mut W, runtime: &Runtime) -> Result<(), Error> {
+        let metadata = &self.metadata;
+        try!(writeln!(out, "[package]"));
+        try!(writeln!(out, "name = {:?}", metadata.package_name()));
+        try!(writeln!(out, "version = {:?}", try!(metadata.package_version())));
+        try!(writeln!(out, "authors = []"));
+        try!(writeln!(out, "description = \"A client for {}, generated from its {} service definition.\"",
+                      metadata.serviceFullName.replace("\"", "\\\""), metadata.apiVersion));
+        try!(writeln!(out, "readme = \"README.md\""));
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "[dependencies]"));
+        match *runtime {
+            Runtime::Version(ref version) => try!(writeln!(out, "aws-service-parser = {:?}", version)),
+            Runtime::Path(ref path) => {
+                try!(writeln!(out, "aws-service-parser = {{ path = {:?} }}", path.display().to_string()))
+            }
+        }
+        try!(writeln!(out, "serde = \"^0.6.1\""));
+        try!(writeln!(out, "serde_macros = \"^0.6.1\""));
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "[dev-dependencies]"));
+        try!(writeln!(out, "serde_json = \"^0.6.0\""));
+        Ok(())
+    }
+
+    /// Writes `README.md`: how to use the package, then the service documentation.
+    pub fn generate_readme<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        let metadata = &self.metadata;
+        let package = metadata.package_name();
+        try!(writeln!(out, "{}", package));
+        try!(writeln!(out, "{}", (0..package.len()).map(|_| "-").collect::<String>()));
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "A client for {}, generated by aws-service-parser from the {} service definition.",
+                      metadata.serviceFullName, metadata.apiVersion));
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "```rust"));
+        try!(writeln!(out, "extern crate {};", package.replace("-", "_")));
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "use {}::{};", package.replace("-", "_"), metadata.client_name()));
+        try!(writeln!(out, "```"));
+        let documentation = Markdown::from_html(&self.documentation).render(WIDTH);
+        if !documentation.is_empty() {
+            try!(writeln!(out, ""));
+            for line in documentation {
+                try!(writeln!(out, "{}", line));
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes `src/lib.rs`. Generated code refers to the support code as `::runtime`,
+    /// which the crate root imports from aws-service-parser.
+    pub fn generate_lib<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        try!(self.generate_module_doc(out));
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "#![feature(custom_derive, plugin)]"));
+        try!(writeln!(out, "#![plugin(serde_macros)]"));
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "extern crate aws_service_parser;"));
+        try!(writeln!(out, "extern crate serde;"));
+        try!(writeln!(out, "#[cfg(test)]"));
+        try!(writeln!(out, "extern crate serde_json;"));
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "use aws_service_parser::runtime;"));
+        try!(writeln!(out, ""));
+        for module in &["model", "error", "protocol", "client"] {
+            try!(writeln!(out, "pub mod {};", module));
+        }
+        try!(writeln!(out, ""));
+        for module in &["model", "error", "protocol", "client"] {
+            try!(writeln!(out, "pub use {}::*;", module));
+        }
+        Ok(())
+    }
+
+    /// Writes the signer, the endpoint resolver when `endpoints` is given, and the
+    /// constants that say how requests are encoded.
+    pub fn generate_protocol<W: Write>(&self, out: &mut W, endpoints: Option<&Endpoints>) -> Result<(), Error> {
+        let metadata = &self.metadata;
+        try!(writeln!(out, "//! How requests to {} are addressed, encoded and signed.\n", metadata.serviceFullName));
+        try!(writeln!(out, "/// The version of the service definition this package was generated from."));
+        try!(writeln!(out, "pub const API_VERSION: &'static str = {:?};", metadata.apiVersion));
+        try!(writeln!(out, "/// How requests are encoded: `rest-json`, `rest-xml`, `json`, `query` or `ec2`."));
+        try!(writeln!(out, "pub const PROTOCOL: &'static str = {:?};", metadata.protocol));
+        try!(writeln!(out, ""));
+        try!(metadata.generate_signer(out, endpoints.is_some()));
+        if let Some(endpoints) = endpoints {
+            try!(writeln!(out, ""));
+            try!(metadata.generate_endpoint(out, endpoints));
+        }
+        Ok(())
+    }
+
+    fn generate_shapes_where<W: Write, F: Fn(&&Shape) -> bool>(&self, out: &mut W, include: F) -> Result<(), Error> {
+        let boxed = self.boxed();
+        for shape in self.shapes.iter().filter(include) {
+            try!(shape.generate_in(out, self, &boxed));
+        }
+        Ok(())
+    }
+}
+
+fn is_exception(shape: &&Shape) -> bool {
+    match shape.shape_type {
+        ShapeType::Exception(_) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use ::testhelpers::{fixture_service, fixture_string};
+
+    #[test]
+    fn names() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(service.metadata.package_name(), "aws-lambda");
+        assert_eq!(service.metadata.package_version().unwrap(), "2015.3.31");
+        service.metadata.endpointPrefix = "cognito-idp".to_string();
+        service.metadata.apiVersion = "latest".to_string();
+        assert_eq!(service.metadata.package_name(), "aws-cognito-idp");
+        assert!(service.metadata.package_version().is_err());
+    }
+
+    #[test]
+    fn lambda() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let runtime = Runtime::Path(PathBuf::from("../aws-service-parser"));
+        let files = service.generate_package(None, &runtime).unwrap();
+        let paths: Vec<String> = files.iter().map(|&(ref path, _)| path.display().to_string()).collect();
+        assert_eq!(paths, vec!("Cargo.toml", "README.md", "src/lib.rs", "src/model.rs", "src/error.rs",
+                               "src/protocol.rs", "src/client.rs"));
+        let file = |name: &str| {
+            let &(_, ref contents) = files.iter().find(|&&(ref path, _)| path == &PathBuf::from(name)).unwrap();
+            String::from_utf8(contents.clone()).unwrap()
+        };
+        assert_eq!(file("Cargo.toml"), fixture_string("generated/package-lambda/Cargo.toml"));
+        assert!(file("README.md").starts_with("aws-lambda\n----------\n\nA client for AWS Lambda, "));
+        // The crate's own tests build the modules below against `runtime`; see lib.rs.
+        for module in &["lib", "model", "error", "protocol", "client"] {
+            assert_eq!(file(&format!("src/{}.rs", module)),
+                       fixture_string(&format!("generated/package-lambda/{}.rs", module)));
+        }
+        assert!(!file("src/model.rs").contains("pub struct ServiceException {"));
+    }
+}
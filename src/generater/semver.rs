@@ -0,0 +1,151 @@
+//! Classifies model changes by what they do to the generated Rust API.
+
+use std::fmt;
+use ::parser::diff::{Change, Diff};
+
+/// A semver bump, smallest first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Bump {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl fmt::Display for Bump {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", match *self {
+            Bump::Patch => "patch",
+            Bump::Minor => "minor",
+            Bump::Major => "major",
+        })
+    }
+}
+
+impl Bump {
+    /// The version after `version` (`major.minor.patch`). Before 1.0.0 a breaking
+    /// change bumps the minor version and anything else the patch version, as cargo
+    /// reads it.
+    pub fn next(self, version: &str) -> Result<String, String> {
+        let parts: Vec<u64> = version.split('.').filter_map(|part| part.parse().ok()).collect();
+        if parts.len() != 3 || version.split('.').count() != 3 {
+            return Err(format!("{} is not a major.minor.patch version", version));
+        }
+        let (major, minor, patch) = (parts[0], parts[1], parts[2]);
+        Ok(match (self, major) {
+            (Bump::Major, 0) => format!("0.{}.0", minor + 1),
+            (_, 0) => format!("0.{}.{}", minor, patch + 1),
+            (Bump::Major, _) => format!("{}.0.0", major + 1),
+            (Bump::Minor, _) => format!("{}.{}.0", major, minor + 1),
+            (Bump::Patch, _) => format!("{}.{}.{}", major, minor, patch + 1),
+        })
+    }
+}
+
+impl Change {
+    /// The bump this change calls for in the generated crate, and why.
+    ///
+    /// Generated enums and structures carry a hidden variant or field, so `match`es
+    /// need a `_` arm, struct literals `..Default::default()` and patterns `..`, and a
+    /// new value or optional member breaks none of them. The client trait's methods
+    /// have no default bodies, so a new one breaks every implementation.
+    pub fn bump(&self) -> (Bump, &'static str) {
+        match *self {
+            Change::OperationAdded(_) => (Bump::Major, "adds a method every client implementation must have"),
+            Change::OperationRemoved(_) => (Bump::Major, "removes a method from the client trait"),
+            Change::InputChanged { .. } | Change::OutputChanged { .. } => (Bump::Major, "changes a method's signature"),
+            Change::HttpChanged { .. } | Change::LocationChanged { .. } => (Bump::Patch, "only changes the requests sent"),
+            Change::ErrorAdded { .. } | Change::ErrorRemoved { .. } => {
+                (Bump::Patch, "errors are not part of the method signatures")
+            }
+            Change::ShapeAdded(_) => (Bump::Minor, "adds a type"),
+            Change::ShapeRemoved(_) => (Bump::Major, "removes a type"),
+            Change::TypeChanged { .. } => (Bump::Major, "changes what a type is"),
+            Change::MemberAdded { required: true, .. } => (Bump::Major, "adds a field that must be set"),
+            Change::MemberAdded { required: false, .. } => (Bump::Minor, "adds an optional field"),
+            Change::MemberRemoved { .. } => (Bump::Major, "removes a field"),
+            Change::MemberShapeChanged { .. } => (Bump::Major, "changes a field's type"),
+            Change::RequiredChanged { .. } => (Bump::Major, "changes a field between T and Option<T>"),
+            Change::EnumValueAdded { .. } => (Bump::Minor, "adds an enum variant"),
+            Change::EnumValueRemoved { .. } => (Bump::Major, "removes an enum variant"),
+            Change::ConstraintChanged { .. } => (Bump::Patch, "only changes what validate() accepts"),
+            Change::StatusCodeChanged { .. } => (Bump::Patch, "only changes the status the error comes with"),
+        }
+    }
+}
+
+impl Diff {
+    /// The largest bump any of the changes calls for, or `None` if nothing changed.
+    pub fn bump(&self) -> Option<Bump> {
+        self.changes.iter().map(|change| change.bump().0).max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::parser::*;
+    use ::parser::diff::Change;
+    use ::testhelpers::fixture_service;
+
+    #[test]
+    fn next_version() {
+        assert_eq!(Bump::Major.next("1.4.2").unwrap(), "2.0.0");
+        assert_eq!(Bump::Minor.next("1.4.2").unwrap(), "1.5.0");
+        assert_eq!(Bump::Patch.next("1.4.2").unwrap(), "1.4.3");
+        assert_eq!(Bump::Major.next("0.3.1").unwrap(), "0.4.0");
+        assert_eq!(Bump::Minor.next("0.3.1").unwrap(), "0.3.2");
+        assert!(Bump::Patch.next("1.4").is_err());
+        assert!(Bump::Patch.next("1.4.2-beta").is_err());
+    }
+
+    #[test]
+    fn classify() {
+        let member = |required: bool| Change::MemberAdded {
+            shape: "FunctionConfiguration".to_string(),
+            member: "Architectures".to_string(),
+            required: required,
+        };
+        assert_eq!(member(false).bump().0, Bump::Minor);
+        assert_eq!(member(true).bump().0, Bump::Major);
+        assert_eq!(Change::EnumValueAdded {
+            shape: "Runtime".to_string(),
+            value: "java8".to_string(),
+        }.bump().0, Bump::Minor);
+        assert_eq!(Change::OperationAdded("ListLayers".to_string()).bump().0, Bump::Major);
+        assert_eq!(Change::TypeChanged {
+            shape: "Size".to_string(),
+            old: "integer".to_string(),
+            new: "long".to_string(),
+        }.bump().0, Bump::Major);
+        assert_eq!(Change::ConstraintChanged {
+            shape: "MemorySize".to_string(),
+            constraint: "max",
+            old: Some("1536".to_string()),
+            new: Some("3008".to_string()),
+        }.bump().0, Bump::Patch);
+        assert_eq!(Change::StatusCodeChanged {
+            shape: "TooManyRequestsException".to_string(),
+            old: 429,
+            new: 503,
+        }.bump().0, Bump::Patch);
+    }
+
+    #[test]
+    fn lambda_updates() {
+        let old = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(old.diff(&old).bump(), None);
+
+        let mut new = fixture_service("services/lambda-2015-03-31");
+        new.shapes.push(Shape {
+            name: "Architecture".to_string(),
+            shape_type: ShapeType::StringEnum(StringEnum(vec!("x86_64".to_string(), "arm64".to_string()))),
+            deprecated: None,
+        });
+        assert_eq!(old.diff(&new).bump(), Some(Bump::Minor));
+
+        if let ShapeType::StringEnum(StringEnum(ref mut values)) = new.shapes.iter_mut().find(|s| s.name == "Runtime").unwrap().shape_type {
+            values.retain(|value| value != "nodejs");
+        }
+        assert_eq!(old.diff(&new).bump(), Some(Bump::Major));
+    }
+}
@@ -1,39 +1,122 @@
 use std::io::Error;
 use std::io::prelude::*;
 use ::parser::*;
+use ::parser::cycles::Boxed;
+use super::deprecation::{write_allow_deprecated, write_deprecated};
+use super::doc::write_doc;
+use super::naming::{check_unique, field_name, rename, type_name, variant_name};
+
+impl ServiceDefinition {
+    /// Writes every shape, boxing the members that close a cycle.
+    pub fn generate_shapes<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        let boxed = self.boxed();
+        for shape in &self.shapes {
+            try!(shape.generate_in(out, self, &boxed));
+        }
+        Ok(())
+    }
+
+    /// Whether `member` is generated as an `Option`: when it is not required, and when
+    /// it is an enum, which has no value a derived `Default` could start it at. The
+    /// builders and `validate()` still insist on a required enum being set.
+    pub fn is_option(&self, member: &Member) -> bool {
+        let is_enum = self.shape(&member.shape).map_or(false, |shape| match shape.shape_type {
+            ShapeType::StringEnum(_) => true,
+            _ => false,
+        });
+        !member.required || is_enum
+    }
+}
 
 impl Shape {
+    /// Like `generate`, using the rest of `service` to find the structure members
+    /// that must be streamed, the derives an overlay adds and whether the code refers
+    /// to deprecated shapes. `boxed` is `service.boxed()`.
+    pub fn generate_in<W: Write>(&self, out: &mut W, service: &ServiceDefinition, boxed: &Boxed) -> Result<(), Error> {
+        let derives = service.derives.get(&self.name).map_or(&[][..], |derives| &derives[..]);
+        try!(self.write_deprecated(out));
+        match self.shape_type {
+            ShapeType::Structure(ref structure) => structure.generate_in(out, &self.name, service, boxed),
+            ShapeType::StringEnum(ref string_enum) => {
+                string_enum.write(out, &type_name(&self.name), derives)
+            }
+            ShapeType::Exception(ref exception) => {
+                exception.write(out, &type_name(&self.name), derives, service.touches_deprecated(&self.name),
+                                &|member| service.is_option(member))
+            }
+            _ => {
+                try!(write_allow_deprecated(out, "", service.refers_to_deprecated(&self.name)));
+                self.generate_type(out)
+            }
+        }
+    }
+
     pub fn generate<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        try!(self.write_deprecated(out));
+        self.generate_type(out)
+    }
+
+    fn write_deprecated<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        match self.deprecated {
+            Some(ref deprecation) => write_deprecated(out, "", deprecation),
+            None => Ok(()),
+        }
+    }
+
+    fn generate_type<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        let deprecated = self.deprecated.is_some();
         let shape_type = &self.shape_type;
         let rust_type = match shape_type {
-            &ShapeType::Blob(_) => "Vec<u8>".to_string(), // TODO -- use streaming bool...
+            &ShapeType::Blob(Blob(true)) => "::runtime::stream::ByteStream".to_string(),
+            &ShapeType::Blob(Blob(false)) => "Vec<u8>".to_string(),
             &ShapeType::Boolean => "bool".to_string(),
             &ShapeType::Double => "f64".to_string(),
             &ShapeType::Float => "f32".to_string(),
             &ShapeType::Integer(_) => "i32".to_string(), // TODO -- use min/max info...
-            &ShapeType::List(List(ref list_type)) => format!("Vec<{}>", &list_type.to_string()),
+            &ShapeType::List(List { member: ref list_type, .. }) => format!("Vec<{}>", type_name(list_type)),
             &ShapeType::Long => "i64".to_string(),
-            &ShapeType::StringEnum(ref string_enum) => return string_enum.generate(out, &self.name),
+            &ShapeType::StringEnum(ref string_enum) => return string_enum.write(out, &type_name(&self.name), &[]),
             &ShapeType::Timestamp |
             &ShapeType::StringPattern(_) => "String".to_string(),
-            &ShapeType::Structure(ref structure) => return structure.generate(out, &self.name),
-            &ShapeType::Exception(ref exception) => return exception.generate(out, &self.name),
+            &ShapeType::Structure(ref structure) => return structure.generate(out, &type_name(&self.name)),
+            &ShapeType::Exception(ref exception) => {
+                return exception.write(out, &type_name(&self.name), &[], deprecated, &|member| !member.required)
+            }
         };
-        try!(writeln!(out, "pub type {} = {};", &self.name, rust_type));
+        try!(writeln!(out, "pub type {} = {};", type_name(&self.name), rust_type));
         Ok(())
     }
 }
 
 impl Member {
     pub fn generate<W:Write>(&self, out: &mut W) -> Result<(), Error> {
+        self.generate_field(out, false, true, !self.required)
+    }
+
+    /// Writes the field, as `Box<T>` when the member closes a cycle of structures.
+    /// `serde` is whether the containing struct derives the serde traits, and `option`
+    /// whether the field is an `Option`.
+    pub fn generate_field<W:Write>(&self, out: &mut W, boxed: bool, serde: bool, option: bool) -> Result<(), Error> {
         if let Some(ref documentation) = self.documentation {
-            try!(writeln!(out, "    /// {}", documentation));
+            try!(write_doc(out, "    ", "///", documentation));
         }
-        let name = &self.name;
-        let shape = &self.shape;
-        match self.required {
-            true => try!(writeln!(out, "    pub {name}: {shape},", name=name, shape=shape)),
-            false => try!(writeln!(out, "    pub {name}: Option<{shape}>,", name=name, shape=shape)),
+        if let Some(ref deprecation) = self.deprecated {
+            try!(write_deprecated(out, "    ", deprecation));
+        }
+        let name = field_name(&self.name);
+        let shape = if boxed {
+            format!("Box<{}>", type_name(&self.shape))
+        } else {
+            type_name(&self.shape)
+        };
+        if let Some(attribute) = rename(&self.name, &name) {
+            if serde {
+                try!(writeln!(out, "    {}", attribute));
+            }
+        }
+        match option {
+            false => try!(writeln!(out, "    pub {name}: {shape},", name=name, shape=shape)),
+            true => try!(writeln!(out, "    pub {name}: Option<{shape}>,", name=name, shape=shape)),
         };
         Ok(())
     }
@@ -47,21 +130,68 @@ impl StringEnum {
     /// `pub enum MyEnum { ... }`. This keeps the implementation clearer for all
     /// the normal cases in generate.
     pub fn generate<W: Write>(&self, out: &mut W, name: &str) -> Result<(), Error> {
+        self.write(out, name, &[])
+    }
+
+    /// A hidden variant keeps `match`es on the enum from being exhaustive, so the
+    /// service adding a value is not a breaking change. There is no `Default`, as no
+    /// value is a safe one to assume; members of enum shapes are `Option`s instead.
+    fn write<W: Write>(&self, out: &mut W, name: &str, derives: &[String]) -> Result<(), Error> {
+        try!(write_derive(out, &["Debug", "Clone", "PartialEq", "Serialize", "Deserialize"], derives));
+        try!(check_unique(name, self.0.iter().map(|v| &v[..]), variant_name));
         try!(writeln!(out, "pub enum {} {{", name));
-        for variant in &self.0 {
+        for value in &self.0 {
+            let variant = variant_name(value);
+            if let Some(attribute) = rename(value, &variant) {
+                try!(writeln!(out, "    {}", attribute));
+            }
             try!(writeln!(out, "    {},", variant));
         }
-        try!(writeln!(out, "}};"));
+        try!(writeln!(out, "    #[doc(hidden)]"));
+        try!(writeln!(out, "    __NonExhaustive,"));
+        try!(writeln!(out, "}}"));
         Ok(())
     }
 }
 
 impl Structure {
     pub fn generate<W: Write>(&self, out: &mut W, name: &str) -> Result<(), Error> {
-        try!(writeln!(out, "#[derive(Debug, Default)]"));
+        self.write(out, name, &[], true, &[], false, &|member| !member.required)
+    }
+
+    /// Writes the structure named `shape` in `service`. Members closing a cycle are
+    /// boxed, and a structure with a streaming payload is not (de)serialized as a
+    /// whole, since its body is the stream itself.
+    pub fn generate_in<W: Write>(&self, out: &mut W, shape: &str, service: &ServiceDefinition, boxed: &Boxed)
+                                 -> Result<(), Error> {
+        let boxed = boxed.members(shape);
+        let streaming = self.0.iter().any(|member| {
+            member.location == Location::Payload &&
+            service.shape(&member.shape).map_or(false, |s| s.shape_type == ShapeType::Blob(Blob(true)))
+        });
+        let derives = service.derives.get(shape).map_or(&[][..], |derives| &derives[..]);
+        self.write(out, &type_name(shape), boxed, !streaming, derives, service.refers_to_deprecated(shape),
+                   &|member| service.is_option(member))
+    }
+
+    /// `option` is whether a member's field is an `Option`.
+    fn write<W: Write>(&self, out: &mut W, name: &str, boxed: &[String], serde: bool, derives: &[String],
+                       allow_deprecated: bool, option: &Fn(&Member) -> bool) -> Result<(), Error> {
+        try!(check_unique(name, self.0.iter().map(|m| &m.name[..]), field_name));
+        try!(write_allow_deprecated(out, "", allow_deprecated));
+        if serde {
+            try!(write_derive(out, &["Debug", "Default", "Serialize", "Deserialize"], derives));
+        } else {
+            try!(write_derive(out, &["Debug", "Default"], derives));
+        }
         try!(writeln!(out, "pub struct {} {{", name));
         for member in &self.0 {
-            member.generate(out);
+            try!(member.generate_field(out, boxed.contains(&member.name), serde, option(member)));
+        }
+        if serde {
+            try!(write_non_exhaustive(out, "#[serde(skip_serializing, default)]"));
+        } else {
+            try!(write_non_exhaustive(out, ""));
         }
         try!(writeln!(out, "}}"));
         Ok(())
@@ -70,30 +200,48 @@ impl Structure {
 
 impl Exception {
     pub fn generate<W: Write>(&self, out: &mut W, name: &str) -> Result<(), Error> {
-        try!(writeln!(out, "#[derive(Debug)]"));
+        self.write(out, name, &[], false, &|member| !member.required)
+    }
+
+    /// `allow_deprecated` is whether the exception, or its members, are deprecated or
+    /// of deprecated shapes, and `option` whether a member's field is an `Option`.
+    fn write<W: Write>(&self, out: &mut W, name: &str, derives: &[String], allow_deprecated: bool,
+                       option: &Fn(&Member) -> bool) -> Result<(), Error> {
+        try!(check_unique(name, self.members.iter().map(|m| &m.name[..]), field_name));
+        try!(write_allow_deprecated(out, "", allow_deprecated));
+        try!(write_derive(out, &["Debug", "Default", "Deserialize"], derives));
         if let Some(ref docs) = self.documentation {
-          try!(writeln!(out, "/// {}", docs));
+          try!(write_doc(out, "", "///", docs));
         }
         try!(writeln!(out, "pub struct {} {{", name));
         for member in &self.members {
-            member.generate(out);
+            try!(member.generate_field(out, false, true, option(member)));
         }
+        try!(write_non_exhaustive(out, "#[serde(default)]"));
         try!(writeln!(out, "}}\n"));
 
-        try!(writeln!(out, "impl ::std::error::Error for {} {{", name));
-        try!(writeln!(out, "    pub fn description(&self) -> &str {{"));
-        try!(write!(out, "        &format!(\"{}:", name));
+        try!(write_allow_deprecated(out, "", allow_deprecated));
+        try!(writeln!(out, "impl ::std::fmt::Display for {} {{", name));
+        try!(writeln!(out, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{"));
+        try!(write!(out, "        write!(f, \"{}", name));
+        if !self.members.is_empty() {
+            try!(write!(out, ":"));
+        }
         for _ in &self.members {
-            try!(write!(out, " {{}}"));
+            try!(write!(out, " {{:?}}"));
         }
         try!(write!(out, "\""));
         for member in &self.members {
-            try!(write!(out, ", self.{}", member.name));
+            try!(write!(out, ", self.{}", field_name(&member.name)));
         }
-        try!(writeln!(out, ");"));
-        try!(writeln!(out, "    }}\n"));
-        try!(writeln!(out, "    pub fn cause(&self) -> Option<&Error> {{"));
-        try!(writeln!(out, "        None"));
+        try!(writeln!(out, ")"));
+        try!(writeln!(out, "    }}"));
+        try!(writeln!(out, "}}\n"));
+
+        try!(write_allow_deprecated(out, "", allow_deprecated));
+        try!(writeln!(out, "impl ::std::error::Error for {} {{", name));
+        try!(writeln!(out, "    fn description(&self) -> &str {{"));
+        try!(writeln!(out, "        {:?}", name));
         try!(writeln!(out, "    }}"));
         try!(writeln!(out, "}}"));
 
@@ -101,11 +249,30 @@ impl Exception {
     }
 }
 
+/// Writes the hidden field that keeps struct literals and patterns from naming every
+/// member, so that they need `..Default::default()` and `..` and a new member is not a
+/// breaking change. `serde` is the attribute, if any, keeping it out of the JSON.
+fn write_non_exhaustive<W: Write>(out: &mut W, serde: &str) -> Result<(), Error> {
+    try!(writeln!(out, "    #[doc(hidden)]"));
+    if !serde.is_empty() {
+        try!(writeln!(out, "    {}", serde));
+    }
+    writeln!(out, "    pub __non_exhaustive: (),")
+}
+
+/// Writes the `#[derive]` line for the traits every such item derives, followed by
+/// the ones an overlay adds.
+fn write_derive<W: Write>(out: &mut W, standard: &[&str], derives: &[String]) -> Result<(), Error> {
+    let mut traits = standard.to_vec();
+    traits.extend(derives.iter().map(|derive| &derive[..]).filter(|derive| !standard.contains(derive)));
+    writeln!(out, "#[derive({})]", traits.join(", "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ::parser::*;
-    use ::testhelpers::fixture_string;
+    use ::testhelpers::{fixture_service, fixture_string};
     use std::io::Write;
 
     macro_rules! generates {
@@ -125,16 +292,23 @@ mod tests {
     generates!(boolean, "boolean", Shape {
         name: "Enabled".to_string(),
         shape_type: ShapeType::Boolean,
+        deprecated: None,
     });
 
     generates!(double, "double-trouble", Shape {
         name: "Trouble".to_string(),
         shape_type: ShapeType::Double,
+        deprecated: None,
     });
 
     generates!(list, "list", Shape {
         name: "AllTheThings".to_string(),
-        shape_type: ShapeType::List(List("Thing".to_string())),
+        shape_type: ShapeType::List(List {
+            member: "Thing".to_string(),
+            min: None,
+            max: None,
+        }),
+        deprecated: None,
     });
 
     generates!(string_enum, "string_enum", Shape {
@@ -145,6 +319,17 @@ mod tests {
             "Toronto".to_string(),
             "Beijing".to_string(),
         ])),
+        deprecated: None,
+    });
+
+    generates!(string_enum_renamed, "string_enum_renamed", Shape {
+        name: "Runtime".to_string(),
+        shape_type: ShapeType::StringEnum(StringEnum(vec![
+            "nodejs".to_string(),
+            "python2.7".to_string(),
+            "nodejs4.3-edge".to_string(),
+        ])),
+        deprecated: None,
     });
 
     generates!(structure, "structure-genie-in-a-bottle", Shape {
@@ -155,16 +340,19 @@ mod tests {
                 shape: "Person".to_string(),
                 documentation: None,
                 required: false,
-                location: Location::Body
+                location: Location::Body,
+                deprecated: None,
             },
             Member {
                 name: "wishes".to_string(),
                 shape: "integer".to_string(),
                 documentation: None,
                 required: true,
-                location: Location::Body
+                location: Location::Body,
+                deprecated: None,
             },
-        ]))
+        ])),
+        deprecated: None,
     });
 
     generates!(string_pattern, "string_pattern", Shape {
@@ -174,6 +362,7 @@ mod tests {
             min: None,
             max: None,
         }),
+        deprecated: None,
     });
 
     generates!(exception, "exception", Shape {
@@ -188,6 +377,7 @@ mod tests {
                     documentation: None,
                     shape: "String".to_string(),
                     location: Location::Body,
+                    deprecated: None,
                 },
                 Member {
                     name: "Message".to_string(),
@@ -195,8 +385,71 @@ mod tests {
                     documentation: None,
                     shape: "String".to_string(),
                     location: Location::Body,
+                    deprecated: None,
                 },
             ],
         }),
+        deprecated: None,
     });
+
+    generates!(member_documentation, "member-documentation", Member {
+        name: "Qualifier".to_string(),
+        shape: "Qualifier".to_string(),
+        documentation: Some("<p>You can specify this optional query parameter to specify function version or alias name. For example, if you specify function version 2 as the qualifier:</p> <p><code>arn:aws:lambda:aws-region:acct-id:function:function-name:2</code></p>".to_string()),
+        required: false,
+        location: Location::QueryString("Qualifier".to_string()),
+        deprecated: None,
+    });
+
+    #[test]
+    fn member_collision() {
+        let member = |name: &str| Member {
+            name: name.to_string(),
+            shape: "String".to_string(),
+            documentation: None,
+            required: false,
+            location: Location::Body,
+            deprecated: None,
+        };
+        let structure = Structure(vec![member("FunctionArn"), member("FunctionARN")]);
+        let err = structure.generate(&mut Vec::new(), "FunctionConfiguration").unwrap_err();
+        assert_eq!(format!("{}", err), "FunctionConfiguration: FunctionArn and FunctionARN both become function_arn");
+    }
+
+    #[test]
+    fn recursive_shapes() {
+        let service = fixture_service("services/recursive");
+        let mut buffer = Vec::new();
+        assert!(service.generate_shapes(&mut buffer).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert_eq!(fixture_string("generated/recursive-shapes.rs"), actual);
+    }
+
+    #[test]
+    fn streaming_payload() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let mut buffer = Vec::new();
+        for name in &["BlobStream", "InvokeAsyncRequest"] {
+            assert!(service.shape(name).unwrap().generate_in(&mut buffer, &service, &service.boxed()).is_ok());
+        }
+        let actual = String::from_utf8(buffer).unwrap();
+        assert_eq!(fixture_string("generated/streaming-payload.rs"), actual);
+    }
+
+    #[test]
+    fn overlay_derives() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        service.derives.insert("Runtime".to_string(), vec!("Hash".to_string(), "Clone".to_string()));
+        service.derives.insert("GetPolicyRequest".to_string(), vec!("Clone".to_string()));
+        service.derives.insert("ServiceException".to_string(), vec!("Clone".to_string()));
+        let derive_line = |name: &str| {
+            let mut buffer = Vec::new();
+            service.shape(name).unwrap().generate_in(&mut buffer, &service, &service.boxed()).unwrap();
+            String::from_utf8(buffer).unwrap().lines().next().unwrap().to_string()
+        };
+        assert_eq!(derive_line("Runtime"), "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Hash)]");
+        assert_eq!(derive_line("GetPolicyRequest"), "#[derive(Debug, Default, Serialize, Deserialize, Clone)]");
+        assert_eq!(derive_line("ServiceException"), "#[derive(Debug, Default, Deserialize, Clone)]");
+        assert_eq!(derive_line("FunctionConfiguration"), "#[derive(Debug, Default, Serialize, Deserialize)]");
+    }
 }
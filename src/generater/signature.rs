@@ -0,0 +1,80 @@
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use ::parser::*;
+
+impl Metadata {
+    /// Writes a `sign()` function for this service that delegates to the runtime
+    /// signer, chosen by `signatureVersion`. Only SigV4 is supported so far.
+    ///
+    /// With `signing_region`, the generated `signing_region()` and `signing_name()` from
+    /// `generate_endpoint` pick the region to sign for and the service name to sign
+    /// with, so that endpoints with a credential scope are signed for what it names.
+    pub fn generate_signer<W: Write>(&self, out: &mut W, signing_region: bool) -> Result<(), Error> {
+        match &self.signatureVersion[..] {
+            "v4" => {},
+            other => return Err(Error::new(ErrorKind::InvalidInput,
+                                           format!("unsupported signatureVersion: {}", other))),
+        }
+        try!(writeln!(out, "/// Signs `request` for {} with AWS Signature Version 4.", self.serviceFullName));
+        try!(writeln!(out, "/// `date` is the request time formatted as `20150830T123600Z`."));
+        try!(writeln!(out, "pub fn sign(request: &mut ::runtime::signature::SignedRequest,"));
+        try!(writeln!(out, "            credentials: &::runtime::signature::Credentials,"));
+        try!(writeln!(out, "            region: &str,"));
+        try!(writeln!(out, "            date: &str) {{"));
+        if signing_region {
+            try!(writeln!(out, "    request.sign(credentials, signing_region(region), signing_name(region), date);"));
+        } else {
+            try!(writeln!(out, "    request.sign(credentials, region, \"{}\", date);", self.signing_name()));
+        }
+        try!(writeln!(out, "}}"));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::parser::*;
+    use ::testhelpers::fixture_string;
+
+    fn metadata(signature_version: &str, signing_name: Option<&str>) -> Metadata {
+        Metadata {
+            apiVersion: "2015-03-31".to_string(),
+            endpointPrefix: "lambda".to_string(),
+            serviceFullName: "AWS Lambda".to_string(),
+            globalEndpoint: None,
+            signatureVersion: signature_version.to_string(),
+            signingName: signing_name.map(|s| s.to_string()),
+            protocol: "rest-json".to_string(),
+        }
+    }
+
+    #[test]
+    fn v4_signer() {
+        let mut buffer = Vec::new();
+        assert!(metadata("v4", None).generate_signer(&mut buffer, false).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert_eq!(fixture_string("generated/signer-v4.rs"), actual);
+    }
+
+    #[test]
+    fn signing_region() {
+        let mut buffer = Vec::new();
+        assert!(metadata("v4", None).generate_signer(&mut buffer, true).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert!(actual.contains("request.sign(credentials, signing_region(region), signing_name(region), date);"));
+    }
+
+    #[test]
+    fn signing_name_overrides_endpoint_prefix() {
+        let mut buffer = Vec::new();
+        assert!(metadata("v4", Some("execute-api")).generate_signer(&mut buffer, false).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert!(actual.contains("request.sign(credentials, region, \"execute-api\", date);"));
+    }
+
+    #[test]
+    fn unsupported_signature_version() {
+        let mut buffer = Vec::new();
+        assert!(metadata("v2", None).generate_signer(&mut buffer, false).is_err());
+    }
+}
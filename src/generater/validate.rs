@@ -0,0 +1,207 @@
+use std::collections::BTreeSet;
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use ::parser::*;
+use ::runtime::pattern::Pattern;
+use super::deprecation::write_allow_deprecated;
+use super::naming::{field_name, type_name};
+
+impl ServiceDefinition {
+    /// Writes `validate()` for every structure used as an operation input, and the
+    /// `Validate` impls it relies on for the constrained structures inside them.
+    ///
+    /// Required members and enum values are already enforced by the generated types,
+    /// and patterns the runtime `Pattern` cannot compile are left unchecked.
+    pub fn generate_validators<W: Write>(&self, out: &mut W) -> Result<(), Error> {
+        let mut inputs = BTreeSet::new();
+        for operation in &self.operations {
+            if let Some(&ShapeType::Structure(_)) = self.shape(&operation.input.shape).map(|s| &s.shape_type) {
+                inputs.insert(&operation.input.shape[..]);
+            }
+        }
+        let mut structures = BTreeSet::new();
+        for input in &inputs {
+            self.collect_structures(input, &mut structures);
+        }
+
+        let mut first = true;
+        for name in structures {
+            let is_input = inputs.contains(name);
+            if !is_input && !self.has_constraints(name, &mut vec!()) {
+                continue;
+            }
+            if !first {
+                try!(writeln!(out, ""));
+            }
+            first = false;
+            let members = match self.shape(name).map(|s| &s.shape_type) {
+                Some(&ShapeType::Structure(Structure(ref members))) => members,
+                _ => return Err(Error::new(ErrorKind::InvalidInput, format!("{} is not a structure", name))),
+            };
+            if is_input {
+                try!(write_allow_deprecated(out, "", self.touches_deprecated(name)));
+                try!(writeln!(out, "impl {} {{", type_name(name)));
+                try!(writeln!(out, "    /// Checks the request against the constraints of its shapes, without sending it."));
+                try!(writeln!(out, "    pub fn validate(&self) -> Result<(), ::runtime::validate::Violations> {{"));
+                try!(writeln!(out, "        ::runtime::validate::validate(self)"));
+                try!(writeln!(out, "    }}"));
+                try!(writeln!(out, "}}\n"));
+            }
+            try!(self.generate_check(out, name, members));
+        }
+        Ok(())
+    }
+
+    /// Adds `shape` and every structure reachable from it to `structures`.
+    fn collect_structures<'a>(&'a self, shape: &'a str, structures: &mut BTreeSet<&'a str>) {
+        match self.shape(shape).map(|s| &s.shape_type) {
+            Some(&ShapeType::Structure(Structure(ref members))) => {
+                if structures.insert(shape) {
+                    for member in members {
+                        self.collect_structures(&member.shape, structures);
+                    }
+                }
+            }
+            Some(&ShapeType::List(List { ref member, .. })) => self.collect_structures(member, structures),
+            _ => {}
+        }
+    }
+
+    /// Whether a value of `shape` has anything to check. `seen` holds the structures
+    /// being looked at already, so recursive shapes come to an end.
+    fn has_constraints(&self, shape: &str, seen: &mut Vec<String>) -> bool {
+        match self.shape(shape).map(|s| &s.shape_type) {
+            Some(&ShapeType::Integer(Integer { min, max })) => min.is_some() || max.is_some(),
+            Some(&ShapeType::StringPattern(ref string)) => {
+                string.min.is_some() || string.max.is_some() || checked_pattern(string).is_some()
+            }
+            Some(&ShapeType::List(List { ref member, min, max })) => {
+                min.is_some() || max.is_some() || self.has_constraints(member, seen)
+            }
+            Some(&ShapeType::Structure(Structure(ref members))) => {
+                if seen.iter().any(|s| s == shape) {
+                    return false;
+                }
+                seen.push(shape.to_string());
+                members.iter().any(|member| self.has_constraints(&member.shape, seen))
+            }
+            _ => false,
+        }
+    }
+
+    fn generate_check<W: Write>(&self, out: &mut W, name: &str, members: &[Member]) -> Result<(), Error> {
+        // Required members that are still `Option`s, because they are enums.
+        let unset: Vec<&Member> = members.iter().filter(|m| m.required && self.is_option(m)).collect();
+        let members: Vec<&Member> = members.iter().filter(|m| self.has_constraints(&m.shape, &mut vec!())).collect();
+        try!(write_allow_deprecated(out, "", self.touches_deprecated(name)));
+        try!(writeln!(out, "impl ::runtime::validate::Validate for {} {{", type_name(name)));
+        if members.is_empty() && unset.is_empty() {
+            try!(writeln!(out, "    fn check(&self, _path: &str, _violations: &mut Vec<::runtime::validate::Violation>) {{"));
+        } else {
+            try!(writeln!(out, "    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {{"));
+        }
+        for member in unset {
+            try!(writeln!(out, "        if self.{}.is_none() {{", field_name(&member.name)));
+            try!(writeln!(out, "            ::runtime::validate::missing(path, {:?}, violations);", member.name));
+            try!(writeln!(out, "        }}"));
+        }
+        for member in members {
+            if !self.is_option(member) {
+                try!(writeln!(out, "        {{"));
+                try!(writeln!(out, "            let value = &self.{};", field_name(&member.name)));
+            } else {
+                try!(writeln!(out, "        if let Some(ref value) = self.{} {{", field_name(&member.name)));
+            }
+            try!(writeln!(out, "            let path = ::runtime::validate::member(path, {:?});", member.name));
+            try!(self.generate_value_check(out, 3, &member.shape));
+            try!(writeln!(out, "        }}"));
+        }
+        try!(writeln!(out, "    }}"));
+        try!(writeln!(out, "}}"));
+        Ok(())
+    }
+
+    /// Writes the checks on `value`, a reference to a value of `shape` found at `path`.
+    fn generate_value_check<W: Write>(&self, out: &mut W, depth: usize, shape: &str) -> Result<(), Error> {
+        let indent: String = (0..depth).map(|_| "    ").collect();
+        match self.shape(shape).map(|s| &s.shape_type) {
+            Some(&ShapeType::Integer(Integer { min, max })) => {
+                try!(writeln!(out, "{}::runtime::validate::range(&path, *value as i64, {:?}, {:?}, violations);",
+                              indent, min, max));
+            }
+            Some(&ShapeType::StringPattern(ref string)) => {
+                if string.min.is_some() || string.max.is_some() {
+                    try!(writeln!(out, "{}::runtime::validate::length(&path, value.chars().count(), {:?}, {:?}, violations);",
+                                  indent, string.min, string.max));
+                }
+                if let Some(pattern) = checked_pattern(string) {
+                    try!(writeln!(out, "{}::runtime::validate::pattern(&path, value, {:?}, violations);", indent, pattern));
+                }
+            }
+            Some(&ShapeType::List(List { ref member, min, max })) => {
+                if min.is_some() || max.is_some() {
+                    try!(writeln!(out, "{}::runtime::validate::length(&path, value.len(), {:?}, {:?}, violations);",
+                                  indent, min, max));
+                }
+                if self.has_constraints(member, &mut vec!()) {
+                    try!(writeln!(out, "{}for (i, value) in value.iter().enumerate() {{", indent));
+                    try!(writeln!(out, "{}    let path = ::runtime::validate::index(&path, i);", indent));
+                    try!(self.generate_value_check(out, depth + 1, member));
+                    try!(writeln!(out, "{}}}", indent));
+                }
+            }
+            Some(&ShapeType::Structure(_)) => {
+                try!(writeln!(out, "{}::runtime::validate::Validate::check(value, &path, violations);", indent));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// The pattern to check, unless it allows anything (which is also what the parser
+/// assumes when there is none) or cannot be compiled.
+fn checked_pattern(string: &StringPattern) -> Option<&str> {
+    if string.pattern == ".*" || Pattern::new(&string.pattern).is_err() {
+        None
+    } else {
+        Some(&string.pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::testhelpers::{fixture_service, fixture_string};
+
+    #[test]
+    fn lambda() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let mut buffer = Vec::new();
+        assert!(service.generate_validators(&mut buffer).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert_eq!(fixture_string("generated/validate-lambda.rs"), actual);
+    }
+
+    #[test]
+    fn recursive() {
+        let service = fixture_service("services/recursive");
+        let mut buffer = Vec::new();
+        assert!(service.generate_validators(&mut buffer).is_ok());
+        let actual = String::from_utf8(buffer).unwrap();
+        assert_eq!(actual.matches("impl ::runtime::validate::Validate for ").count(), 4);
+        assert!(actual.contains("\
+impl ::runtime::validate::Validate for Node {
+    fn check(&self, path: &str, violations: &mut Vec<::runtime::validate::Violation>) {
+        if let Some(ref value) = self.next {
+            let path = ::runtime::validate::member(path, \"Next\");
+            ::runtime::validate::Validate::check(value, &path, violations);
+        }
+"));
+        assert!(actual.contains("
+            for (i, value) in value.iter().enumerate() {
+                let path = ::runtime::validate::index(&path, i);
+                ::runtime::validate::length(&path, value.chars().count(), Some(1), None, violations);
+            }
+"));
+    }
+}
@@ -0,0 +1,219 @@
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use serde_json::Value;
+use ::parser::*;
+use ::parser::path::{self, Path, Segment};
+use ::parser::waiters::{Waiter, Acceptor, AcceptorState, Matcher};
+use super::deprecation::{write_allow_deprecated, write_deprecated};
+use super::naming::{field_name, snake_case, type_name, variant_name};
+use super::doc::write_doc;
+
+impl Waiter {
+    /// Writes the provided `wait_until_*` method for the client trait. The acceptors
+    /// are checked in order against each attempt by `::runtime::waiter::wait`.
+    pub fn generate<W: Write>(&self, out: &mut W, service: &ServiceDefinition) -> Result<(), Error> {
+        let operation = try!(service.operation(&self.operation).ok_or(self.invalid("unknown operation")));
+        let (ok_acceptors, err_acceptors): (Vec<&Acceptor>, Vec<&Acceptor>) = self.acceptors.iter()
+            .partition(|acceptor| match acceptor.matcher {
+                Matcher::Error(_) => false,
+                Matcher::Status(status) => status >= 200 && status < 300,
+                _ => true,
+            });
+
+        if let Some(ref description) = self.description {
+            try!(write_doc(out, "    ", "///", description));
+        }
+        let every = if self.delay == 1 { "second".to_string() } else { format!("{} seconds", self.delay) };
+        try!(writeln!(out, "    /// Polls `{}` every {}, at most {} times.", self.operation, every, self.max_attempts));
+        if let Some(ref deprecation) = operation.deprecation() {
+            try!(write_deprecated(out, "    ", deprecation));
+        }
+        try!(write_allow_deprecated(out, "    ", service.operation_touches_deprecated(operation)));
+        try!(writeln!(out, "    fn wait_until_{}(&self, input: &{}) -> Result<(), ::runtime::waiter::WaiterError<Self::Error>>",
+                      snake_case(&self.name), type_name(&operation.input.shape)));
+        try!(writeln!(out, "        where Self: Sized, Self::Error: ::runtime::waiter::ServiceError"));
+        try!(writeln!(out, "    {{"));
+        try!(writeln!(out, "        ::runtime::waiter::wait({}, {}, || self.{}(input), |result| {{",
+                      self.delay, self.max_attempts, snake_case(&self.operation)));
+        try!(writeln!(out, "            match *result {{"));
+
+        // Conditions for successful responses; `None` means any successful response matches.
+        // Only path matchers look at the output, so status-only waiters can poll
+        // operations that have none.
+        let output = || operation.output.as_ref().map(|o| &o.shape[..]).ok_or(self.invalid("operation has no output"));
+        let mut conditions = vec!();
+        for acceptor in ok_acceptors {
+            let condition = match acceptor.matcher {
+                Matcher::Status(_) => None,
+                Matcher::Path { ref argument, ref expected } => {
+                    // A projection selects a list, which no single expected value equals.
+                    if argument.alternatives.iter().any(|segments| segments.contains(&Segment::Flatten)) {
+                        return Err(self.invalid(&format!("a path matcher cannot compare the list {} selects; \
+                                                          use pathAll or pathAny", argument)));
+                    }
+                    let (values, compare) = try!(self.values(service, try!(output()), argument, expected));
+                    Some(format!("{}.first().map_or(false, |v| {})", values, compare))
+                }
+                Matcher::PathAll { ref argument, ref expected } => {
+                    let (values, compare) = try!(self.values(service, try!(output()), argument, expected));
+                    Some(format!("{{ let values = {}; !values.is_empty() && values.iter().all(|v| {}) }}", values, compare))
+                }
+                Matcher::PathAny { ref argument, ref expected } => {
+                    let (values, compare) = try!(self.values(service, try!(output()), argument, expected));
+                    Some(format!("{}.iter().any(|v| {})", values, compare))
+                }
+                Matcher::Error(_) => unreachable!(),
+            };
+            let unconditional = condition.is_none();
+            conditions.push((condition, acceptor.state));
+            // Later acceptors can never be reached.
+            if unconditional {
+                break;
+            }
+        }
+
+        if conditions.is_empty() {
+            try!(writeln!(out, "                Ok(_) => {{}},"));
+        } else if conditions[0].0.is_none() {
+            try!(writeln!(out, "                Ok(_) => return Some({}),", state(conditions[0].1)));
+        } else {
+            try!(writeln!(out, "                Ok(ref output) => {{"));
+            for (condition, acceptor_state) in conditions {
+                match condition {
+                    Some(condition) => {
+                        try!(writeln!(out, "                    if {} {{", condition));
+                        try!(writeln!(out, "                        return Some({});", state(acceptor_state)));
+                        try!(writeln!(out, "                    }}"));
+                    }
+                    None => try!(writeln!(out, "                    return Some({});", state(acceptor_state))),
+                }
+            }
+            try!(writeln!(out, "                }}"));
+        }
+
+        if err_acceptors.is_empty() {
+            try!(writeln!(out, "                Err(_) => {{}},"));
+        } else {
+            try!(writeln!(out, "                Err(ref err) => {{"));
+            for acceptor in err_acceptors {
+                let condition = match acceptor.matcher {
+                    Matcher::Error(ref code) => format!("::runtime::waiter::ServiceError::code(err) == {:?}", code),
+                    Matcher::Status(status) => format!("::runtime::waiter::ServiceError::status(err) == {}", status),
+                    _ => unreachable!(),
+                };
+                try!(writeln!(out, "                    if {} {{", condition));
+                try!(writeln!(out, "                        return Some({});", state(acceptor.state)));
+                try!(writeln!(out, "                    }}"));
+            }
+            try!(writeln!(out, "                }}"));
+        }
+
+        try!(writeln!(out, "            }}"));
+        try!(writeln!(out, "            None"));
+        try!(writeln!(out, "        }})"));
+        try!(writeln!(out, "    }}"));
+        Ok(())
+    }
+
+    /// Returns an expression collecting every value `path` selects from `output` into
+    /// a `Vec<&T>`, and a comparison of one such `v: &&T` with `expected`.
+    fn values(&self, service: &ServiceDefinition, root: &str, path: &Path, expected: &Value) -> Result<(String, String), Error> {
+        if path.alternatives.len() != 1 {
+            return Err(self.invalid("`||` is not supported in acceptor arguments"));
+        }
+        let mut expression = "vec!(output).into_iter()".to_string();
+        let mut current = root;
+        for segment in &path.alternatives[0] {
+            let (target, member) = try!(path::step(service, current, segment).map_err(|message| self.invalid(&message)));
+            match (segment, member) {
+                (&Segment::Member(ref name), Some(member)) => {
+                    if !service.is_option(member) {
+                        expression.push_str(&format!(".map(|v| &v.{})", field_name(name)));
+                    } else {
+                        expression.push_str(&format!(".filter_map(|v| v.{}.as_ref())", field_name(name)));
+                    }
+                }
+                (&Segment::Flatten, _) => expression.push_str(".flat_map(|v| v.iter())"),
+                (&Segment::Index(index), _) if index == -1 => expression.push_str(".filter_map(|v| v.last())"),
+                (&Segment::Index(index), _) if index >= 0 => {
+                    expression.push_str(&format!(".filter_map(|v| v.get({}))", index));
+                }
+                _ => return Err(self.invalid(&format!("cannot follow {:?} from {}", segment, current))),
+            }
+            current = target;
+        }
+        expression.push_str(".collect::<Vec<_>>()");
+
+        let shape = try!(service.shape(current).ok_or(self.invalid(&format!("unknown shape {}", current))));
+        let mismatch = || self.invalid(&format!("{} cannot be compared with {:?}", current, expected));
+        let compare = match shape.shape_type {
+            ShapeType::StringEnum(StringEnum(ref variants)) => {
+                let variant = try!(expected.as_string().and_then(|e| variants.iter().find(|v| *v == e)).ok_or(mismatch()));
+                format!("match **v {{ {}::{} => true, _ => false }}", type_name(current), variant_name(variant))
+            }
+            ShapeType::StringPattern(_) | ShapeType::Timestamp => {
+                format!("**v == {:?}", try!(expected.as_string().ok_or(mismatch())))
+            }
+            ShapeType::Boolean => format!("**v == {}", try!(expected.as_boolean().ok_or(mismatch()))),
+            ShapeType::Integer(_) | ShapeType::Long => format!("**v == {}", try!(expected.as_i64().ok_or(mismatch()))),
+            ShapeType::Double | ShapeType::Float => format!("**v == {:?}", try!(expected.as_f64().ok_or(mismatch()))),
+            _ => return Err(mismatch()),
+        };
+        Ok((expression, compare))
+    }
+
+    fn invalid(&self, message: &str) -> Error {
+        Error::new(ErrorKind::InvalidInput, format!("waiter {}: {}", self.name, message))
+    }
+}
+
+fn state(state: AcceptorState) -> &'static str {
+    match state {
+        AcceptorState::Success => "::runtime::waiter::State::Success",
+        AcceptorState::Failure => "::runtime::waiter::State::Failure",
+        AcceptorState::Retry => "::runtime::waiter::State::Retry",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::parser::waiters::Waiters;
+    use ::testhelpers::{fixture_reader, fixture_service, fixture_string};
+
+    macro_rules! generates {
+        ($test:ident, $fixture:expr, $waiter:expr) => {
+            #[test]
+            fn $test() {
+                let mut service = fixture_service("services/lambda-2015-03-31");
+                service.add_waiters(Waiters::parse(fixture_reader("waiters/lambda-2015-03-31")).unwrap()).unwrap();
+                let mut buffer = Vec::new();
+                assert!(service.waiter($waiter).unwrap().generate(&mut buffer, &service).is_ok());
+                let actual = String::from_utf8(buffer).unwrap();
+                let expected = fixture_string(&format!("generated/{}.rs", $fixture));
+                assert_eq!(expected, actual);
+            }
+        };
+    }
+
+    generates!(status_and_error, "waiter-function-exists", "FunctionExists");
+    generates!(path, "waiter-event-source-mapping-enabled", "EventSourceMappingEnabled");
+    generates!(path_all_and_any, "waiter-all-functions-updated", "AllFunctionsUpdated");
+
+    #[test]
+    fn status_without_output() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        service.add_waiters(Waiters::parse(fixture_reader("waiters/no-output")).unwrap()).unwrap();
+        let mut buffer = Vec::new();
+        assert!(service.waiter("FunctionDeleted").unwrap().generate(&mut buffer, &service).is_ok());
+        assert_eq!(fixture_string("generated/waiter-function-deleted.rs"), String::from_utf8(buffer).unwrap());
+    }
+
+    #[test]
+    fn path_projection() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        service.add_waiters(Waiters::parse(fixture_reader("waiters/path-projection")).unwrap()).unwrap();
+        let err = service.waiter("NodeFunctions").unwrap().generate(&mut Vec::new(), &service).unwrap_err();
+        assert_eq!(format!("{}", err), "waiter NodeFunctions: a path matcher cannot compare the list Functions[].Runtime \
+                                        selects; use pathAll or pathAny");
+    }
+}
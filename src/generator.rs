@@ -0,0 +1,801 @@
+//! Generating code from a build script.
+
+use std::cmp;
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use ::generater::naming::snake_case;
+use ::generater::package::{package_name, Runtime};
+use ::parser::ParseError;
+use ::parser::endpoints::Endpoints;
+use ::parser::load::{self, LoadError, Loaded};
+use ::parser::overlay::Overlay;
+use ::parser::ServiceDefinition;
+
+/// A problem with one of the files a `Generator` reads or writes.
+#[derive(Debug)]
+pub enum Diagnostic {
+    /// The file could not be read or written.
+    Io(PathBuf, io::Error),
+    /// A service definition, companion file or endpoints file is invalid.
+    Parse(PathBuf, ParseError),
+    /// The service definition parsed, but code cannot be generated from it.
+    Generate(PathBuf, String),
+    /// Neither `out_dir()` nor `OUT_DIR` says where to write.
+    NoOutDir,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Diagnostic::Io(ref path, ref err) => write!(f, "{}: {}", path.display(), err),
+            Diagnostic::Parse(ref path, ref err) => write!(f, "{}: {}", path.display(), err),
+            Diagnostic::Generate(ref path, ref message) => write!(f, "{}: {}", path.display(), message),
+            Diagnostic::NoOutDir => write!(f, "no output directory: call out_dir() or set OUT_DIR"),
+        }
+    }
+}
+
+impl From<LoadError> for Diagnostic {
+    fn from(err: LoadError) -> Diagnostic {
+        match err {
+            LoadError::Io(path, err) => Diagnostic::Io(path, err),
+            LoadError::Parse(path, err) => Diagnostic::Parse(path, err),
+        }
+    }
+}
+
+/// The files a successful `Generator::generate()` read and wrote.
+#[derive(Debug, Default, PartialEq)]
+pub struct Generated {
+    pub read: Vec<PathBuf>,
+    pub written: Vec<PathBuf>,
+    /// What `operation()` left out of each service, when it was called.
+    pub subsets: Vec<Subset>,
+}
+
+/// How much smaller generating only the operations given to `Generator::operation()`
+/// made a service.
+#[derive(Debug, PartialEq)]
+pub struct Subset {
+    /// The service's endpoint prefix.
+    pub service: String,
+    /// How many operations were left out.
+    pub operations: usize,
+    /// How many shapes were left out, as no operation kept reaches them.
+    pub shapes: usize,
+    /// How many fewer lines were generated, or `None` when the whole service cannot be
+    /// generated to compare with.
+    pub lines: Option<usize>,
+}
+
+/// What `Generator::generate_batch()` did with each service in a models directory.
+#[derive(Debug, Default)]
+pub struct Report {
+    /// Every file read, starting with the models directory itself.
+    pub read: Vec<PathBuf>,
+    /// The services generated, by name.
+    pub generated: Vec<GeneratedService>,
+    /// The services left out, by name.
+    pub skipped: Vec<Skipped>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GeneratedService {
+    pub service: String,
+    pub api_version: String,
+    pub written: Vec<PathBuf>,
+    pub subset: Option<Subset>,
+}
+
+#[derive(Debug)]
+pub struct Skipped {
+    pub service: String,
+    pub reason: SkipReason,
+}
+
+/// Why a service in a models directory was not generated.
+#[derive(Debug)]
+pub enum SkipReason {
+    /// None of its version directories has a `service-*.json`.
+    NoServiceDefinition,
+    /// The API version pinned with `Generator::pin()` is not there.
+    MissingVersion(String),
+    /// The API version chosen could not be loaded or generated.
+    Failed(String, Diagnostic),
+    /// Generating the API version chosen panicked, with this message.
+    Panicked(String, String),
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SkipReason::NoServiceDefinition => write!(f, "no service definition"),
+            SkipReason::MissingVersion(ref version) => write!(f, "pinned version {} is not there", version),
+            SkipReason::Failed(ref version, ref diagnostic) => write!(f, "{}: {}", version, diagnostic),
+            SkipReason::Panicked(ref version, ref message) => write!(f, "{}: panicked: {}", version, message),
+        }
+    }
+}
+
+/// Generates a Rust file per service definition, named after its endpoint prefix
+/// (`lambda.rs`, `cognito_idp.rs`), for a build script to `include!`:
+///
+/// ```rust,ignore
+/// // build.rs
+/// extern crate aws_service_parser;
+///
+/// fn main() {
+///     aws_service_parser::Generator::new()
+///         .model("botocore/data/lambda/2015-03-31/service-2.json")
+///         .generate()
+///         .unwrap_or_else(|diagnostics| {
+///             for diagnostic in &diagnostics {
+///                 println!("cargo:warning={}", diagnostic);
+///             }
+///             panic!("could not generate the service clients");
+///         });
+/// }
+///
+/// // src/lib.rs
+/// extern crate aws_service_parser;
+/// use aws_service_parser::runtime;
+///
+/// pub mod lambda {
+///     include!(concat!(env!("OUT_DIR"), "/lambda.rs"));
+/// }
+/// ```
+///
+/// Generated code refers to the support code as `::runtime`, hence the `use`.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    models: Vec<PathBuf>,
+    overlays: Vec<PathBuf>,
+    operations: Vec<String>,
+    omit_deprecated: bool,
+    pins: BTreeMap<String, String>,
+    jobs: usize,
+    endpoints: Option<PathBuf>,
+    out_dir: Option<PathBuf>,
+    module_file: bool,
+    package: bool,
+    runtime: Runtime,
+    cargo_metadata: bool,
+}
+
+impl Generator {
+    pub fn new() -> Generator {
+        Generator {
+            models: vec!(),
+            overlays: vec!(),
+            operations: vec!(),
+            omit_deprecated: false,
+            pins: BTreeMap::new(),
+            jobs: 4,
+            endpoints: None,
+            out_dir: None,
+            module_file: false,
+            package: false,
+            runtime: Runtime::default(),
+            cargo_metadata: true,
+        }
+    }
+
+    /// Adds a service definition. A botocore `service-*.json` brings along the
+    /// paginators, waiters and examples next to it.
+    pub fn model<P: AsRef<Path>>(mut self, path: P) -> Generator {
+        self.models.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Applies an overlay to every model it is for before generating it, in the order
+    /// the overlays were added. See `Overlay` for what one can change.
+    pub fn overlay<P: AsRef<Path>>(mut self, path: P) -> Generator {
+        self.overlays.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Generates only this operation and the others added, leaving out the shapes none
+    /// of them reach. A model without one of the operations fails to generate, so this
+    /// is for `generate()` with a single model.
+    pub fn operation<S: Into<String>>(mut self, name: S) -> Generator {
+        self.operations.push(name.into());
+        self
+    }
+
+    /// Whether to leave out everything the models deprecate, rather than generate it
+    /// with `#[deprecated]`. This goes for operations, errors, members and shapes, and
+    /// for what is only there for them. Off by default.
+    pub fn omit_deprecated(mut self, omit_deprecated: bool) -> Generator {
+        self.omit_deprecated = omit_deprecated;
+        self
+    }
+
+    /// Makes `generate_batch()` use `api_version` of `service`, named after its directory
+    /// (`lambda`, `cognito-idp`), rather than the latest.
+    pub fn pin<S: Into<String>, V: Into<String>>(mut self, service: S, api_version: V) -> Generator {
+        self.pins.insert(service.into(), api_version.into());
+        self
+    }
+
+    /// How many services `generate_batch()` generates at once. 4 by default.
+    pub fn jobs(mut self, jobs: usize) -> Generator {
+        self.jobs = jobs;
+        self
+    }
+
+    /// Reads `endpoints.json` so that each service gets an `endpoint(region)` function.
+    pub fn endpoints<P: AsRef<Path>>(mut self, path: P) -> Generator {
+        self.endpoints = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Where to write, instead of the `OUT_DIR` cargo gives build scripts.
+    pub fn out_dir<P: AsRef<Path>>(mut self, path: P) -> Generator {
+        self.out_dir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Whether to write module files, which start with the service documentation as
+    /// `//!` comments and so cannot be `include!`d. Off by default.
+    pub fn module_file(mut self, module_file: bool) -> Generator {
+        self.module_file = module_file;
+        self
+    }
+
+    /// Whether to write a whole Cargo package per service, into `<out_dir>/<package
+    /// name>`, instead of a single file. Off by default.
+    pub fn package(mut self, package: bool) -> Generator {
+        self.package = package;
+        self
+    }
+
+    /// Where generated packages get the support code from: by default, the version of
+    /// this crate doing the generating.
+    pub fn runtime(mut self, runtime: Runtime) -> Generator {
+        self.runtime = runtime;
+        self
+    }
+
+    /// Whether to print a `cargo:rerun-if-changed` line for every file read, so that
+    /// cargo runs the build script again when one changes. On by default.
+    pub fn cargo_metadata(mut self, cargo_metadata: bool) -> Generator {
+        self.cargo_metadata = cargo_metadata;
+        self
+    }
+
+    /// Generates every model, carrying on past failures so that all of them are
+    /// reported at once.
+    pub fn generate(&self) -> Result<Generated, Vec<Diagnostic>> {
+        let stdout = io::stdout();
+        let mut cargo = stdout.lock();
+        self.generate_to(&mut cargo)
+    }
+
+    /// `generate()`, with the cargo lines going to `cargo`.
+    fn generate_to<W: Write>(&self, cargo: &mut W) -> Result<Generated, Vec<Diagnostic>> {
+        let mut generated = Generated::default();
+        let mut diagnostics = vec!();
+        let out_dir = try!(self.resolve_out_dir().map_err(|diagnostic| vec!(diagnostic)));
+        let endpoints = match self.endpoints {
+            Some(ref path) => {
+                generated.read.push(path.clone());
+                match load_endpoints(path) {
+                    Ok(endpoints) => Some(endpoints),
+                    Err(diagnostic) => {
+                        diagnostics.push(diagnostic);
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+
+        for model in &self.models {
+            let (read, result) = self.generate_model(model, endpoints.as_ref(), &out_dir, None);
+            generated.read.extend(read);
+            match result {
+                Ok((paths, subset)) => {
+                    generated.written.extend(paths);
+                    generated.subsets.extend(subset);
+                }
+                Err(diagnostic) => diagnostics.push(diagnostic),
+            }
+        }
+        self.print_cargo_metadata(cargo, &generated.read);
+
+        if diagnostics.is_empty() {
+            Ok(generated)
+        } else {
+            Err(diagnostics)
+        }
+    }
+
+    /// Generates every service in a botocore `data/` directory at its latest API version,
+    /// or the one pinned with `pin()`, several at a time. Models added with `model()`
+    /// play no part. Outputs are named after the service's directory rather than its
+    /// endpoint prefix, which several services can share: `kinesis-video-media` goes to
+    /// `kinesis_video_media.rs`, not `kinesisvideo.rs`.
+    ///
+    /// A service that cannot be generated is skipped and reported rather than stopping
+    /// the others, so this only fails without an output directory or when the models
+    /// directory or the endpoints cannot be read.
+    pub fn generate_batch<P: AsRef<Path>>(&self, data: P) -> Result<Report, Diagnostic> {
+        let stdout = io::stdout();
+        let mut cargo = stdout.lock();
+        self.generate_batch_to(data.as_ref(), &mut cargo)
+    }
+
+    /// `generate_batch()`, with the cargo lines going to `cargo`.
+    fn generate_batch_to<W: Write>(&self, data: &Path, cargo: &mut W) -> Result<Report, Diagnostic> {
+        let mut report = Report::default();
+        let out_dir = try!(self.resolve_out_dir());
+        // The directory itself, so that cargo notices services being added or removed.
+        report.read.push(data.to_path_buf());
+        let endpoints = match self.endpoints {
+            Some(ref path) => {
+                report.read.push(path.clone());
+                Some(try!(load_endpoints(path)))
+            }
+            None => None,
+        };
+
+        let available = try!(load::available(data));
+        for (service, version) in &self.pins {
+            if !available.iter().any(|a| &a.service == service) {
+                report.skipped.push(Skipped {
+                    service: service.clone(),
+                    reason: SkipReason::MissingVersion(version.clone()),
+                });
+            }
+        }
+        let mut jobs = vec!();
+        for available in available {
+            let chosen = match self.pins.get(&available.service) {
+                Some(pinned) => available.versions.iter().find(|v| &v.0 == pinned).ok_or(SkipReason::MissingVersion(pinned.clone())),
+                None => available.versions.last().ok_or(SkipReason::NoServiceDefinition),
+            };
+            match chosen {
+                Ok(&(ref version, ref model)) => jobs.push((available.service.clone(), version.clone(), model.clone())),
+                Err(reason) => report.skipped.push(Skipped {
+                    service: available.service,
+                    reason: reason,
+                }),
+            }
+        }
+
+        // Workers take services off a shared queue until it runs dry.
+        let queue = Arc::new(Mutex::new(jobs.into_iter()));
+        let generator = Arc::new(self.clone());
+        let endpoints = Arc::new(endpoints);
+        let out_dir = Arc::new(out_dir);
+        let (sender, receiver) = mpsc::channel();
+        let workers: Vec<_> = (0..cmp::max(self.jobs, 1)).map(|_| {
+            let (queue, generator, endpoints, out_dir, sender) =
+                (queue.clone(), generator.clone(), endpoints.clone(), out_dir.clone(), sender.clone());
+            thread::spawn(move || loop {
+                let job = queue.lock().unwrap().next();
+                let (service, version, model) = match job {
+                    Some(job) => job,
+                    None => break,
+                };
+                // Each service gets a thread of its own, so that one panicking is
+                // reported rather than taking the worker and its service with it.
+                let (generator, endpoints, out_dir, name) =
+                    (generator.clone(), endpoints.clone(), out_dir.clone(), service.clone());
+                let result = thread::spawn(move || {
+                    generator.generate_model(&model, (*endpoints).as_ref(), &out_dir, Some(&name))
+                }).join().map_err(|panic| {
+                    panic.downcast_ref::<&str>().map(|message| message.to_string())
+                        .or(panic.downcast_ref::<String>().cloned())
+                        .unwrap_or("no message".to_string())
+                });
+                let _ = sender.send((service, version, result));
+            })
+        }).collect();
+        drop(sender);
+        let mut results: Vec<_> = receiver.iter().collect();
+        for worker in workers {
+            // A worker only takes jobs off the queue and sends back what became of them.
+            worker.join().unwrap();
+        }
+
+        results.sort_by(|a, b| a.0.cmp(&b.0));
+        for (service, version, result) in results {
+            match result {
+                Ok((read, Ok((written, subset)))) => {
+                    report.read.extend(read);
+                    report.generated.push(GeneratedService {
+                        service: service,
+                        api_version: version,
+                        written: written,
+                        subset: subset,
+                    });
+                }
+                Ok((read, Err(diagnostic))) => {
+                    report.read.extend(read);
+                    report.skipped.push(Skipped {
+                        service: service,
+                        reason: SkipReason::Failed(version, diagnostic),
+                    });
+                }
+                Err(message) => report.skipped.push(Skipped {
+                    service: service,
+                    reason: SkipReason::Panicked(version, message),
+                }),
+            }
+        }
+        report.skipped.sort_by(|a, b| a.service.cmp(&b.service));
+        self.print_cargo_metadata(cargo, &report.read);
+        Ok(report)
+    }
+
+    fn resolve_out_dir(&self) -> Result<PathBuf, Diagnostic> {
+        self.out_dir.clone().or(env::var_os("OUT_DIR").map(PathBuf::from)).ok_or(Diagnostic::NoOutDir)
+    }
+
+    fn print_cargo_metadata<W: Write>(&self, cargo: &mut W, read: &[PathBuf]) {
+        if self.cargo_metadata {
+            for path in read {
+                let _ = writeln!(cargo, "cargo:rerun-if-changed={}", path.display());
+            }
+        }
+    }
+
+    /// Loads and generates one model, returning the files read along with the files
+    /// written and how much `operation()` left out, or what went wrong. The output is
+    /// named after `name`, or the endpoint prefix without one.
+    fn generate_model(&self, model: &Path, endpoints: Option<&Endpoints>, out_dir: &Path, name: Option<&str>)
+                      -> (Vec<PathBuf>, Result<(Vec<PathBuf>, Option<Subset>), Diagnostic>) {
+        match ServiceDefinition::load(model) {
+            Ok(Loaded { mut service, mut files, .. }) => {
+                let result = self.apply_overlays(&mut service, &mut files)
+                    .and_then(|_| self.generate_service(&mut service, endpoints, model, out_dir, name));
+                (files, result)
+            }
+            Err(err) => {
+                let mut read = vec!(model.to_path_buf());
+                if let LoadError::Parse(ref path, _) = err {
+                    if path != model {
+                        read.push(path.clone());
+                    }
+                }
+                (read, Err(Diagnostic::from(err)))
+            }
+        }
+    }
+
+    /// Applies the overlays for `service`, adding each to `read`.
+    fn apply_overlays(&self, service: &mut ServiceDefinition, read: &mut Vec<PathBuf>) -> Result<(), Diagnostic> {
+        for path in &self.overlays {
+            read.push(path.clone());
+            let file = try!(File::open(path).map_err(|err| Diagnostic::Io(path.clone(), err)));
+            let overlay = try!(Overlay::parse(file).map_err(|err| Diagnostic::Parse(path.clone(), err)));
+            if overlay.applies_to(service) {
+                try!(service.apply(&overlay).map_err(|err| Diagnostic::Parse(path.clone(), err)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Leaves out what is deprecated if asked to and cuts the service down to the
+    /// operations given to `operation()`, if any, then generates and writes it.
+    fn generate_service(&self, service: &mut ServiceDefinition, endpoints: Option<&Endpoints>, model: &Path,
+                        out_dir: &Path, name: Option<&str>) -> Result<(Vec<PathBuf>, Option<Subset>), Diagnostic> {
+        if self.omit_deprecated {
+            service.omit_deprecated();
+        }
+        let whole = if self.operations.is_empty() {
+            None
+        } else {
+            // Only what is left has to generate, so the whole service may not.
+            let lines = self.render(service, endpoints, model, out_dir, name).ok().map(|files| count_lines(&files));
+            let whole = (service.operations.len(), service.shapes.len(), lines);
+            try!(service.subset(&self.operations).map_err(|err| Diagnostic::Parse(model.to_path_buf(), err)));
+            Some(whole)
+        };
+        let files = try!(self.render(service, endpoints, model, out_dir, name));
+        let subset = whole.map(|(operations, shapes, lines)| Subset {
+            service: service.metadata.endpointPrefix.clone(),
+            operations: operations - service.operations.len(),
+            shapes: shapes - service.shapes.len(),
+            lines: lines.map(|lines| lines - count_lines(&files)),
+        });
+        Ok((try!(write_files(files, out_dir)), subset))
+    }
+
+    /// Generates the files for a service into memory, so that a failure leaves nothing
+    /// half written. They are named after `name`, or the endpoint prefix without one.
+    fn render(&self, service: &ServiceDefinition, endpoints: Option<&Endpoints>, model: &Path, out_dir: &Path,
+              name: Option<&str>) -> Result<Vec<(PathBuf, Vec<u8>)>, Diagnostic> {
+        let name = name.unwrap_or(&service.metadata.endpointPrefix);
+        let generate_error = |err: io::Error| match err.kind() {
+            ErrorKind::InvalidInput => Diagnostic::Generate(model.to_path_buf(), err.to_string()),
+            _ => Diagnostic::Io(model.to_path_buf(), err),
+        };
+        let files = if self.package {
+            let directory = out_dir.join(package_name(name));
+            let files = try!(service.generate_package(endpoints, &self.runtime).map_err(generate_error));
+            files.into_iter().map(|(path, code)| (directory.join(path), code)).collect()
+        } else {
+            let mut code = Vec::new();
+            let result = if self.module_file {
+                service.generate_module(&mut code, endpoints)
+            } else {
+                service.generate_items(&mut code, endpoints)
+            };
+            try!(result.map_err(generate_error));
+            vec!((out_dir.join(format!("{}.rs", snake_case(name))), code))
+        };
+        Ok(files)
+    }
+}
+
+fn write_files(files: Vec<(PathBuf, Vec<u8>)>, out_dir: &Path) -> Result<Vec<PathBuf>, Diagnostic> {
+    let mut written = vec!();
+    for (target, code) in files {
+        let directory = target.parent().unwrap_or(out_dir).to_path_buf();
+        try!(fs::create_dir_all(&directory).and_then(|_| File::create(&target)).and_then(|mut file| file.write_all(&code))
+             .map_err(|err| Diagnostic::Io(target.clone(), err)));
+        written.push(target);
+    }
+    Ok(written)
+}
+
+fn count_lines(files: &[(PathBuf, Vec<u8>)]) -> usize {
+    files.iter().fold(0, |lines, &(_, ref code)| lines + code.iter().filter(|&&byte| byte == b'\n').count())
+}
+
+fn load_endpoints(path: &Path) -> Result<Endpoints, Diagnostic> {
+    let file = try!(File::open(path).map_err(|err| Diagnostic::Io(path.to_path_buf(), err)));
+    Endpoints::parse(file).map_err(|err| Diagnostic::Parse(path.to_path_buf(), err))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::Read;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use ::generater::package::Runtime;
+    use ::parser::ParseError;
+
+    fn out_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aws-service-parser-{}", name))
+    }
+
+    #[test]
+    fn build_script() {
+        let out_dir = out_dir("generator");
+        let mut cargo = Vec::new();
+        let generated = Generator::new()
+            .model("fixtures/botocore/lambda/2015-03-31/service-2.json")
+            .endpoints("fixtures/endpoints/endpoints.json")
+            .out_dir(&out_dir)
+            .generate_to(&mut cargo)
+            .unwrap();
+        assert_eq!(generated.written, vec!(out_dir.join("lambda.rs")));
+        assert_eq!(String::from_utf8(cargo).unwrap(), "\
+cargo:rerun-if-changed=fixtures/endpoints/endpoints.json
+cargo:rerun-if-changed=fixtures/botocore/lambda/2015-03-31/service-2.json
+cargo:rerun-if-changed=fixtures/botocore/lambda/2015-03-31/examples-1.json
+cargo:rerun-if-changed=fixtures/botocore/lambda/2015-03-31/paginators-1.json
+cargo:rerun-if-changed=fixtures/botocore/lambda/2015-03-31/waiters-2.json
+");
+        let mut code = String::new();
+        File::open(out_dir.join("lambda.rs")).unwrap().read_to_string(&mut code).unwrap();
+        assert!(!code.contains("//!"));
+        assert!(code.contains("pub fn endpoint(region: &str)"));
+        assert!(code.contains("fn wait_until_function_exists("));
+    }
+
+    #[test]
+    fn every_problem_is_reported() {
+        let mut cargo = Vec::new();
+        let diagnostics = Generator::new()
+            .model("fixtures/services/missing.json")
+            .model("fixtures/services/stream-not-payload.json")
+            .model("fixtures/services/recursive.json")
+            .out_dir(out_dir("generator-diagnostics"))
+            .cargo_metadata(false)
+            .generate_to(&mut cargo)
+            .unwrap_err();
+        assert!(cargo.is_empty());
+        assert_eq!(diagnostics.len(), 2);
+        match diagnostics[0] {
+            Diagnostic::Io(ref path, _) => assert_eq!(path, &PathBuf::from("fixtures/services/missing.json")),
+            ref other => panic!("unexpected {:?}", other),
+        }
+        match diagnostics[1] {
+            Diagnostic::Parse(_, ParseError::InvalidPayload(ref member)) => assert_eq!(member, "UploadRequest.Body"),
+            ref other => panic!("unexpected {:?}", other),
+        }
+        assert!(out_dir("generator-diagnostics").join("conditions.rs").exists());
+    }
+
+    #[test]
+    fn batch() {
+        let mut cargo = Vec::new();
+        let report = Generator::new()
+            .out_dir(out_dir("generator-batch"))
+            .generate_batch_to(Path::new("fixtures/botocore"), &mut cargo)
+            .unwrap();
+        let generated: Vec<(&str, &str)> = report.generated.iter()
+            .map(|g| (&g.service[..], &g.api_version[..])).collect();
+        assert_eq!(generated, vec!(("conditions", "2016-01-01"), ("lambda", "2015-03-31"),
+                                   ("lambda-preview", "2015-03-31")));
+        assert_eq!(report.generated[1].written, vec!(out_dir("generator-batch").join("lambda.rs")));
+        assert_eq!(report.generated[2].written, vec!(out_dir("generator-batch").join("lambda_preview.rs")));
+        let skipped: Vec<String> = report.skipped.iter().map(|s| format!("{}: {}", s.service, s.reason)).collect();
+        assert_eq!(skipped, vec!(
+            "sdb: no service definition".to_string(),
+            "uploads: 2016-01-01: fixtures/botocore/uploads/2016-01-01/service-2.json: invalid payload: UploadRequest.Body"
+                .to_string(),
+        ));
+        let cargo = String::from_utf8(cargo).unwrap();
+        assert!(cargo.starts_with("cargo:rerun-if-changed=fixtures/botocore\n\
+                                   cargo:rerun-if-changed=fixtures/botocore/conditions/2016-01-01/service-2.json\n"));
+        assert!(cargo.contains("cargo:rerun-if-changed=fixtures/botocore/lambda/2015-03-31/waiters-2.json\n"));
+    }
+
+    #[test]
+    fn batch_pins() {
+        let report = Generator::new()
+            .out_dir(out_dir("generator-batch-pins"))
+            .pin("conditions", "2015-06-01")
+            .pin("lambda", "2014-11-11")
+            .pin("ec2", "2016-11-15")
+            .jobs(1)
+            .cargo_metadata(false)
+            .generate_batch_to(Path::new("fixtures/botocore"), &mut Vec::new())
+            .unwrap();
+        assert_eq!(report.generated.len(), 2);
+        assert_eq!(report.generated[0].api_version, "2015-06-01");
+        let skipped: Vec<String> = report.skipped.iter().map(|s| format!("{}: {}", s.service, s.reason)).collect();
+        assert_eq!(&skipped[..2], &["ec2: pinned version 2016-11-15 is not there".to_string(),
+                                    "lambda: pinned version 2014-11-11 is not there".to_string()]);
+        assert_eq!(skipped.len(), 4);
+    }
+
+    #[test]
+    fn overlays() {
+        let out_dir = out_dir("generator-overlays");
+        let mut cargo = Vec::new();
+        Generator::new()
+            .model("fixtures/botocore/lambda/2015-03-31/service-2.json")
+            .model("fixtures/services/recursive.json")
+            .overlay("fixtures/overlays/lambda.json")
+            .out_dir(&out_dir)
+            .generate_to(&mut cargo)
+            .unwrap();
+        let cargo = String::from_utf8(cargo).unwrap();
+        assert!(cargo.ends_with("cargo:rerun-if-changed=fixtures/overlays/lambda.json\n"));
+        let mut code = String::new();
+        File::open(out_dir.join("lambda.rs")).unwrap().read_to_string(&mut code).unwrap();
+        assert!(code.contains("#[derive(Debug, Default, Serialize, Deserialize, Clone)]\npub struct Function {"));
+        assert!(!code.contains("FunctionConfiguration {"));
+        assert!(!code.contains("fn invoke_async("));
+
+        let diagnostics = Generator::new()
+            .model("fixtures/services/lambda-2015-03-31.json")
+            .overlay("fixtures/overlays/lambda.json")
+            .overlay("fixtures/overlays/lambda.json")
+            .out_dir(&out_dir)
+            .generate_to(&mut Vec::new())
+            .unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].to_string(),
+                   "fixtures/overlays/lambda.json: invalid overlay: removeOperations: there is no operation AddPermission");
+    }
+
+    #[test]
+    fn operations() {
+        let out_dir = out_dir("generator-operations");
+        let generated = Generator::new()
+            .model("fixtures/botocore/lambda/2015-03-31/service-2.json")
+            .operation("Invoke")
+            .operation("GetFunction")
+            .out_dir(&out_dir)
+            .cargo_metadata(false)
+            .generate_to(&mut Vec::new())
+            .unwrap();
+        assert_eq!(generated.subsets.len(), 1);
+        let subset = &generated.subsets[0];
+        assert_eq!((&subset.service[..], subset.operations), ("lambda", 22));
+        assert!(subset.shapes > 40);
+        assert!(subset.lines.unwrap() > 1000);
+        let mut code = String::new();
+        File::open(out_dir.join("lambda.rs")).unwrap().read_to_string(&mut code).unwrap();
+        assert!(code.contains("fn invoke("));
+        assert!(code.contains("fn get_function("));
+        assert!(!code.contains("fn list_functions("));
+        assert!(!code.contains("pub struct AliasConfiguration"));
+
+        let diagnostics = Generator::new()
+            .model("fixtures/services/recursive.json")
+            .operation("Invoke")
+            .out_dir(&out_dir)
+            .generate_to(&mut Vec::new())
+            .unwrap_err();
+        assert_eq!(diagnostics[0].to_string(), "fixtures/services/recursive.json: unknown operation Invoke");
+
+        let generated = Generator::new()
+            .model("fixtures/services/build-member.json")
+            .operation("Ping")
+            .out_dir(&out_dir)
+            .cargo_metadata(false)
+            .generate_to(&mut Vec::new())
+            .unwrap();
+        assert_eq!(generated.subsets, vec!(Subset { service: "builds".to_string(), operations: 1, shapes: 1, lines: None }));
+    }
+
+    #[test]
+    fn deprecated() {
+        let out_dir = out_dir("generator-deprecated");
+        let generator = Generator::new()
+            .model("fixtures/botocore/lambda/2015-03-31/service-2.json")
+            .out_dir(&out_dir)
+            .cargo_metadata(false);
+        let read = || {
+            let mut code = String::new();
+            File::open(out_dir.join("lambda.rs")).unwrap().read_to_string(&mut code).unwrap();
+            code
+        };
+        generator.generate_to(&mut Vec::new()).unwrap();
+        assert!(read().contains("#[deprecated(note = \"deprecated in the service model\")]\n    \
+                                 #[allow(deprecated)]\n    fn invoke_async("));
+
+        generator.omit_deprecated(true).generate_to(&mut Vec::new()).unwrap();
+        let code = read();
+        assert!(!code.contains("fn invoke_async("));
+        assert!(!code.contains("InvokeAsyncRequest"));
+        assert!(!code.contains("deprecated"));
+    }
+
+    #[test]
+    fn packages() {
+        let out_dir = out_dir("generator-packages");
+        let generated = Generator::new()
+            .model("fixtures/services/lambda-2015-03-31.json")
+            .out_dir(&out_dir)
+            .package(true)
+            .runtime(Runtime::Path(PathBuf::from("/src/aws-service-parser")))
+            .generate_to(&mut Vec::new())
+            .unwrap();
+        assert_eq!(generated.written.len(), 7);
+        assert_eq!(generated.written[0], out_dir.join("aws-lambda/Cargo.toml"));
+        assert_eq!(generated.written[6], out_dir.join("aws-lambda/src/client.rs"));
+        let mut manifest = String::new();
+        File::open(&generated.written[0]).unwrap().read_to_string(&mut manifest).unwrap();
+        assert!(manifest.contains("aws-service-parser = { path = \"/src/aws-service-parser\" }\n"));
+    }
+
+    /// Builds and tests a package generated with endpoints, paginators, waiters and
+    /// examples against this checkout, lib.rs and all. Ignored since cargo must find
+    /// the dependencies in its cache: run it with `cargo test -- --ignored`.
+    #[test]
+    #[ignore]
+    fn package_builds() {
+        let out_dir = out_dir("generator-package-builds");
+        let _ = fs::remove_dir_all(&out_dir);
+        Generator::new()
+            .model("fixtures/botocore/lambda/2015-03-31/service-2.json")
+            .endpoints("fixtures/endpoints/endpoints.json")
+            .out_dir(&out_dir)
+            .package(true)
+            .runtime(Runtime::Path(env::current_dir().unwrap()))
+            .generate_to(&mut Vec::new())
+            .unwrap();
+        let cargo = env::var("CARGO").unwrap_or("cargo".to_string());
+        let status = Command::new(cargo).args(&["test", "--offline"]).current_dir(out_dir.join("aws-lambda"))
+                                        .status().unwrap();
+        assert!(status.success());
+    }
+}
@@ -0,0 +1,47 @@
+//! Reads AWS service definitions, the JSON models botocore ships, and generates Rust
+//! clients from them. `Generator` does the whole job from a build script; `parser`
+//! and `generater` are the pieces it is made of, and `runtime` is the support code
+//! the generated clients call into. `export` writes definitions out for other tools.
+
+#![feature(custom_derive, plugin)]
+#![plugin(serde_macros)]
+#![allow(non_snake_case)]
+#![allow(unused_attributes)]
+
+extern crate serde;
+extern crate serde_json;
+
+pub mod parser;
+pub mod generater;
+pub mod runtime;
+pub mod export;
+mod generator;
+#[doc(hidden)]
+pub mod cli;
+
+pub use generator::{Diagnostic, Generated, GeneratedService, Generator, Report, SkipReason, Skipped, Subset};
+pub use generater::package::Runtime;
+
+#[cfg(test)]
+mod testhelpers;
+
+// The modules of the generated lambda package, which refer to the support code as
+// `::runtime` just as this crate does, so that the tests type-check them against it.
+// `generater::package`'s tests keep them up to date. The ignored `package_builds` test
+// in `generator` builds a whole package, lib.rs and endpoints included.
+#[cfg(test)]
+#[allow(dead_code)]
+#[path = "../fixtures/generated/package-lambda/model.rs"]
+mod model;
+#[cfg(test)]
+#[allow(dead_code)]
+#[path = "../fixtures/generated/package-lambda/error.rs"]
+mod error;
+#[cfg(test)]
+#[allow(dead_code)]
+#[path = "../fixtures/generated/package-lambda/protocol.rs"]
+mod protocol;
+#[cfg(test)]
+#[allow(dead_code)]
+#[path = "../fixtures/generated/package-lambda/client.rs"]
+mod client;
@@ -1,15 +1,12 @@
-#![feature(custom_derive, plugin)]
-#![plugin(serde_macros)]
-#![allow(non_snake_case)]
-#![allow(unused_attributes)]
-
-extern crate serde;
-extern crate serde_json;
-
-mod parser;
-mod generater;
-
-#[cfg(test)]
-mod testhelpers;
-
-fn main() {}
+extern crate aws_service_parser;
+
+use std::env;
+use std::io;
+use std::process;
+use aws_service_parser::cli;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let status = cli::run(&args, &mut io::stdout(), &mut io::stderr());
+    process::exit(status);
+}
@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+use super::service_definition::ServiceDefinition;
+use super::shape_type::*;
+
+/// Structures that contain themselves by value, directly or through other structures.
+/// Lists are left out of the graph since a `Vec` already puts its elements on the heap.
+#[derive(Debug, PartialEq)]
+pub struct Cycle {
+    /// The structures on the cycle, starting with the one it was entered from.
+    pub shapes: Vec<String>,
+    /// The structure and member closing the cycle, which is generated as a `Box`.
+    pub boxed: (String, String),
+}
+
+/// The members closing a cycle, by structure. Generators take this once with
+/// `ServiceDefinition::boxed()` rather than finding the cycles again for every structure.
+#[derive(Debug, Default, PartialEq)]
+pub struct Boxed(BTreeMap<String, Vec<String>>);
+
+impl Boxed {
+    /// Whether `member` of the structure `shape` closes a cycle.
+    pub fn contains(&self, shape: &str, member: &str) -> bool {
+        self.members(shape).iter().any(|boxed| boxed == member)
+    }
+
+    /// The members of `shape` that close a cycle.
+    pub fn members(&self, shape: &str) -> &[String] {
+        self.0.get(shape).map_or(&[][..], |members| &members[..])
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Visit {
+    InProgress,
+    Done,
+}
+
+impl ServiceDefinition {
+    /// Walks the graph of structures and the members they hold by value depth first,
+    /// returning one cycle for each member that leads back to a structure still being
+    /// walked. Boxing those members breaks every cycle, but cycles that share such a
+    /// member are reported once, so this is not every cycle in the graph. Shapes and
+    /// members are walked in order, so the result is stable.
+    pub fn cycles(&self) -> Vec<Cycle> {
+        let mut visits = BTreeMap::new();
+        let mut cycles = vec!();
+        for shape in &self.shapes {
+            if !visits.contains_key(&shape.name[..]) {
+                let mut path = vec!();
+                self.visit(&shape.name, &mut path, &mut visits, &mut cycles);
+            }
+        }
+        cycles
+    }
+
+    /// The members closing each cycle, by structure.
+    pub fn boxed(&self) -> Boxed {
+        let mut boxed = Boxed::default();
+        for cycle in self.cycles() {
+            boxed.0.entry(cycle.boxed.0).or_insert_with(Vec::new).push(cycle.boxed.1);
+        }
+        boxed
+    }
+
+    /// Whether `member` of the structure `shape` closes a cycle. This walks the whole
+    /// model; take `boxed()` once to look up many members.
+    pub fn is_boxed(&self, shape: &str, member: &str) -> bool {
+        self.boxed().contains(shape, member)
+    }
+
+    /// The members of `shape` that close a cycle. Like `is_boxed()`, for a single lookup.
+    pub fn boxed_members(&self, shape: &str) -> Vec<String> {
+        self.boxed().members(shape).to_vec()
+    }
+
+    fn visit<'a>(&'a self, name: &'a str, path: &mut Vec<&'a str>,
+                 visits: &mut BTreeMap<&'a str, Visit>, cycles: &mut Vec<Cycle>) {
+        let members = match self.shape(name).map(|shape| &shape.shape_type) {
+            Some(&ShapeType::Structure(Structure(ref members))) => members,
+            _ => return,
+        };
+        visits.insert(name, Visit::InProgress);
+        path.push(name);
+        for member in members {
+            match visits.get(&member.shape[..]).cloned() {
+                Some(Visit::InProgress) => {
+                    let start = path.iter().position(|shape| *shape == member.shape).unwrap();
+                    cycles.push(Cycle {
+                        shapes: path[start..].iter().map(|shape| shape.to_string()).collect(),
+                        boxed: (name.to_string(), member.name.clone()),
+                    });
+                }
+                Some(Visit::Done) => {}
+                None => self.visit(&member.shape, path, visits, cycles),
+            }
+        }
+        path.pop();
+        visits.insert(name, Visit::Done);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::testhelpers::fixture_service;
+
+    #[test]
+    fn self_and_mutual_recursion() {
+        let service = fixture_service("services/recursive");
+        assert_eq!(service.cycles(), vec!(
+            Cycle {
+                shapes: vec!("Expression".to_string(), "Operand".to_string()),
+                boxed: ("Operand".to_string(), "Expression".to_string()),
+            },
+            Cycle {
+                shapes: vec!("Node".to_string()),
+                boxed: ("Node".to_string(), "Next".to_string()),
+            },
+        ));
+        assert!(service.is_boxed("Node", "Next"));
+        assert!(!service.is_boxed("Expression", "Left"));
+        assert!(service.boxed_members("Tree").is_empty());
+        let boxed = service.boxed();
+        assert_eq!(boxed.members("Operand"), &["Expression".to_string()]);
+        assert!(boxed.contains("Node", "Next"));
+        assert!(!boxed.contains("Node", "Value"));
+    }
+
+    #[test]
+    fn lambda_has_no_cycles() {
+        assert!(fixture_service("services/lambda-2015-03-31").cycles().is_empty());
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::mem;
+use serde_json::Value;
+use super::error::ParseError;
+use super::operation::Operation;
+use super::service_definition::ServiceDefinition;
+use super::shape_type::*;
+
+/// Marks a shape, member or operation that should no longer be used. `message`, when
+/// the model has one, says why or what to use instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deprecation {
+    pub message: Option<String>,
+}
+
+impl Deprecation {
+    /// Reads the `deprecated` flag and `deprecatedMessage` of a shape or member.
+    pub fn parse(obj: &BTreeMap<String, Value>) -> Result<Option<Deprecation>, ParseError> {
+        let deprecated = match obj.get("deprecated") {
+            Some(json) => try!(json.as_boolean().ok_or(ParseError::InvalidDeprecated)),
+            None => false,
+        };
+        let message = match obj.get("deprecatedMessage") {
+            Some(json) => Some(try!(json.as_string().ok_or(ParseError::InvalidDeprecated)).to_string()),
+            None => None,
+        };
+        Ok(if deprecated { Some(Deprecation { message: message }) } else { None })
+    }
+}
+
+impl Operation {
+    pub fn deprecation(&self) -> Option<Deprecation> {
+        match self.deprecated {
+            Some(true) => Some(Deprecation { message: self.deprecatedMessage.clone() }),
+            _ => None,
+        }
+    }
+}
+
+impl ServiceDefinition {
+    /// Whether `shape` has members, or elements, of a deprecated shape.
+    pub fn refers_to_deprecated(&self, shape: &str) -> bool {
+        self.shape(shape).map_or(false, |shape| shape.shape_type.references().into_iter().any(|(_, target)| {
+            self.shape(target).map_or(false, |target| target.deprecated.is_some())
+        }))
+    }
+
+    /// Whether the code generated for `shape` refers to anything deprecated: the shape
+    /// itself, one of its members or a shape they have. Such code is generated with
+    /// `#[allow(deprecated)]`, so that only code using the deprecated items warns.
+    pub fn touches_deprecated(&self, shape: &str) -> bool {
+        let members_deprecated = match self.shape(shape).map(|shape| &shape.shape_type) {
+            Some(&ShapeType::Structure(Structure(ref members))) |
+            Some(&ShapeType::Exception(Exception { ref members, .. })) => {
+                members.iter().any(|member| member.deprecated.is_some())
+            }
+            _ => false,
+        };
+        self.shape(shape).map_or(false, |shape| shape.deprecated.is_some()) || members_deprecated ||
+        self.refers_to_deprecated(shape)
+    }
+
+    /// Whether an operation is deprecated or its input or output touches something that is.
+    pub fn operation_touches_deprecated(&self, operation: &Operation) -> bool {
+        operation.deprecated == Some(true) || self.touches_deprecated(&operation.input.shape) ||
+        operation.output.as_ref().map_or(false, |output| self.touches_deprecated(&output.shape))
+    }
+
+    /// Leaves out everything deprecated: operations, errors, members and shapes, along
+    /// with lists of deprecated shapes, members of them and operations taking or
+    /// returning them. A structure that requires a deprecated member, or one of an
+    /// omitted shape, cannot be built without it and goes as well. Paginators, waiters
+    /// and examples left invalid go too.
+    pub fn omit_deprecated(&mut self) {
+        let mut omitted: BTreeSet<String> = self.shapes.iter().filter(|shape| shape.deprecated.is_some())
+            .map(|shape| shape.name.clone()).collect();
+        loop {
+            let containers: Vec<String> = self.shapes.iter()
+                .filter(|shape| !omitted.contains(&shape.name))
+                .filter(|shape| match shape.shape_type {
+                    ShapeType::List(List { ref member, .. }) => omitted.contains(member),
+                    ShapeType::Structure(Structure(ref members)) |
+                    ShapeType::Exception(Exception { ref members, .. }) => {
+                        members.iter().any(|member| {
+                            member.required && (member.deprecated.is_some() || omitted.contains(&member.shape))
+                        })
+                    }
+                    _ => false,
+                })
+                .map(|shape| shape.name.clone())
+                .collect();
+            if containers.is_empty() {
+                break;
+            }
+            omitted.extend(containers);
+        }
+
+        let operations: Vec<String> = self.operations.iter()
+            .filter(|operation| {
+                operation.deprecated == Some(true) || operation.input.deprecated == Some(true) ||
+                omitted.contains(&operation.input.shape) ||
+                operation.output.as_ref().map_or(false, |output| {
+                    output.deprecated == Some(true) || omitted.contains(&output.shape)
+                })
+            })
+            .map(|operation| operation.name.clone())
+            .collect();
+        self.remove_operations(&operations);
+        for operation in &mut self.operations {
+            operation.errors.retain(|error| error.deprecated != Some(true) && !omitted.contains(&error.shape));
+        }
+        self.shapes.retain(|shape| !omitted.contains(&shape.name));
+        for shape in &mut self.shapes {
+            match shape.shape_type {
+                ShapeType::Structure(Structure(ref mut members)) |
+                ShapeType::Exception(Exception { ref mut members, .. }) => {
+                    members.retain(|member| member.deprecated.is_none() && !omitted.contains(&member.shape));
+                }
+                _ => {}
+            }
+        }
+        for name in &omitted {
+            self.derives.remove(name);
+        }
+
+        let paginators = mem::replace(&mut self.paginators, vec!());
+        self.paginators = paginators.into_iter().filter(|paginator| paginator.validate(self).is_ok()).collect();
+        let waiters = mem::replace(&mut self.waiters, vec!());
+        self.waiters = waiters.into_iter().filter(|waiter| waiter.validate(self).is_ok()).collect();
+        let examples = mem::replace(&mut self.examples, vec!());
+        self.examples = examples.into_iter().filter(|example| example.validate(self).is_ok()).collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::parser::*;
+    use ::testhelpers::{fixture_btreemap, fixture_service};
+
+    #[test]
+    fn parse() {
+        let shapes = fixture_btreemap("shapes/deprecated");
+        let parse = |name: &str| Deprecation::parse(shapes.get(name).unwrap().as_object().unwrap());
+        assert_eq!(parse("Deprecated"), Ok(Some(Deprecation { message: None })));
+        assert_eq!(parse("WithMessage"), Ok(Some(Deprecation { message: Some("Use Current.".to_string()) })));
+        assert_eq!(parse("Current"), Ok(None));
+        assert_eq!(parse("Invalid"), Err(ParseError::InvalidDeprecated));
+    }
+
+    #[test]
+    fn lambda() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        {
+            let invoke_async = service.operation("InvokeAsync").unwrap();
+            assert_eq!(invoke_async.deprecation(), Some(Deprecation { message: None }));
+            assert!(service.operation("Invoke").unwrap().deprecation().is_none());
+            assert!(service.shape("InvokeAsyncRequest").unwrap().deprecated.is_some());
+            assert!(service.operation_touches_deprecated(invoke_async));
+            assert!(!service.operation_touches_deprecated(service.operation("Invoke").unwrap()));
+        }
+
+        service.omit_deprecated();
+        assert!(service.operation("InvokeAsync").is_none());
+        assert!(service.shape("InvokeAsyncRequest").is_none());
+        assert!(service.shape("InvokeAsyncResponse").is_none());
+        assert!(service.operation("Invoke").is_some());
+        assert_eq!(service.operations.len(), 23);
+    }
+
+    #[test]
+    fn required_members() {
+        let mut service = fixture_service("services/deprecated-required");
+        service.omit_deprecated();
+        let names: Vec<&str> = service.shapes.iter().map(|shape| &shape.name[..]).collect();
+        assert_eq!(names, vec!("String", "TagRequest"));
+        let operations: Vec<&str> = service.operations.iter().map(|operation| &operation.name[..]).collect();
+        assert_eq!(operations, vec!("Tag"));
+        match service.shape("TagRequest").unwrap().shape_type {
+            ShapeType::Structure(Structure(ref members)) => {
+                assert_eq!(members.iter().map(|member| &member.name[..]).collect::<Vec<_>>(), vec!("Tag"));
+            }
+            _ => panic!("TagRequest is not a structure"),
+        }
+    }
+}
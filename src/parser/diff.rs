@@ -0,0 +1,468 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use super::service_definition::ServiceDefinition;
+use super::operation::Operation;
+use super::shape_type::*;
+
+/// Names that were added, removed or changed between two versions of a service.
+#[derive(Debug, Default, PartialEq)]
+pub struct Changes {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl Changes {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// One difference between two versions of a service.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    OperationAdded(String),
+    OperationRemoved(String),
+    /// The method or request URI changed; `old` and `new` are like `GET /functions`.
+    HttpChanged { operation: String, old: String, new: String },
+    InputChanged { operation: String, old: String, new: String },
+    OutputChanged { operation: String, old: Option<String>, new: Option<String> },
+    ErrorAdded { operation: String, error: String },
+    ErrorRemoved { operation: String, error: String },
+    ShapeAdded(String),
+    ShapeRemoved(String),
+    /// The shape is a different kind of thing, as `ShapeType::kind()` names it, except
+    /// that a streaming blob counts as different from one that is not.
+    TypeChanged { shape: String, old: String, new: String },
+    MemberAdded { shape: String, member: String, required: bool },
+    MemberRemoved { shape: String, member: String },
+    /// A member, or the `member` of a list, refers to a different shape.
+    MemberShapeChanged { shape: String, member: String, old: String, new: String },
+    RequiredChanged { shape: String, member: String, required: bool },
+    /// Where a member goes in the HTTP request or response, like `header:X-Amz-Date`.
+    LocationChanged { shape: String, member: String, old: String, new: String },
+    EnumValueAdded { shape: String, value: String },
+    EnumValueRemoved { shape: String, value: String },
+    /// A `min`, `max` or `pattern`; `None` when there is none.
+    ConstraintChanged { shape: String, constraint: &'static str, old: Option<String>, new: Option<String> },
+    /// The HTTP status code an exception comes with.
+    StatusCodeChanged { shape: String, old: i64, new: i64 },
+}
+
+impl Change {
+    /// A short name for the kind of change, like `enum-value-added`.
+    pub fn kind(&self) -> &'static str {
+        match *self {
+            Change::OperationAdded(_) => "operation-added",
+            Change::OperationRemoved(_) => "operation-removed",
+            Change::HttpChanged { .. } => "http-changed",
+            Change::InputChanged { .. } => "input-changed",
+            Change::OutputChanged { .. } => "output-changed",
+            Change::ErrorAdded { .. } => "error-added",
+            Change::ErrorRemoved { .. } => "error-removed",
+            Change::ShapeAdded(_) => "shape-added",
+            Change::ShapeRemoved(_) => "shape-removed",
+            Change::TypeChanged { .. } => "type-changed",
+            Change::MemberAdded { .. } => "member-added",
+            Change::MemberRemoved { .. } => "member-removed",
+            Change::MemberShapeChanged { .. } => "member-shape-changed",
+            Change::RequiredChanged { .. } => "required-changed",
+            Change::LocationChanged { .. } => "location-changed",
+            Change::EnumValueAdded { .. } => "enum-value-added",
+            Change::EnumValueRemoved { .. } => "enum-value-removed",
+            Change::ConstraintChanged { .. } => "constraint-changed",
+            Change::StatusCodeChanged { .. } => "status-code-changed",
+        }
+    }
+
+    /// The operation the change is to, if it is to an operation.
+    pub fn operation(&self) -> Option<&str> {
+        match *self {
+            Change::OperationAdded(ref operation) |
+            Change::OperationRemoved(ref operation) |
+            Change::HttpChanged { ref operation, .. } |
+            Change::InputChanged { ref operation, .. } |
+            Change::OutputChanged { ref operation, .. } |
+            Change::ErrorAdded { ref operation, .. } |
+            Change::ErrorRemoved { ref operation, .. } => Some(operation),
+            _ => None,
+        }
+    }
+
+    /// The shape the change is to, if it is to a shape.
+    pub fn shape(&self) -> Option<&str> {
+        match *self {
+            Change::ShapeAdded(ref shape) |
+            Change::ShapeRemoved(ref shape) |
+            Change::TypeChanged { ref shape, .. } |
+            Change::MemberAdded { ref shape, .. } |
+            Change::MemberRemoved { ref shape, .. } |
+            Change::MemberShapeChanged { ref shape, .. } |
+            Change::RequiredChanged { ref shape, .. } |
+            Change::LocationChanged { ref shape, .. } |
+            Change::EnumValueAdded { ref shape, .. } |
+            Change::EnumValueRemoved { ref shape, .. } |
+            Change::ConstraintChanged { ref shape, .. } |
+            Change::StatusCodeChanged { ref shape, .. } => Some(shape),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let or_none = |value: &Option<String>| value.clone().unwrap_or("none".to_string());
+        match *self {
+            Change::OperationAdded(ref operation) => write!(f, "operation {} added", operation),
+            Change::OperationRemoved(ref operation) => write!(f, "operation {} removed", operation),
+            Change::HttpChanged { ref operation, ref old, ref new } => {
+                write!(f, "operation {}: HTTP binding {} -> {}", operation, old, new)
+            }
+            Change::InputChanged { ref operation, ref old, ref new } => {
+                write!(f, "operation {}: input {} -> {}", operation, old, new)
+            }
+            Change::OutputChanged { ref operation, ref old, ref new } => {
+                write!(f, "operation {}: output {} -> {}", operation, or_none(old), or_none(new))
+            }
+            Change::ErrorAdded { ref operation, ref error } => write!(f, "operation {}: error {} added", operation, error),
+            Change::ErrorRemoved { ref operation, ref error } => {
+                write!(f, "operation {}: error {} removed", operation, error)
+            }
+            Change::ShapeAdded(ref shape) => write!(f, "shape {} added", shape),
+            Change::ShapeRemoved(ref shape) => write!(f, "shape {} removed", shape),
+            Change::TypeChanged { ref shape, ref old, ref new } => write!(f, "shape {}: {} -> {}", shape, old, new),
+            Change::MemberAdded { ref shape, ref member, required } => {
+                write!(f, "shape {}: {} member {} added", shape, if required { "required" } else { "optional" }, member)
+            }
+            Change::MemberRemoved { ref shape, ref member } => write!(f, "shape {}: member {} removed", shape, member),
+            Change::MemberShapeChanged { ref shape, ref member, ref old, ref new } => {
+                write!(f, "shape {}: member {} {} -> {}", shape, member, old, new)
+            }
+            Change::RequiredChanged { ref shape, ref member, required } => {
+                write!(f, "shape {}: member {} is {} required", shape, member, if required { "now" } else { "no longer" })
+            }
+            Change::LocationChanged { ref shape, ref member, ref old, ref new } => {
+                write!(f, "shape {}: member {} moved from {} to {}", shape, member, old, new)
+            }
+            Change::EnumValueAdded { ref shape, ref value } => write!(f, "shape {}: value {:?} added", shape, value),
+            Change::EnumValueRemoved { ref shape, ref value } => write!(f, "shape {}: value {:?} removed", shape, value),
+            Change::ConstraintChanged { ref shape, constraint, ref old, ref new } => {
+                write!(f, "shape {}: {} {} -> {}", shape, constraint, or_none(old), or_none(new))
+            }
+            Change::StatusCodeChanged { ref shape, old, new } => {
+                write!(f, "shape {}: HTTP status code {} -> {}", shape, old, new)
+            }
+        }
+    }
+}
+
+/// How the operations and shapes of two versions of a service differ.
+#[derive(Debug, Default, PartialEq)]
+pub struct Diff {
+    pub operations: Changes,
+    pub shapes: Changes,
+    /// Every difference: the operations' in name order, then the shapes'.
+    pub changes: Vec<Change>,
+}
+
+impl Diff {
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty() && self.shapes.is_empty()
+    }
+}
+
+/// The report `diff` prints: one change per line.
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "no differences");
+        }
+        for change in &self.changes {
+            try!(writeln!(f, "{}", change));
+        }
+        Ok(())
+    }
+}
+
+impl ServiceDefinition {
+    /// Compares this service with a newer version of it. An operation has changed when
+    /// its HTTP binding or its input, output or error shapes differ, and a shape when
+    /// its type, members, enum values or constraints do. Documentation is ignored.
+    pub fn diff(&self, new: &ServiceDefinition) -> Diff {
+        let mut diff = Diff::default();
+        compare(self.operations.iter().map(|o| (&o.name[..], o)).collect(),
+                new.operations.iter().map(|o| (&o.name[..], o)).collect(),
+                &mut diff.operations, &mut diff.changes,
+                Change::OperationAdded, Change::OperationRemoved, operation_changes);
+        compare(self.shapes.iter().map(|s| (&s.name[..], &s.shape_type)).collect(),
+                new.shapes.iter().map(|s| (&s.name[..], &s.shape_type)).collect(),
+                &mut diff.shapes, &mut diff.changes,
+                Change::ShapeAdded, Change::ShapeRemoved, shape_changes);
+        diff
+    }
+}
+
+fn compare<T, F>(old: BTreeMap<&str, &T>, new: BTreeMap<&str, &T>, names: &mut Changes, changes: &mut Vec<Change>,
+                 added: fn(String) -> Change, removed: fn(String) -> Change, compare: F)
+    where F: Fn(&str, &T, &T, &mut Vec<Change>)
+{
+    for (name, old_item) in &old {
+        match new.get(name) {
+            Some(new_item) => {
+                let before = changes.len();
+                compare(name, old_item, new_item, changes);
+                if changes.len() > before {
+                    names.changed.push(name.to_string());
+                }
+            }
+            None => {
+                names.removed.push(name.to_string());
+                changes.push(removed(name.to_string()));
+            }
+        }
+    }
+    for name in new.keys().filter(|name| !old.contains_key(*name)) {
+        names.added.push(name.to_string());
+        changes.push(added(name.to_string()));
+    }
+}
+
+fn operation_changes(name: &str, old: &Operation, new: &Operation, changes: &mut Vec<Change>) {
+    let http = |operation: &Operation| format!("{} {}", operation.http.method, operation.http.requestUri);
+    if http(old) != http(new) {
+        changes.push(Change::HttpChanged { operation: name.to_string(), old: http(old), new: http(new) });
+    }
+    if old.input.shape != new.input.shape {
+        changes.push(Change::InputChanged {
+            operation: name.to_string(),
+            old: old.input.shape.clone(),
+            new: new.input.shape.clone(),
+        });
+    }
+    let output = |operation: &Operation| operation.output.as_ref().map(|output| output.shape.clone());
+    if output(old) != output(new) {
+        changes.push(Change::OutputChanged { operation: name.to_string(), old: output(old), new: output(new) });
+    }
+    let errors = |operation: &Operation| -> Vec<String> {
+        let mut errors: Vec<String> = operation.errors.iter().map(|e| e.shape.clone()).collect();
+        errors.sort();
+        errors
+    };
+    let (old_errors, new_errors) = (errors(old), errors(new));
+    for error in old_errors.iter().filter(|error| !new_errors.contains(error)) {
+        changes.push(Change::ErrorRemoved { operation: name.to_string(), error: error.clone() });
+    }
+    for error in new_errors.iter().filter(|error| !old_errors.contains(error)) {
+        changes.push(Change::ErrorAdded { operation: name.to_string(), error: error.clone() });
+    }
+}
+
+/// `ShapeType::kind()`, telling streaming blobs apart.
+fn describe(shape_type: &ShapeType) -> &'static str {
+    match *shape_type {
+        ShapeType::Blob(Blob(true)) => "streaming blob",
+        _ => shape_type.kind(),
+    }
+}
+
+fn shape_changes(name: &str, old: &ShapeType, new: &ShapeType, changes: &mut Vec<Change>) {
+    if describe(old) != describe(new) {
+        changes.push(Change::TypeChanged {
+            shape: name.to_string(),
+            old: describe(old).to_string(),
+            new: describe(new).to_string(),
+        });
+        return;
+    }
+    let number = |n: Option<i64>| n.map(|n| n.to_string());
+    match (old, new) {
+        (&ShapeType::Integer(ref old), &ShapeType::Integer(ref new)) => {
+            constraint(changes, name, "min", number(old.min), number(new.min));
+            constraint(changes, name, "max", number(old.max), number(new.max));
+        }
+        (&ShapeType::StringPattern(ref old), &ShapeType::StringPattern(ref new)) => {
+            constraint(changes, name, "min", number(old.min), number(new.min));
+            constraint(changes, name, "max", number(old.max), number(new.max));
+            constraint(changes, name, "pattern", Some(old.pattern.clone()), Some(new.pattern.clone()));
+        }
+        (&ShapeType::List(ref old), &ShapeType::List(ref new)) => {
+            if old.member != new.member {
+                changes.push(Change::MemberShapeChanged {
+                    shape: name.to_string(),
+                    member: "member".to_string(),
+                    old: old.member.clone(),
+                    new: new.member.clone(),
+                });
+            }
+            constraint(changes, name, "min", number(old.min), number(new.min));
+            constraint(changes, name, "max", number(old.max), number(new.max));
+        }
+        (&ShapeType::StringEnum(StringEnum(ref old)), &ShapeType::StringEnum(StringEnum(ref new))) => {
+            for value in old.iter().filter(|value| !new.contains(value)) {
+                changes.push(Change::EnumValueRemoved { shape: name.to_string(), value: value.clone() });
+            }
+            for value in new.iter().filter(|value| !old.contains(value)) {
+                changes.push(Change::EnumValueAdded { shape: name.to_string(), value: value.clone() });
+            }
+        }
+        (&ShapeType::Structure(Structure(ref old)), &ShapeType::Structure(Structure(ref new))) => {
+            member_changes(name, old, new, changes)
+        }
+        (&ShapeType::Exception(ref old), &ShapeType::Exception(ref new)) => {
+            if old.status_code != new.status_code {
+                changes.push(Change::StatusCodeChanged {
+                    shape: name.to_string(),
+                    old: old.status_code,
+                    new: new.status_code,
+                });
+            }
+            member_changes(name, &old.members, &new.members, changes)
+        }
+        _ => {}
+    }
+}
+
+fn constraint(changes: &mut Vec<Change>, shape: &str, constraint: &'static str, old: Option<String>, new: Option<String>) {
+    if old != new {
+        changes.push(Change::ConstraintChanged { shape: shape.to_string(), constraint: constraint, old: old, new: new });
+    }
+}
+
+fn member_changes(name: &str, old: &[Member], new: &[Member], changes: &mut Vec<Change>) {
+    for old_member in old {
+        let new_member = match new.iter().find(|m| m.name == old_member.name) {
+            Some(new_member) => new_member,
+            None => {
+                changes.push(Change::MemberRemoved { shape: name.to_string(), member: old_member.name.clone() });
+                continue;
+            }
+        };
+        if old_member.shape != new_member.shape {
+            changes.push(Change::MemberShapeChanged {
+                shape: name.to_string(),
+                member: old_member.name.clone(),
+                old: old_member.shape.clone(),
+                new: new_member.shape.clone(),
+            });
+        }
+        if old_member.required != new_member.required {
+            changes.push(Change::RequiredChanged {
+                shape: name.to_string(),
+                member: old_member.name.clone(),
+                required: new_member.required,
+            });
+        }
+        if old_member.location != new_member.location {
+            changes.push(Change::LocationChanged {
+                shape: name.to_string(),
+                member: old_member.name.clone(),
+                old: location(&old_member.location),
+                new: location(&new_member.location),
+            });
+        }
+    }
+    for member in new.iter().filter(|m| !old.iter().any(|o| o.name == m.name)) {
+        changes.push(Change::MemberAdded { shape: name.to_string(), member: member.name.clone(), required: member.required });
+    }
+}
+
+/// A location as botocore writes it, with its `locationName`: `header:X-Amz-Date`.
+fn location(location: &Location) -> String {
+    match *location {
+        Location::Body => "body".to_string(),
+        Location::Payload => "payload".to_string(),
+        Location::StatusCode => "statusCode".to_string(),
+        Location::URI(ref name) => format!("uri:{}", name),
+        Location::QueryString(ref name) => format!("querystring:{}", name),
+        Location::Header(ref name) => format!("header:{}", name),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::parser::*;
+    use ::testhelpers::fixture_service;
+
+    #[test]
+    fn same_service() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        assert!(service.diff(&fixture_service("services/lambda-2015-03-31")).is_empty());
+    }
+
+    #[test]
+    fn added_removed_changed() {
+        let old = fixture_service("services/lambda-2015-03-31");
+        let mut new = fixture_service("services/lambda-2015-03-31");
+        new.operations.retain(|operation| operation.name != "DeleteAlias");
+        new.operations.iter_mut().find(|operation| operation.name == "GetPolicy").unwrap().http.method = "POST".to_string();
+        new.shapes.push(Shape {
+            name: "Architecture".to_string(),
+            shape_type: ShapeType::StringEnum(StringEnum(vec!("x86_64".to_string(), "arm64".to_string()))),
+            deprecated: None,
+        });
+        if let ShapeType::Integer(ref mut integer) = new.shapes.iter_mut().find(|s| s.name == "MemorySize").unwrap().shape_type {
+            integer.max = Some(3008);
+        }
+        let diff = old.diff(&new);
+        assert_eq!(diff.operations, Changes {
+            added: vec!(),
+            removed: vec!("DeleteAlias".to_string()),
+            changed: vec!("GetPolicy".to_string()),
+        });
+        assert_eq!(diff.shapes, Changes {
+            added: vec!("Architecture".to_string()),
+            removed: vec!(),
+            changed: vec!("MemorySize".to_string()),
+        });
+        assert_eq!(diff.to_string(), "\
+operation DeleteAlias removed
+operation GetPolicy: HTTP binding GET /2015-03-31/functions/{FunctionName}/policy -> POST /2015-03-31/functions/{FunctionName}/policy
+shape MemorySize: max 1536 -> 3008
+shape Architecture added
+");
+    }
+
+    #[test]
+    fn details() {
+        let old = fixture_service("services/lambda-2015-03-31");
+        let mut new = fixture_service("services/lambda-2015-03-31");
+        for shape in new.shapes.iter_mut() {
+            match (&shape.name[..], &mut shape.shape_type) {
+                ("Runtime", &mut ShapeType::StringEnum(StringEnum(ref mut values))) => {
+                    values.retain(|value| value != "nodejs");
+                    values.push("nodejs4.3".to_string());
+                }
+                ("FunctionName", &mut ShapeType::StringPattern(ref mut string)) => string.max = Some(170),
+                ("UpdateFunctionCodeRequest", &mut ShapeType::Structure(Structure(ref mut members))) => {
+                    members.retain(|member| member.name != "S3ObjectVersion");
+                    let publish = members.iter_mut().find(|member| member.name == "Publish").unwrap();
+                    publish.required = true;
+                    publish.location = Location::QueryString("publish".to_string());
+                    publish.documentation = None;
+                }
+                ("TooManyRequestsException", &mut ShapeType::Exception(ref mut exception)) => exception.status_code = 503,
+                _ => {}
+            }
+        }
+        new.operations.iter_mut().find(|operation| operation.name == "Invoke").unwrap().errors.pop();
+        let diff = old.diff(&new);
+        assert_eq!(diff.shapes.changed, vec!("FunctionName", "Runtime", "TooManyRequestsException",
+                                             "UpdateFunctionCodeRequest"));
+        let changes: Vec<String> = diff.changes.iter().map(|change| change.to_string()).collect();
+        assert_eq!(changes, vec!(
+            "operation Invoke: error InvalidParameterValueException removed",
+            "shape FunctionName: max 140 -> 170",
+            "shape Runtime: value \"nodejs\" removed",
+            "shape Runtime: value \"nodejs4.3\" added",
+            "shape TooManyRequestsException: HTTP status code 429 -> 503",
+            "shape UpdateFunctionCodeRequest: member Publish is now required",
+            "shape UpdateFunctionCodeRequest: member Publish moved from body to querystring:publish",
+            "shape UpdateFunctionCodeRequest: member S3ObjectVersion removed",
+        ));
+        assert_eq!(diff.changes[2].kind(), "enum-value-removed");
+        assert_eq!(diff.changes[2].shape(), Some("Runtime"));
+        assert_eq!(diff.changes[0].operation(), Some("Invoke"));
+        assert_eq!(diff.changes[4].kind(), "status-code-changed");
+    }
+}
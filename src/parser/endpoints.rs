@@ -0,0 +1,446 @@
+extern crate serde;
+extern crate serde_json;
+
+use std::io::Read;
+use std::collections::BTreeMap;
+use serde_json::Value;
+use super::error::ParseError;
+
+/// The botocore `endpoints.json` partition file.
+#[derive(Debug, PartialEq)]
+pub struct Endpoints {
+    pub partitions: Vec<Partition>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Partition {
+    pub name: String,
+    pub dns_suffix: String,
+    pub region_regex: String,
+    pub defaults: EndpointProperties,
+    pub regions: Vec<String>,
+    pub services: BTreeMap<String, ServiceEndpoints>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ServiceEndpoints {
+    pub defaults: EndpointProperties,
+    pub endpoints: BTreeMap<String, EndpointProperties>,
+    pub partition_endpoint: Option<String>,
+    pub is_regionalized: bool,
+}
+
+/// Properties shared by partition defaults, service defaults and single endpoints.
+/// Anything left as `None` (or empty) is inherited from the enclosing level.
+#[derive(Debug, PartialEq, Default)]
+pub struct EndpointProperties {
+    pub hostname: Option<String>,
+    pub protocols: Vec<String>,
+    pub signature_versions: Vec<String>,
+    pub credential_scope: Option<CredentialScope>,
+    pub variants: Vec<EndpointVariant>,
+    pub deprecated: bool,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct CredentialScope {
+    pub region: Option<String>,
+    pub service: Option<String>,
+}
+
+/// An alternative hostname selected by tags such as `fips` or `dualstack`.
+#[derive(Debug, PartialEq)]
+pub struct EndpointVariant {
+    pub hostname: Option<String>,
+    pub dns_suffix: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// The outcome of resolving a service in a region.
+#[derive(Debug, PartialEq)]
+pub struct ResolvedEndpoint {
+    pub partition: String,
+    pub hostname: String,
+    pub protocol: String,
+    pub signing_region: String,
+    pub signing_name: Option<String>,
+}
+
+impl ResolvedEndpoint {
+    pub fn url(&self) -> String {
+        format!("{}://{}", self.protocol, self.hostname)
+    }
+}
+
+impl Endpoints {
+    pub fn parse<R: Read>(fd: R) -> Result<Endpoints, ParseError> {
+        let json: Value = match serde_json::from_reader(fd) {
+            Ok(json) => json,
+            Err(_) => return Err(ParseError::SerdeError),
+        };
+        let obj = try!(json.as_object().ok_or(ParseError::ExpectedObject));
+        let raw_partitions = try!(obj.get("partitions").and_then(|p| p.as_array())
+            .ok_or(ParseError::InvalidEndpoints("partitions".to_string())));
+        let mut partitions = vec!();
+        for raw in raw_partitions {
+            partitions.push(try!(Partition::parse(raw)));
+        }
+        Ok(Endpoints { partitions: partitions })
+    }
+
+    /// Finds the partition for `region`: one that lists it explicitly, or else the one
+    /// whose `regionRegex` prefix matches it most specifically.
+    pub fn partition_for(&self, region: &str) -> Option<&Partition> {
+        if let Some(partition) = self.partitions.iter().find(|p| p.regions.iter().any(|r| r == region)) {
+            return Some(partition);
+        }
+        let mut best: Option<(&Partition, usize)> = None;
+        for partition in &self.partitions {
+            for prefix in partition.region_prefixes() {
+                let longer = best.map(|(_, len)| prefix.len() > len).unwrap_or(true);
+                if region.starts_with(&prefix[..]) && longer {
+                    best = Some((partition, prefix.len()));
+                }
+            }
+        }
+        best.map(|(partition, _)| partition)
+    }
+
+    /// Resolves `service` (an `endpointPrefix`) in `region`. `tags` selects a
+    /// variant, e.g. `&["fips"]` or `&["dualstack"]`; pass `&[]` for the standard endpoint.
+    pub fn resolve(&self, service: &str, region: &str, tags: &[&str]) -> Option<ResolvedEndpoint> {
+        self.partition_for(region).and_then(|partition| partition.resolve(service, region, tags))
+    }
+}
+
+impl Partition {
+    fn parse(json: &Value) -> Result<Partition, ParseError> {
+        let obj = try!(json.as_object().ok_or(ParseError::InvalidEndpoints("partition".to_string())));
+        let name = try!(required_string(obj, "partition"));
+        let dns_suffix = try!(required_string(obj, "dnsSuffix"));
+        let region_regex = try!(optional_string(obj, "regionRegex")).unwrap_or(String::new());
+        let defaults = match obj.get("defaults") {
+            Some(json) => try!(EndpointProperties::parse(json)),
+            None => EndpointProperties::default(),
+        };
+        let regions = match obj.get("regions") {
+            Some(json) => {
+                let regions = try!(json.as_object().ok_or(ParseError::InvalidEndpoints(format!("{}.regions", name))));
+                regions.keys().cloned().collect()
+            }
+            None => vec!(),
+        };
+        let mut services = BTreeMap::new();
+        if let Some(json) = obj.get("services") {
+            let raw_services = try!(json.as_object().ok_or(ParseError::InvalidEndpoints(format!("{}.services", name))));
+            for (service, raw) in raw_services.iter() {
+                services.insert(service.to_string(), try!(ServiceEndpoints::parse(service, raw)));
+            }
+        }
+        Ok(Partition {
+            name: name,
+            dns_suffix: dns_suffix,
+            region_regex: region_regex,
+            defaults: defaults,
+            regions: regions,
+            services: services,
+        })
+    }
+
+    /// Region prefixes accepted by `regionRegex`. Botocore's regexes all look like
+    /// `^(us|eu)\-\w+\-\d+$` or `^us\-gov\-\w+\-\d+$`, so rather than pulling in a regex
+    /// engine we expand the literal part before `\w` into its alternatives.
+    pub fn region_prefixes(&self) -> Vec<String> {
+        let literal = self.region_regex.trim_left_matches('^');
+        let literal = match literal.find("\\w") {
+            Some(i) => &literal[..i],
+            None => return vec!(),
+        };
+        let literal = literal.replace("\\-", "-");
+        match (literal.find('('), literal.find(')')) {
+            (Some(open), Some(close)) if open < close => {
+                let (head, tail) = (&literal[..open], &literal[close + 1..]);
+                literal[open + 1..close].split('|')
+                    .map(|alt| format!("{}{}{}", head, alt, tail))
+                    .collect()
+            }
+            _ => vec!(literal.to_string()),
+        }
+    }
+
+    pub fn resolve(&self, service: &str, region: &str, tags: &[&str]) -> Option<ResolvedEndpoint> {
+        let empty = ServiceEndpoints::default();
+        let service_endpoints = self.services.get(service).unwrap_or(&empty);
+
+        // Global services resolve every region to their partition endpoint.
+        let endpoint_name = match service_endpoints.partition_endpoint {
+            Some(ref global) if !service_endpoints.is_regionalized => &global[..],
+            _ => region,
+        };
+        let default_endpoint = EndpointProperties::default();
+        let endpoint = service_endpoints.endpoints.get(endpoint_name).unwrap_or(&default_endpoint);
+
+        // Most specific first: endpoint, service defaults, partition defaults.
+        let levels = [endpoint, &service_endpoints.defaults, &self.defaults];
+
+        let mut dns_suffix = self.dns_suffix.clone();
+        let hostname = if tags.is_empty() {
+            levels.iter().filter_map(|l| l.hostname.as_ref()).next()
+        } else {
+            let variant = levels.iter().filter_map(|l| l.variant(tags)).next();
+            if let Some(suffix) = variant.and_then(|v| v.dns_suffix.as_ref()) {
+                dns_suffix = suffix.clone();
+            }
+            match variant.and_then(|v| v.hostname.as_ref()) {
+                Some(hostname) => Some(hostname),
+                None => return None,
+            }
+        };
+        let hostname = match hostname {
+            Some(hostname) => hostname,
+            None => return None,
+        };
+        let protocol = levels.iter()
+            .filter_map(|l| l.protocols.iter().find(|p| *p == "https").or(l.protocols.first()))
+            .next()
+            .map(|p| p.to_string())
+            .unwrap_or("https".to_string());
+        // An endpoint's scope may name only the region, leaving the service to the defaults.
+        let scopes: Vec<&CredentialScope> = levels.iter().filter_map(|l| l.credential_scope.as_ref()).collect();
+
+        Some(ResolvedEndpoint {
+            partition: self.name.clone(),
+            hostname: expand_hostname(hostname, service, endpoint_name, &dns_suffix),
+            protocol: protocol,
+            signing_region: scopes.iter().filter_map(|s| s.region.clone()).next().unwrap_or(region.to_string()),
+            signing_name: scopes.iter().filter_map(|s| s.service.clone()).next(),
+        })
+    }
+}
+
+/// Fills in a botocore hostname template such as `{service}.{region}.{dnsSuffix}`.
+pub fn expand_hostname(template: &str, service: &str, region: &str, dns_suffix: &str) -> String {
+    template.replace("{service}", service)
+        .replace("{region}", region)
+        .replace("{dnsSuffix}", dns_suffix)
+}
+
+impl ServiceEndpoints {
+    fn parse(service: &str, json: &Value) -> Result<ServiceEndpoints, ParseError> {
+        let obj = try!(json.as_object().ok_or(ParseError::InvalidEndpoints(service.to_string())));
+        let defaults = match obj.get("defaults") {
+            Some(json) => try!(EndpointProperties::parse(json)),
+            None => EndpointProperties::default(),
+        };
+        let mut endpoints = BTreeMap::new();
+        if let Some(json) = obj.get("endpoints") {
+            let raw = try!(json.as_object().ok_or(ParseError::InvalidEndpoints(format!("{}.endpoints", service))));
+            for (name, endpoint) in raw.iter() {
+                endpoints.insert(name.to_string(), try!(EndpointProperties::parse(endpoint)));
+            }
+        }
+        let is_regionalized = match obj.get("isRegionalized") {
+            Some(json) => try!(json.as_boolean().ok_or(ParseError::InvalidEndpoints(format!("{}.isRegionalized", service)))),
+            None => true,
+        };
+        Ok(ServiceEndpoints {
+            defaults: defaults,
+            endpoints: endpoints,
+            partition_endpoint: try!(optional_string(obj, "partitionEndpoint")),
+            is_regionalized: is_regionalized,
+        })
+    }
+}
+
+impl Default for ServiceEndpoints {
+    fn default() -> ServiceEndpoints {
+        ServiceEndpoints {
+            defaults: EndpointProperties::default(),
+            endpoints: BTreeMap::new(),
+            partition_endpoint: None,
+            is_regionalized: true,
+        }
+    }
+}
+
+impl EndpointProperties {
+    fn parse(json: &Value) -> Result<EndpointProperties, ParseError> {
+        let obj = try!(json.as_object().ok_or(ParseError::InvalidEndpoints("endpoint".to_string())));
+        let credential_scope = match obj.get("credentialScope") {
+            Some(json) => {
+                let scope = try!(json.as_object().ok_or(ParseError::InvalidEndpoints("credentialScope".to_string())));
+                Some(CredentialScope {
+                    region: try!(optional_string(scope, "region")),
+                    service: try!(optional_string(scope, "service")),
+                })
+            }
+            None => None,
+        };
+        let mut variants = vec!();
+        if let Some(json) = obj.get("variants") {
+            let raw = try!(json.as_array().ok_or(ParseError::InvalidEndpoints("variants".to_string())));
+            for variant in raw {
+                let variant = try!(variant.as_object().ok_or(ParseError::InvalidEndpoints("variants".to_string())));
+                variants.push(EndpointVariant {
+                    hostname: try!(optional_string(variant, "hostname")),
+                    dns_suffix: try!(optional_string(variant, "dnsSuffix")),
+                    tags: try!(string_list(variant, "tags")),
+                });
+            }
+        }
+        let deprecated = match obj.get("deprecated") {
+            Some(json) => try!(json.as_boolean().ok_or(ParseError::InvalidEndpoints("deprecated".to_string()))),
+            None => false,
+        };
+        Ok(EndpointProperties {
+            hostname: try!(optional_string(obj, "hostname")),
+            protocols: try!(string_list(obj, "protocols")),
+            signature_versions: try!(string_list(obj, "signatureVersions")),
+            credential_scope: credential_scope,
+            variants: variants,
+            deprecated: deprecated,
+        })
+    }
+
+    /// The variant whose tags are exactly `tags` (in any order).
+    pub fn variant(&self, tags: &[&str]) -> Option<&EndpointVariant> {
+        self.variants.iter().find(|v| {
+            v.tags.len() == tags.len() && tags.iter().all(|t| v.tags.iter().any(|vt| vt == t))
+        })
+    }
+}
+
+fn optional_string(obj: &BTreeMap<String, Value>, key: &str) -> Result<Option<String>, ParseError> {
+    match obj.get(key) {
+        Some(json) => {
+            let s = try!(json.as_string().ok_or(ParseError::InvalidEndpoints(key.to_string())));
+            Ok(Some(s.to_string()))
+        }
+        None => Ok(None),
+    }
+}
+
+fn required_string(obj: &BTreeMap<String, Value>, key: &str) -> Result<String, ParseError> {
+    let s = try!(optional_string(obj, key));
+    s.ok_or(ParseError::InvalidEndpoints(key.to_string()))
+}
+
+fn string_list(obj: &BTreeMap<String, Value>, key: &str) -> Result<Vec<String>, ParseError> {
+    let mut list = vec!();
+    if let Some(json) = obj.get(key) {
+        let array = try!(json.as_array().ok_or(ParseError::InvalidEndpoints(key.to_string())));
+        for item in array {
+            let s = try!(item.as_string().ok_or(ParseError::InvalidEndpoints(key.to_string())));
+            list.push(s.to_string());
+        }
+    }
+    Ok(list)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::testhelpers::fixture_reader;
+
+    fn endpoints() -> Endpoints {
+        Endpoints::parse(fixture_reader("endpoints/endpoints")).unwrap()
+    }
+
+    #[test]
+    fn partitions() {
+        let endpoints = endpoints();
+        let names: Vec<&str> = endpoints.partitions.iter().map(|p| &p.name[..]).collect();
+        assert_eq!(names, vec!("aws", "aws-cn", "aws-us-gov"));
+        assert_eq!(endpoints.partitions[0].regions, vec!("eu-west-1", "us-east-1", "us-west-2"));
+    }
+
+    #[test]
+    fn region_prefixes() {
+        let endpoints = endpoints();
+        assert_eq!(endpoints.partitions[0].region_prefixes(),
+                   vec!("us-", "eu-", "ap-", "sa-", "ca-", "me-", "af-"));
+        assert_eq!(endpoints.partitions[2].region_prefixes(), vec!("us-gov-"));
+    }
+
+    #[test]
+    fn partition_for_unlisted_region() {
+        let endpoints = endpoints();
+        assert_eq!(endpoints.partition_for("ap-southeast-2").unwrap().name, "aws");
+        assert_eq!(endpoints.partition_for("cn-northwest-1").unwrap().name, "aws-cn");
+        assert_eq!(endpoints.partition_for("us-gov-east-1").unwrap().name, "aws-us-gov");
+        assert!(endpoints.partition_for("mars-north-1").is_none());
+    }
+
+    #[test]
+    fn regional_endpoint() {
+        let resolved = endpoints().resolve("lambda", "us-west-2", &[]).unwrap();
+        assert_eq!(resolved, ResolvedEndpoint {
+            partition: "aws".to_string(),
+            hostname: "lambda.us-west-2.amazonaws.com".to_string(),
+            protocol: "https".to_string(),
+            signing_region: "us-west-2".to_string(),
+            signing_name: None,
+        });
+        assert_eq!(resolved.url(), "https://lambda.us-west-2.amazonaws.com");
+    }
+
+    #[test]
+    fn china_dns_suffix() {
+        let resolved = endpoints().resolve("lambda", "cn-north-1", &[]).unwrap();
+        assert_eq!(resolved.hostname, "lambda.cn-north-1.amazonaws.com.cn");
+    }
+
+    #[test]
+    fn global_endpoint() {
+        let resolved = endpoints().resolve("iam", "eu-west-1", &[]).unwrap();
+        assert_eq!(resolved.hostname, "iam.amazonaws.com");
+        assert_eq!(resolved.signing_region, "us-east-1");
+    }
+
+    #[test]
+    fn credential_scope_merges_levels() {
+        let resolved = endpoints().partitions[0].resolve("iot", "fips-us-east-1", &[]).unwrap();
+        assert_eq!(resolved.signing_region, "us-east-1");
+        assert_eq!(resolved.signing_name, Some("execute-api".to_string()));
+        let resolved = endpoints().resolve("iot", "cn-north-1", &[]).unwrap();
+        assert_eq!(resolved.signing_name, None);
+    }
+
+    #[test]
+    fn fips_variant_from_partition_defaults() {
+        let resolved = endpoints().resolve("lambda", "us-west-2", &["fips"]).unwrap();
+        assert_eq!(resolved.hostname, "lambda-fips.us-west-2.amazonaws.com");
+    }
+
+    #[test]
+    fn fips_variant_from_service_defaults() {
+        let resolved = endpoints().resolve("lambda", "us-gov-west-1", &["fips"]).unwrap();
+        assert_eq!(resolved.hostname, "lambda-fips.us-gov-west-1.amazonaws.com");
+    }
+
+    #[test]
+    fn dualstack_variant() {
+        let endpoints = endpoints();
+        let resolved = endpoints.resolve("lambda", "us-east-1", &["dualstack"]).unwrap();
+        assert_eq!(resolved.hostname, "lambda.us-east-1.api.aws");
+        let resolved = endpoints.resolve("lambda", "eu-west-1", &["dualstack"]).unwrap();
+        assert_eq!(resolved.hostname, "lambda.eu-west-1.api.aws");
+    }
+
+    #[test]
+    fn missing_variant() {
+        assert!(endpoints().resolve("lambda", "cn-north-1", &["fips"]).is_none());
+    }
+
+    #[test]
+    fn credential_scope() {
+        let endpoints = endpoints();
+        let lambda = endpoints.partitions[0].services.get("lambda").unwrap();
+        let fips = lambda.endpoints.get("fips-us-east-1").unwrap();
+        assert!(fips.deprecated);
+        assert_eq!(fips.credential_scope, Some(CredentialScope {
+            region: Some("us-east-1".to_string()),
+            service: None,
+        }));
+    }
+}
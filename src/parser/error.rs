@@ -1,3 +1,5 @@
+use std::fmt;
+
 #[derive(Debug, PartialEq)]
 pub enum ParseError {
     ExpectedObject,
@@ -10,6 +12,8 @@ pub enum ParseError {
     InvalidListShape,
     MissingListMember,
     InvalidListMember,
+    InvalidListMin,
+    InvalidListMax,
     InvalidMember(String),
     InvalidRequired,
     MissingErrorInException,
@@ -20,7 +24,61 @@ pub enum ParseError {
     InvalidStringPattern,
     InvalidStringEnum,
     InvalidStringVariant,
+    InvalidDeprecated,
+    InvalidStreaming,
     ServiceDefinitionMissingShapes,
     ServiceDefinitionInvalidShapes,
+    InvalidEndpoints(String),
+    InvalidPath(String),
+    InvalidPaginator(String),
+    InvalidWaiter(String),
+    InvalidExample(String),
+    InvalidPayload(String),
+    UnknownOperation(String),
+    InvalidSmithy(String),
+    InvalidOverlay(String),
     SerdeError,
 }
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::ExpectedObject => write!(f, "expected a JSON object"),
+            ParseError::TypeStringMissing => write!(f, "a shape has no type"),
+            ParseError::NotImplemented => write!(f, "not implemented"),
+            ParseError::InvalidTypeString => write!(f, "a shape has an unknown type"),
+            ParseError::StructureHasNoMembers => write!(f, "a structure has no members"),
+            ParseError::InvalidStructureMembers => write!(f, "a structure's members are not an object"),
+            ParseError::MissingListShape => write!(f, "a list member has no shape"),
+            ParseError::InvalidListShape => write!(f, "a list member's shape is not a string"),
+            ParseError::MissingListMember => write!(f, "a list has no member"),
+            ParseError::InvalidListMember => write!(f, "a list's member is not an object"),
+            ParseError::InvalidListMin => write!(f, "a list's min is not an integer"),
+            ParseError::InvalidListMax => write!(f, "a list's max is not an integer"),
+            ParseError::InvalidMember(ref member) => write!(f, "member {} is invalid", member),
+            ParseError::InvalidRequired => write!(f, "a structure's required members are not a list of names"),
+            ParseError::MissingErrorInException => write!(f, "an exception has no error"),
+            ParseError::InvalidMaxInteger => write!(f, "an integer's max is not an integer"),
+            ParseError::InvalidMinInteger => write!(f, "an integer's min is not an integer"),
+            ParseError::InvalidStringMax => write!(f, "a string's max is not an integer"),
+            ParseError::InvalidStringMin => write!(f, "a string's min is not an integer"),
+            ParseError::InvalidStringPattern => write!(f, "a string's pattern is not a string"),
+            ParseError::InvalidStringEnum => write!(f, "a string's enum is not a list"),
+            ParseError::InvalidStringVariant => write!(f, "a string enum value is not a string"),
+            ParseError::InvalidDeprecated => write!(f, "a deprecated flag or message is invalid"),
+            ParseError::InvalidStreaming => write!(f, "a blob's streaming flag is not a boolean"),
+            ParseError::ServiceDefinitionMissingShapes => write!(f, "the service definition has no shapes"),
+            ParseError::ServiceDefinitionInvalidShapes => write!(f, "the service definition's shapes are not an object"),
+            ParseError::InvalidEndpoints(ref message) => write!(f, "invalid endpoints: {}", message),
+            ParseError::InvalidPath(ref message) => write!(f, "invalid path: {}", message),
+            ParseError::InvalidPaginator(ref message) => write!(f, "invalid paginator: {}", message),
+            ParseError::InvalidWaiter(ref message) => write!(f, "invalid waiter: {}", message),
+            ParseError::InvalidExample(ref message) => write!(f, "invalid example: {}", message),
+            ParseError::InvalidPayload(ref message) => write!(f, "invalid payload: {}", message),
+            ParseError::UnknownOperation(ref operation) => write!(f, "unknown operation {}", operation),
+            ParseError::InvalidSmithy(ref message) => write!(f, "invalid Smithy model: {}", message),
+            ParseError::InvalidOverlay(ref message) => write!(f, "invalid overlay: {}", message),
+            ParseError::SerdeError => write!(f, "not a valid service definition"),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+extern crate serde;
+extern crate serde_json;
+
+use std::io::Read;
+use std::collections::BTreeMap;
+use serde_json::Value;
+use super::error::ParseError;
+use super::service_definition::ServiceDefinition;
+use super::shape_type::*;
+
+/// The contents of an `examples-1.json` companion file, or the `examples`
+/// section of a service definition.
+#[derive(Debug, PartialEq)]
+pub struct Examples(pub Vec<Example>);
+
+/// A sample request (and usually its response) for one operation.
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub operation: String,
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub input: BTreeMap<String, Value>,
+    pub output: Option<BTreeMap<String, Value>>,
+}
+
+impl Examples {
+    pub fn parse<R: Read>(fd: R) -> Result<Examples, ParseError> {
+        let json: Value = match serde_json::from_reader(fd) {
+            Ok(json) => json,
+            Err(_) => return Err(ParseError::SerdeError),
+        };
+        let obj = try!(json.as_object().ok_or(ParseError::ExpectedObject));
+        let examples = try!(obj.get("examples").ok_or(ParseError::InvalidExample("examples".to_string())));
+        Examples::parse_section(examples)
+    }
+
+    /// Parses the `{ "Operation": [ example, ... ] }` map.
+    pub fn parse_section(json: &Value) -> Result<Examples, ParseError> {
+        let operations = try!(json.as_object().ok_or(ParseError::InvalidExample("examples".to_string())));
+        let mut examples = vec!();
+        for (operation, list) in operations.iter() {
+            let list = try!(list.as_array().ok_or(ParseError::InvalidExample(operation.to_string())));
+            for raw in list {
+                examples.push(try!(Example::parse(operation, raw)));
+            }
+        }
+        Ok(Examples(examples))
+    }
+}
+
+impl Example {
+    fn parse(operation: &str, json: &Value) -> Result<Example, ParseError> {
+        let invalid = |field: &str| ParseError::InvalidExample(format!("{}: {}", operation, field));
+        let obj = try!(json.as_object().ok_or(invalid("expected object")));
+        let string = |key: &str| -> Result<Option<String>, ParseError> {
+            match obj.get(key) {
+                Some(json) => Ok(Some(try!(json.as_string().ok_or(invalid(key))).to_string())),
+                None => Ok(None),
+            }
+        };
+        let input = match obj.get("input") {
+            Some(json) => try!(json.as_object().ok_or(invalid("input"))).clone(),
+            None => BTreeMap::new(),
+        };
+        let output = match obj.get("output") {
+            Some(json) => Some(try!(json.as_object().ok_or(invalid("output"))).clone()),
+            None => None,
+        };
+        Ok(Example {
+            operation: operation.to_string(),
+            id: try!(string("id")),
+            title: try!(string("title")),
+            description: try!(string("description")),
+            input: input,
+            output: output,
+        })
+    }
+
+    /// A name for error messages and generated tests: the id, else the title.
+    pub fn label(&self) -> &str {
+        self.id.as_ref().or(self.title.as_ref()).map(|s| &s[..]).unwrap_or("example")
+    }
+
+    /// Checks the operation exists and that the input and output samples match its shapes.
+    pub fn validate(&self, service: &ServiceDefinition) -> Result<(), ParseError> {
+        let operation = try!(service.operation(&self.operation)
+            .ok_or(ParseError::UnknownOperation(self.operation.clone())));
+        let invalid = |message: String| ParseError::InvalidExample(
+            format!("{} example {}: {}", self.operation, self.label(), message));
+
+        let input = Value::Object(self.input.clone());
+        try!(check(service, &operation.input.shape, &input, "input").map_err(&invalid));
+        if let Some(ref output) = self.output {
+            let shape = try!(operation.output.as_ref().ok_or(invalid("operation has no output".to_string())));
+            try!(check(service, &shape.shape, &Value::Object(output.clone()), "output").map_err(&invalid));
+        }
+        Ok(())
+    }
+}
+
+/// Checks `value` against the shape named `shape_name`; `path` locates it for messages.
+pub fn check(service: &ServiceDefinition, shape_name: &str, value: &Value, path: &str) -> Result<(), String> {
+    let shape = try!(service.shape(shape_name).ok_or(format!("{}: unknown shape {}", path, shape_name)));
+    let expected = |kind: &str| format!("{}: expected {} for {}", path, kind, shape_name);
+    match shape.shape_type {
+        ShapeType::Structure(Structure(ref members)) |
+        ShapeType::Exception(Exception { ref members, .. }) => {
+            let obj = try!(value.as_object().ok_or(expected("an object")));
+            for (name, value) in obj.iter() {
+                let member = try!(members.iter().find(|m| m.name == *name)
+                    .ok_or(format!("{}: {} is not a member of {}", path, name, shape_name)));
+                try!(check(service, &member.shape, value, &format!("{}.{}", path, name)));
+            }
+            Ok(())
+        }
+        ShapeType::List(List { member: ref element, .. }) => {
+            let array = try!(value.as_array().ok_or(expected("an array")));
+            for (i, value) in array.iter().enumerate() {
+                try!(check(service, element, value, &format!("{}[{}]", path, i)));
+            }
+            Ok(())
+        }
+        ShapeType::StringEnum(StringEnum(ref variants)) => {
+            let s = try!(value.as_string().ok_or(expected("a string")));
+            if variants.iter().any(|v| v == s) {
+                Ok(())
+            } else {
+                Err(format!("{}: {} is not one of {}", path, s, variants.join(", ")))
+            }
+        }
+        ShapeType::StringPattern(_) | ShapeType::Blob(_) => value.as_string().map(|_| ()).ok_or(expected("a string")),
+        ShapeType::Timestamp => {
+            if value.is_string() || value.is_number() { Ok(()) } else { Err(expected("a timestamp")) }
+        }
+        ShapeType::Boolean => value.as_boolean().map(|_| ()).ok_or(expected("a boolean")),
+        ShapeType::Integer(_) | ShapeType::Long => value.as_i64().map(|_| ()).ok_or(expected("an integer")),
+        ShapeType::Double | ShapeType::Float => value.as_f64().map(|_| ()).ok_or(expected("a number")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::error::ParseError;
+    use ::testhelpers::{fixture_reader, fixture_service};
+
+    fn examples(fixture: &str) -> Examples {
+        Examples::parse(fixture_reader(&format!("examples/{}", fixture))).unwrap()
+    }
+
+    #[test]
+    fn lambda() {
+        let Examples(examples) = examples("lambda-2015-03-31");
+        assert_eq!(examples.len(), 3);
+        let add_permission = &examples[0];
+        assert_eq!(add_permission.operation, "AddPermission");
+        assert_eq!(add_permission.label(), "add-permission-1474651469455");
+        assert_eq!(add_permission.title, Some("add-permission".to_string()));
+        assert_eq!(add_permission.input.len(), 6);
+        assert_eq!(add_permission.output.as_ref().unwrap().get("Statement").unwrap().as_string(), Some("ID-1"));
+    }
+
+    #[test]
+    fn embedded_section_is_empty() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        assert!(service.examples.is_empty());
+    }
+
+    #[test]
+    fn validate_lambda() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(service.add_examples(examples("lambda-2015-03-31")), vec!());
+        assert_eq!(service.examples_for("CreateFunction").len(), 1);
+    }
+
+    #[test]
+    fn validate_nested_member() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(service.add_examples(examples("invalid-member")), vec!(ParseError::InvalidExample(
+            "CreateFunction example Typo in a nested member: input.Code: S3Path is not a member of FunctionCode".to_string())));
+        assert!(service.examples.is_empty());
+    }
+
+    #[test]
+    fn validate_enum() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(service.add_examples(examples("invalid-enum")), vec!(ParseError::InvalidExample(
+            "CreateFunction example Unknown runtime: input.Runtime: cobol is not one of nodejs, java8, python2.7".to_string())));
+    }
+}
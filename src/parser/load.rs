@@ -0,0 +1,211 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use super::error::ParseError;
+use super::examples::Examples;
+use super::paginators::Paginators;
+use super::service_definition::ServiceDefinition;
+use super::waiters::Waiters;
+
+/// Why a service definition, or one of its companion files, could not be read.
+#[derive(Debug)]
+pub enum LoadError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, ParseError),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LoadError::Io(ref path, ref err) => write!(f, "{}: {}", path.display(), err),
+            LoadError::Parse(ref path, ref err) => write!(f, "{}: {}", path.display(), err),
+        }
+    }
+}
+
+/// A service definition read from disk.
+#[derive(Debug)]
+pub struct Loaded {
+    pub service: ServiceDefinition,
+    /// The definition followed by every companion file that was read, in that order.
+    pub files: Vec<PathBuf>,
+    /// The examples left out because they do not fit the operations, with the file
+    /// each came from. They do not stop the service from loading.
+    pub dropped: Vec<LoadError>,
+}
+
+impl ServiceDefinition {
+    /// Reads the service definition at `path`. In the botocore layout, where it is
+    /// `<service>/<api-version>/service-2.json`, the `paginators-*.json`,
+    /// `waiters-*.json` and `examples-*.json` next to it are attached as well.
+    pub fn load(path: &Path) -> Result<Loaded, LoadError> {
+        let mut service = try!(read(path, ServiceDefinition::parse));
+        let mut files = vec!(path.to_path_buf());
+        let mut dropped = vec!();
+        for companion in try!(companions(path)) {
+            let name = companion.file_name().and_then(|name| name.to_str()).unwrap_or("").to_string();
+            let added = if name.starts_with("paginators-") {
+                let paginators = try!(read(&companion, Paginators::parse));
+                service.add_paginators(paginators)
+            } else if name.starts_with("waiters-") {
+                let waiters = try!(read(&companion, Waiters::parse));
+                service.add_waiters(waiters)
+            } else {
+                let examples = try!(read(&companion, Examples::parse));
+                dropped.extend(service.add_examples(examples).into_iter()
+                    .map(|err| LoadError::Parse(companion.clone(), err)));
+                Ok(())
+            };
+            try!(added.map_err(|err| LoadError::Parse(companion.clone(), err)));
+            files.push(companion);
+        }
+        Ok(Loaded {
+            service: service,
+            files: files,
+            dropped: dropped,
+        })
+    }
+}
+
+fn read<T, F: Fn(File) -> Result<T, ParseError>>(path: &Path, parse: F) -> Result<T, LoadError> {
+    let file = try!(File::open(path).map_err(|err| LoadError::Io(path.to_path_buf(), err)));
+    parse(file).map_err(|err| LoadError::Parse(path.to_path_buf(), err))
+}
+
+/// A service in a botocore `data/` directory.
+#[derive(Debug, PartialEq)]
+pub struct Available {
+    /// The service's directory name, like `lambda`.
+    pub service: String,
+    /// The API versions that have a service definition, oldest first, with the definition.
+    pub versions: Vec<(String, PathBuf)>,
+}
+
+/// Lists the services in a botocore `data/` directory, laid out as
+/// `<service>/<api-version>/service-*.json`, sorted by name. Loose files such as
+/// `endpoints.json` are ignored, and a version directory holding more than one
+/// service definition offers the last.
+pub fn available(data: &Path) -> Result<Vec<Available>, LoadError> {
+    let mut services = vec!();
+    for service in try!(entries(data)) {
+        if !service.is_dir() {
+            continue;
+        }
+        let mut versions = vec!();
+        for version in try!(entries(&service)) {
+            if !version.is_dir() {
+                continue;
+            }
+            let definition = try!(entries(&version)).into_iter().filter(|path| is_service_definition(path)).last();
+            if let Some(definition) = definition {
+                versions.push((file_name(&version), definition));
+            }
+        }
+        services.push(Available {
+            service: file_name(&service),
+            versions: versions,
+        });
+    }
+    Ok(services)
+}
+
+fn is_service_definition(path: &Path) -> bool {
+    let name = file_name(path);
+    name.starts_with("service-") && name.ends_with(".json")
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or(String::new(), |name| name.to_string_lossy().into_owned())
+}
+
+/// The paths in `directory`, sorted.
+fn entries(directory: &Path) -> Result<Vec<PathBuf>, LoadError> {
+    let listing = try!(fs::read_dir(directory).map_err(|err| LoadError::Io(directory.to_path_buf(), err)));
+    let mut entries = vec!();
+    for entry in listing {
+        let entry = try!(entry.map_err(|err| LoadError::Io(directory.to_path_buf(), err)));
+        entries.push(entry.path());
+    }
+    entries.sort();
+    Ok(entries)
+}
+
+/// The companion files of a botocore `service-*.json`, sorted by name.
+fn companions(path: &Path) -> Result<Vec<PathBuf>, LoadError> {
+    let directory = match path.parent() {
+        Some(directory) if is_service_definition(path) => directory,
+        _ => return Ok(vec!()),
+    };
+    let directory = if directory.as_os_str().is_empty() { Path::new(".") } else { directory };
+    let companions = try!(entries(directory)).into_iter().filter(|path| {
+        let name = file_name(path);
+        let is_companion = ["paginators-", "waiters-", "examples-"].iter().any(|prefix| name.starts_with(prefix));
+        is_companion && name.ends_with(".json")
+    }).collect();
+    Ok(companions)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::path::{Path, PathBuf};
+    use super::super::error::ParseError;
+
+    #[test]
+    fn botocore_layout() {
+        let loaded = ServiceDefinition::load(Path::new("fixtures/botocore/lambda/2015-03-31/service-2.json")).unwrap();
+        let files: Vec<String> = loaded.files.iter().map(|path| path.display().to_string()).collect();
+        assert_eq!(files, vec!(
+            "fixtures/botocore/lambda/2015-03-31/service-2.json",
+            "fixtures/botocore/lambda/2015-03-31/examples-1.json",
+            "fixtures/botocore/lambda/2015-03-31/paginators-1.json",
+            "fixtures/botocore/lambda/2015-03-31/waiters-2.json",
+        ));
+        assert_eq!(loaded.service.paginators.len(), 4);
+        assert!(loaded.service.waiter("FunctionExists").is_some());
+        assert!(loaded.dropped.is_empty());
+    }
+
+    #[test]
+    fn invalid_examples_are_dropped() {
+        let loaded = ServiceDefinition::load(Path::new("fixtures/botocore/lambda-preview/2015-03-31/service-2.json"))
+            .unwrap();
+        assert!(loaded.service.examples.is_empty());
+        let dropped: Vec<String> = loaded.dropped.iter().map(|err| err.to_string()).collect();
+        assert_eq!(dropped, vec!("fixtures/botocore/lambda-preview/2015-03-31/examples-1.json: invalid example: \
+                                  CreateFunction example Typo in a nested member: input.Code: \
+                                  S3Path is not a member of FunctionCode"));
+    }
+
+    #[test]
+    fn single_file() {
+        let loaded = ServiceDefinition::load(Path::new("fixtures/services/lambda-2015-03-31.json")).unwrap();
+        assert_eq!(loaded.files.len(), 1);
+        assert!(loaded.service.paginators.is_empty());
+    }
+
+    #[test]
+    fn data_directory() {
+        let available = available(Path::new("fixtures/botocore")).unwrap();
+        let services: Vec<&str> = available.iter().map(|a| &a.service[..]).collect();
+        assert_eq!(services, vec!("conditions", "lambda", "lambda-preview", "sdb", "uploads"));
+        let versions: Vec<&str> = available[0].versions.iter().map(|v| &v.0[..]).collect();
+        assert_eq!(versions, vec!("2015-06-01", "2016-01-01"));
+        assert_eq!(available[1].versions, vec!(("2015-03-31".to_string(),
+                                                 PathBuf::from("fixtures/botocore/lambda/2015-03-31/service-2.json"))));
+        assert!(available[3].versions.is_empty());
+    }
+
+    #[test]
+    fn errors_name_the_file() {
+        match ServiceDefinition::load(Path::new("fixtures/services/stream-not-payload.json")) {
+            Err(LoadError::Parse(path, ParseError::InvalidPayload(_))) => {
+                assert_eq!(path, Path::new("fixtures/services/stream-not-payload.json"));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        let err = ServiceDefinition::load(Path::new("fixtures/services/missing.json")).unwrap_err();
+        assert!(err.to_string().starts_with("fixtures/services/missing.json: "));
+    }
+}
@@ -3,9 +3,21 @@ pub mod service_definition;
 pub mod shape;
 pub mod shape_type;
 pub mod operation;
+pub mod endpoints;
+pub mod path;
+pub mod paginators;
+pub mod waiters;
+pub mod examples;
+pub mod load;
+pub mod cycles;
+pub mod diff;
+pub mod smithy;
+pub mod overlay;
+pub mod deprecation;
 
 pub use self::error::*;
 pub use self::shape_type::*;
-pub use self::service_definition::ServiceDefinition;
+pub use self::service_definition::{ServiceDefinition, Metadata};
 pub use self::shape::Shape;
 pub use self::operation::Operation;
+pub use self::deprecation::Deprecation;
@@ -4,48 +4,53 @@ use serde_json::Value;
 use std::collections::{BTreeMap, HashMap};
 use super::error::ParseError;
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, PartialEq)]
 pub struct Operation {
     pub name: String,
     pub http: HTTP,
     pub input: Input,
     pub output: Option<Output>,
     pub errors: Vec<Error>,
-    pub deprecated: Option<bool>, // Silliness for Serde
+    /// `Some(true)` when the operation is deprecated; see `deprecation()`.
+    pub deprecated: Option<bool>,
+    pub deprecatedMessage: Option<String>,
     pub documentation: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, PartialEq)]
 pub struct HTTP {
-    method: String,  // Would be nice to make this an enum...
-    requestUri: String,
-    responseCode: Option<i32>,
+    pub method: String,  // Would be nice to make this an enum...
+    pub requestUri: String,
+    pub responseCode: Option<i32>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, PartialEq)]
 pub struct Error {
-    shape: String,
-    error: HTTPError,
-    exception: bool, // Silliness -- this should be ignored
-    documentation: Option<String>,
-    deprecated: Option<bool>, // Silliness, false by default
+    pub shape: String,
+    pub error: HTTPError,
+    pub exception: bool, // Silliness -- this should be ignored
+    pub documentation: Option<String>,
+    /// `Some(true)` when the operation no longer returns this error.
+    pub deprecated: Option<bool>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, PartialEq)]
 pub struct HTTPError {
-    httpStatusCode: i32
+    pub httpStatusCode: i32
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, PartialEq)]
 pub struct Output {
-    shape: String,
-    documentation: Option<String>,
-    deprecated: Option<bool>,
+    pub shape: String,
+    pub documentation: Option<String>,
+    /// `Some(true)` alongside a deprecated output shape.
+    pub deprecated: Option<bool>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, PartialEq)]
 pub struct Input {
-    shape: String,
-    deprecated: Option<bool>, // Silly, false by default
+    pub shape: String,
+    /// `Some(true)` alongside a deprecated input shape.
+    pub deprecated: Option<bool>,
 }
 
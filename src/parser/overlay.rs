@@ -0,0 +1,336 @@
+extern crate serde;
+extern crate serde_json;
+
+use std::io::Read;
+use std::collections::BTreeMap;
+use serde_json::Value;
+use super::error::ParseError;
+use super::service_definition::ServiceDefinition;
+use super::shape_type::*;
+
+/// Changes to make to a service definition before generating code from it, so that
+/// an upstream model can be patched without forking it. Shapes, members and
+/// operations are named as they are upstream, even when `rename` gives them new names.
+///
+/// ```json
+/// {
+///     "service": "lambda",
+///     "rename": {"FunctionConfiguration": "Function"},
+///     "required": {"FunctionConfiguration.Runtime": true},
+///     "enumValues": {"Runtime": ["nodejs4.3"]},
+///     "documentation": {"Invoke": "<p>Runs a function.</p>", "GetFunctionRequest.Qualifier": "..."},
+///     "removeOperations": ["AddPermission"],
+///     "removeDeprecated": true,
+///     "derives": {"Runtime": ["Hash", "Eq"]}
+/// }
+/// ```
+///
+/// `service` is the endpoint prefix of the one service the overlay is for; without
+/// it, the overlay is for any service.
+#[derive(Debug, Default, PartialEq)]
+pub struct Overlay {
+    pub service: Option<String>,
+    pub rename: BTreeMap<String, String>,
+    /// Members, as `Shape.Member`, and whether they are required.
+    pub required: BTreeMap<String, bool>,
+    /// Values to add to string enums.
+    pub enum_values: BTreeMap<String, Vec<String>>,
+    /// Documentation for operations, exceptions and members (`Shape.Member`).
+    pub documentation: BTreeMap<String, String>,
+    pub remove_operations: Vec<String>,
+    pub remove_deprecated: bool,
+    /// Traits to derive for structures, enums and exceptions, on top of the usual ones.
+    pub derives: BTreeMap<String, Vec<String>>,
+}
+
+impl Overlay {
+    pub fn parse<R: Read>(fd: R) -> Result<Overlay, ParseError> {
+        let json: Value = match serde_json::from_reader(fd) {
+            Ok(json) => json,
+            Err(_) => return Err(ParseError::SerdeError),
+        };
+        let obj = try!(json.as_object().ok_or(ParseError::ExpectedObject));
+        let mut overlay = Overlay::default();
+        for (key, value) in obj.iter() {
+            match &key[..] {
+                "service" => overlay.service = Some(try!(string(key, value))),
+                "rename" => {
+                    for (old, new) in try!(object(key, value)) {
+                        overlay.rename.insert(old.clone(), try!(string(old, new)));
+                    }
+                }
+                "required" => {
+                    for (member, required) in try!(object(key, value)) {
+                        let required = try!(required.as_boolean().ok_or(invalid(format!("{} is not true or false", member))));
+                        overlay.required.insert(member.clone(), required);
+                    }
+                }
+                "enumValues" => {
+                    for (shape, values) in try!(object(key, value)) {
+                        overlay.enum_values.insert(shape.clone(), try!(strings(shape, values)));
+                    }
+                }
+                "documentation" => {
+                    for (target, text) in try!(object(key, value)) {
+                        overlay.documentation.insert(target.clone(), try!(string(target, text)));
+                    }
+                }
+                "removeOperations" => overlay.remove_operations = try!(strings(key, value)),
+                "removeDeprecated" => {
+                    overlay.remove_deprecated = try!(value.as_boolean().ok_or(invalid(format!("{} is not true or false", key))));
+                }
+                "derives" => {
+                    for (shape, traits) in try!(object(key, value)) {
+                        overlay.derives.insert(shape.clone(), try!(strings(shape, traits)));
+                    }
+                }
+                other => return Err(invalid(format!("unknown key {}", other))),
+            }
+        }
+        Ok(overlay)
+    }
+
+    /// Whether the overlay is for `service`.
+    pub fn applies_to(&self, service: &ServiceDefinition) -> bool {
+        self.service.as_ref().map_or(true, |prefix| *prefix == service.metadata.endpointPrefix)
+    }
+}
+
+impl ServiceDefinition {
+    /// Applies an overlay. A change to a shape, member or operation the service does
+    /// not have, or one that changes nothing, is an error: it is most likely stale,
+    /// left behind by an update to the model. The service is left half changed then.
+    pub fn apply(&mut self, overlay: &Overlay) -> Result<(), ParseError> {
+        for name in &overlay.remove_operations {
+            if self.operation(name).is_none() {
+                return Err(invalid(format!("removeOperations: there is no operation {}", name)));
+            }
+        }
+        let removed: Vec<String> = self.operations.iter()
+            .filter(|operation| {
+                overlay.remove_operations.contains(&operation.name) ||
+                (overlay.remove_deprecated && operation.deprecated == Some(true))
+            })
+            .map(|operation| operation.name.clone())
+            .collect();
+        self.remove_operations(&removed);
+
+        for (target, &required) in &overlay.required {
+            let member = try!(self.member_mut("required", target));
+            if member.required == required {
+                return Err(invalid(format!("required: {} is already {}", target, if required { "required" } else { "optional" })));
+            }
+            member.required = required;
+        }
+
+        for (name, values) in &overlay.enum_values {
+            let existing = match self.shapes.iter_mut().find(|shape| shape.name == *name).map(|shape| &mut shape.shape_type) {
+                Some(&mut ShapeType::StringEnum(StringEnum(ref mut existing))) => existing,
+                Some(_) => return Err(invalid(format!("enumValues: {} is not an enum", name))),
+                None => return Err(invalid(format!("enumValues: there is no shape {}", name))),
+            };
+            for value in values {
+                if existing.contains(value) {
+                    return Err(invalid(format!("enumValues: {} already has {}", name, value)));
+                }
+                existing.push(value.clone());
+            }
+        }
+
+        for (target, text) in &overlay.documentation {
+            let documentation = if target.contains('.') {
+                &mut try!(self.member_mut("documentation", target)).documentation
+            } else if let Some(operation) = self.operations.iter_mut().find(|operation| operation.name == *target) {
+                &mut operation.documentation
+            } else {
+                match self.shapes.iter_mut().find(|shape| shape.name == *target).map(|shape| &mut shape.shape_type) {
+                    Some(&mut ShapeType::Exception(ref mut exception)) => &mut exception.documentation,
+                    Some(_) => return Err(invalid(format!("documentation: {} has none; name a member as {}.<member>", target, target))),
+                    None => return Err(invalid(format!("documentation: there is no operation or shape {}", target))),
+                }
+            };
+            if documentation.as_ref() == Some(text) {
+                return Err(invalid(format!("documentation: {} already says that", target)));
+            }
+            *documentation = Some(text.clone());
+        }
+
+        for (name, traits) in &overlay.derives {
+            match self.shape(name).map(|shape| &shape.shape_type) {
+                Some(&ShapeType::Structure(_)) | Some(&ShapeType::StringEnum(_)) | Some(&ShapeType::Exception(_)) => {}
+                Some(_) => return Err(invalid(format!("derives: {} is not a structure, enum or exception", name))),
+                None => return Err(invalid(format!("derives: there is no shape {}", name))),
+            }
+            self.derives.entry(name.clone()).or_insert_with(Vec::new).extend(traits.iter().cloned());
+        }
+
+        // Last, so that everything above goes by the upstream names.
+        for (old, new) in &overlay.rename {
+            try!(self.rename(old, new));
+        }
+        Ok(())
+    }
+
+    /// Renames a shape and every reference to it.
+    fn rename(&mut self, old: &str, new: &str) -> Result<(), ParseError> {
+        if self.shape(old).is_none() {
+            return Err(invalid(format!("rename: there is no shape {}", old)));
+        }
+        if self.shape(new).is_some() {
+            return Err(invalid(format!("rename: there already is a shape {}", new)));
+        }
+        let rename = |name: &mut String| {
+            if *name == old {
+                *name = new.to_string();
+            }
+        };
+        for shape in &mut self.shapes {
+            rename(&mut shape.name);
+            match shape.shape_type {
+                ShapeType::Structure(Structure(ref mut members)) |
+                ShapeType::Exception(Exception { ref mut members, .. }) => {
+                    for member in members {
+                        rename(&mut member.shape);
+                    }
+                }
+                ShapeType::List(List { ref mut member, .. }) => rename(member),
+                _ => {}
+            }
+        }
+        for operation in &mut self.operations {
+            rename(&mut operation.input.shape);
+            if let Some(ref mut output) = operation.output {
+                rename(&mut output.shape);
+            }
+            for error in &mut operation.errors {
+                rename(&mut error.shape);
+            }
+        }
+        if let Some(traits) = self.derives.remove(old) {
+            self.derives.insert(new.to_string(), traits);
+        }
+        Ok(())
+    }
+
+    /// The member `target` names as `Shape.Member`, for the overlay section `section`.
+    fn member_mut(&mut self, section: &str, target: &str) -> Result<&mut Member, ParseError> {
+        let at = try!(target.find('.').ok_or(invalid(format!("{}: {} is not <shape>.<member>", section, target))));
+        let (name, member) = (&target[..at], &target[at + 1..]);
+        let members = match self.shapes.iter_mut().find(|shape| shape.name == name).map(|shape| &mut shape.shape_type) {
+            Some(&mut ShapeType::Structure(Structure(ref mut members))) |
+            Some(&mut ShapeType::Exception(Exception { ref mut members, .. })) => members,
+            Some(_) => return Err(invalid(format!("{}: {} is not a structure", section, name))),
+            None => return Err(invalid(format!("{}: there is no shape {}", section, name))),
+        };
+        members.iter_mut().find(|m| m.name == member)
+            .ok_or(invalid(format!("{}: {} has no member {}", section, name, member)))
+    }
+}
+
+fn invalid(message: String) -> ParseError {
+    ParseError::InvalidOverlay(message)
+}
+
+fn object<'a>(key: &str, value: &'a Value) -> Result<&'a BTreeMap<String, Value>, ParseError> {
+    value.as_object().ok_or(invalid(format!("{} is not an object", key)))
+}
+
+fn string(key: &str, value: &Value) -> Result<String, ParseError> {
+    value.as_string().map(|s| s.to_string()).ok_or(invalid(format!("{} is not a string", key)))
+}
+
+fn strings(key: &str, value: &Value) -> Result<Vec<String>, ParseError> {
+    let values = try!(value.as_array().ok_or(invalid(format!("{} is not a list", key))));
+    values.iter().map(|value| string(key, value)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::parser::*;
+    use ::testhelpers::{fixture_reader, fixture_service};
+
+    fn overlay(json: &str) -> Overlay {
+        Overlay::parse(json.as_bytes()).unwrap()
+    }
+
+    fn members(service: &ServiceDefinition, shape: &str) -> Vec<(String, bool)> {
+        match service.shape(shape).unwrap().shape_type {
+            ShapeType::Structure(Structure(ref members)) => {
+                members.iter().map(|member| (member.name.clone(), member.required)).collect()
+            }
+            _ => panic!("{} is not a structure", shape),
+        }
+    }
+
+    #[test]
+    fn lambda() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        let lambda = Overlay::parse(fixture_reader("overlays/lambda")).unwrap();
+        assert_eq!(lambda.service, Some("lambda".to_string()));
+        assert!(lambda.applies_to(&service));
+        service.apply(&lambda).unwrap();
+
+        assert!(service.operation("InvokeAsync").is_none());
+        assert!(service.operation("AddPermission").is_none());
+        assert!(service.examples_for("AddPermission").is_empty());
+        assert_eq!(service.operations.len(), 22);
+
+        assert!(service.shape("FunctionConfiguration").is_none());
+        assert!(members(&service, "Function").contains(&("Runtime".to_string(), true)));
+        let list = service.shape("FunctionList").unwrap();
+        assert_eq!(list.shape_type.references(), vec!(("member", "Function")));
+        assert_eq!(service.operation("GetFunctionConfiguration").unwrap().output.as_ref().unwrap().shape, "Function");
+
+        assert_eq!(service.shape("Runtime").unwrap().shape_type,
+                   ShapeType::StringEnum(StringEnum(vec!("nodejs".to_string(), "java8".to_string(),
+                                                         "python2.7".to_string(), "nodejs4.3".to_string()))));
+        assert_eq!(service.operation("Invoke").unwrap().documentation, Some("<p>Runs a function.</p>".to_string()));
+        assert_eq!(service.derives.get("Runtime"), Some(&vec!("Hash".to_string(), "Eq".to_string())));
+        assert_eq!(service.derives.get("Function"), Some(&vec!("Clone".to_string())));
+    }
+
+    #[test]
+    fn other_service() {
+        let service = fixture_service("services/recursive");
+        assert!(!overlay(r#"{"service": "lambda"}"#).applies_to(&service));
+        assert!(overlay("{}").applies_to(&service));
+    }
+
+    #[test]
+    fn stale() {
+        let error = |json: &str| {
+            let mut service = fixture_service("services/lambda-2015-03-31");
+            match service.apply(&overlay(json)) {
+                Err(ParseError::InvalidOverlay(message)) => message,
+                other => panic!("{} gave {:?}", json, other),
+            }
+        };
+        assert_eq!(error(r#"{"rename": {"Functions": "Function"}}"#), "rename: there is no shape Functions");
+        assert_eq!(error(r#"{"rename": {"Runtime": "FunctionName"}}"#),
+                   "rename: there already is a shape FunctionName");
+        assert_eq!(error(r#"{"required": {"GetFunctionRequest.Version": true}}"#),
+                   "required: GetFunctionRequest has no member Version");
+        assert_eq!(error(r#"{"required": {"GetFunctionRequest.FunctionName": true}}"#),
+                   "required: GetFunctionRequest.FunctionName is already required");
+        assert_eq!(error(r#"{"required": {"Runtime.Value": true}}"#), "required: Runtime is not a structure");
+        assert_eq!(error(r#"{"enumValues": {"Runtime": ["java8"]}}"#), "enumValues: Runtime already has java8");
+        assert_eq!(error(r#"{"enumValues": {"RoleArn": ["admin"]}}"#), "enumValues: RoleArn is not an enum");
+        assert_eq!(error(r#"{"documentation": {"Invok": "Runs."}}"#),
+                   "documentation: there is no operation or shape Invok");
+        assert_eq!(error(r#"{"removeOperations": ["Invok"]}"#), "removeOperations: there is no operation Invok");
+        assert_eq!(error(r#"{"derives": {"Timeout": ["Hash"]}}"#),
+                   "derives: Timeout is not a structure, enum or exception");
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Overlay::parse(r#"{"renames": {}}"#.as_bytes()),
+                   Err(ParseError::InvalidOverlay("unknown key renames".to_string())));
+        assert_eq!(Overlay::parse(r#"{"required": {"A.B": "yes"}}"#.as_bytes()),
+                   Err(ParseError::InvalidOverlay("A.B is not true or false".to_string())));
+        assert_eq!(Overlay::parse(r#"{"derives": {"A": "Hash"}}"#.as_bytes()),
+                   Err(ParseError::InvalidOverlay("A is not a list".to_string())));
+        assert_eq!(Overlay::parse("[]".as_bytes()), Err(ParseError::ExpectedObject));
+    }
+}
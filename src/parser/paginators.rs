@@ -0,0 +1,185 @@
+extern crate serde;
+extern crate serde_json;
+
+use std::io::Read;
+use std::collections::BTreeMap;
+use serde_json::Value;
+use super::error::ParseError;
+use super::path::Path;
+use super::service_definition::ServiceDefinition;
+use super::shape_type::*;
+
+/// The contents of a `paginators-1.json` companion file.
+#[derive(Debug, PartialEq)]
+pub struct Paginators(pub Vec<Paginator>);
+
+#[derive(Debug, PartialEq)]
+pub struct Paginator {
+    pub operation: String,
+    pub input_token: Vec<String>,
+    pub output_token: Vec<Path>,
+    pub limit_key: Option<String>,
+    pub result_key: Vec<Path>,
+    pub more_results: Option<Path>,
+}
+
+impl Paginators {
+    pub fn parse<R: Read>(fd: R) -> Result<Paginators, ParseError> {
+        let json: Value = match serde_json::from_reader(fd) {
+            Ok(json) => json,
+            Err(_) => return Err(ParseError::SerdeError),
+        };
+        let obj = try!(json.as_object().ok_or(ParseError::ExpectedObject));
+        let pagination = try!(obj.get("pagination").and_then(|p| p.as_object())
+            .ok_or(ParseError::InvalidPaginator("pagination".to_string())));
+        let mut paginators = vec!();
+        for (operation, raw) in pagination.iter() {
+            paginators.push(try!(Paginator::parse(operation, raw)));
+        }
+        Ok(Paginators(paginators))
+    }
+}
+
+impl Paginator {
+    fn parse(operation: &str, json: &Value) -> Result<Paginator, ParseError> {
+        let obj = try!(json.as_object().ok_or(ParseError::InvalidPaginator(operation.to_string())));
+        let input_token = try!(one_or_many(operation, obj, "input_token"));
+        let mut output_token = vec!();
+        for token in try!(one_or_many(operation, obj, "output_token")) {
+            output_token.push(try!(Path::parse(&token)));
+        }
+        if input_token.is_empty() || input_token.len() != output_token.len() {
+            return Err(ParseError::InvalidPaginator(
+                format!("{}: input_token and output_token must pair up", operation)));
+        }
+        let mut result_key = vec!();
+        for key in try!(one_or_many(operation, obj, "result_key")) {
+            result_key.push(try!(Path::parse(&key)));
+        }
+        let more_results = match try!(one_or_many(operation, obj, "more_results")).first() {
+            Some(path) => Some(try!(Path::parse(path))),
+            None => None,
+        };
+        Ok(Paginator {
+            operation: operation.to_string(),
+            input_token: input_token,
+            output_token: output_token,
+            limit_key: try!(one_or_many(operation, obj, "limit_key")).pop(),
+            result_key: result_key,
+            more_results: more_results,
+        })
+    }
+
+    /// Checks that the operation exists and that every token, key and path names
+    /// a member of the operation's input or output shape.
+    pub fn validate(&self, service: &ServiceDefinition) -> Result<(), ParseError> {
+        let invalid = |message: String| ParseError::InvalidPaginator(format!("{}: {}", self.operation, message));
+        let operation = try!(service.operation(&self.operation)
+            .ok_or(ParseError::UnknownOperation(self.operation.clone())));
+        let output = match operation.output {
+            Some(ref output) => &output.shape,
+            None => return Err(invalid("operation has no output".to_string())),
+        };
+
+        let input = &operation.input.shape;
+        let input_members = match service.shape(input).map(|shape| &shape.shape_type) {
+            Some(&ShapeType::Structure(Structure(ref members))) => members,
+            _ => return Err(invalid(format!("input {} is not a structure", input))),
+        };
+        for token in self.input_token.iter().chain(self.limit_key.iter()) {
+            if !input_members.iter().any(|member| member.name == *token) {
+                return Err(invalid(format!("{} is not a member of {}", token, input)));
+            }
+        }
+
+        for path in self.output_token.iter().chain(self.result_key.iter()).chain(self.more_results.iter()) {
+            try!(path.resolve(service, output).map_err(&invalid));
+        }
+        Ok(())
+    }
+}
+
+/// Paginator fields may hold either a single string or a list of them.
+fn one_or_many(operation: &str, obj: &BTreeMap<String, Value>, key: &str) -> Result<Vec<String>, ParseError> {
+    let invalid = || ParseError::InvalidPaginator(format!("{}: {}", operation, key));
+    match obj.get(key) {
+        Some(&Value::String(ref s)) => Ok(vec!(s.to_string())),
+        Some(&Value::Array(ref array)) => {
+            let mut values = vec!();
+            for json in array {
+                values.push(try!(json.as_string().ok_or(invalid())).to_string());
+            }
+            Ok(values)
+        }
+        Some(_) => Err(invalid()),
+        None => Ok(vec!()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::error::ParseError;
+    use super::super::path::Path;
+    use ::testhelpers::{fixture_reader, fixture_service};
+
+    fn paginators(fixture: &str) -> Paginators {
+        Paginators::parse(fixture_reader(&format!("paginators/{}", fixture))).unwrap()
+    }
+
+    #[test]
+    fn lambda() {
+        let Paginators(paginators) = paginators("lambda-2015-03-31");
+        assert_eq!(paginators.len(), 4);
+        assert_eq!(paginators[2], Paginator {
+            operation: "ListFunctions".to_string(),
+            input_token: vec!("Marker".to_string()),
+            output_token: vec!(Path::parse("NextMarker").unwrap()),
+            limit_key: Some("MaxItems".to_string()),
+            result_key: vec!(Path::parse("Functions").unwrap()),
+            more_results: None,
+        });
+    }
+
+    #[test]
+    fn multiple_tokens() {
+        let Paginators(paginators) = paginators("multi-token");
+        let versions = &paginators[0];
+        assert_eq!(versions.operation, "ListObjectVersions");
+        assert_eq!(versions.input_token, vec!("KeyMarker", "VersionIdMarker"));
+        assert_eq!(versions.output_token, vec!(
+            Path::parse("NextKeyMarker").unwrap(),
+            Path::parse("NextVersionIdMarker").unwrap(),
+        ));
+        assert_eq!(versions.result_key.len(), 3);
+        assert_eq!(versions.more_results, Some(Path::parse("IsTruncated").unwrap()));
+    }
+
+    #[test]
+    fn path_token() {
+        let Paginators(paginators) = paginators("multi-token");
+        assert_eq!(paginators[1].output_token, vec!(Path::parse("NextMarker || Contents[-1].Key").unwrap()));
+    }
+
+    #[test]
+    fn validate_lambda() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(service.add_paginators(paginators("lambda-2015-03-31")), Ok(()));
+        assert_eq!(service.paginator("ListAliases").unwrap().result_key, vec!(Path::parse("Aliases").unwrap()));
+    }
+
+    #[test]
+    fn validate_unknown_member() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(service.add_paginators(paginators("unknown-member")), Err(ParseError::InvalidPaginator(
+            "ListFunctions: NextToken is not a member of ListFunctionsResponse".to_string())));
+        assert!(service.paginators.is_empty());
+    }
+
+    #[test]
+    fn validate_unknown_operation() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(service.add_paginators(paginators("unknown-operation")),
+                   Err(ParseError::UnknownOperation("ListLayers".to_string())));
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt;
+use super::error::ParseError;
+use super::service_definition::ServiceDefinition;
+use super::shape_type::*;
+
+/// One step of a `Path`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Segment {
+    Member(String),  // `Foo`
+    Index(i64),      // `[0]`, `[-1]`
+    Flatten,         // `[]`
+}
+
+/// The JMESPath-ish expressions paginators and waiters use to point into an
+/// operation's input or output, e.g. `NextMarker`, `Configuration.State`,
+/// `Contents[-1].Key` or `NextMarker || Contents[-1].Key`.
+///
+/// Each `||` separated alternative is kept as its own list of segments.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Path {
+    pub alternatives: Vec<Vec<Segment>>,
+}
+
+impl Path {
+    pub fn parse(expression: &str) -> Result<Path, ParseError> {
+        let mut alternatives = vec!();
+        for alternative in expression.split("||") {
+            alternatives.push(try!(parse_segments(alternative.trim(), expression)));
+        }
+        Ok(Path { alternatives: alternatives })
+    }
+
+    /// Follows every alternative from `root` (a shape name) and returns the shape
+    /// name each one ends at. The error describes the first step that doesn't exist.
+    pub fn resolve(&self, service: &ServiceDefinition, root: &str) -> Result<Vec<String>, String> {
+        let mut targets = vec!();
+        for segments in &self.alternatives {
+            let mut current = root.to_string();
+            for segment in segments {
+                current = try!(step(service, &current, segment)).0.to_string();
+            }
+            targets.push(current);
+        }
+        Ok(targets)
+    }
+}
+
+impl fmt::Display for Path {
+    /// The expression as `parse()` reads it.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, segments) in self.alternatives.iter().enumerate() {
+            if i > 0 {
+                try!(write!(f, " || "));
+            }
+            for (j, segment) in segments.iter().enumerate() {
+                try!(match *segment {
+                    Segment::Member(ref name) if j > 0 => write!(f, ".{}", name),
+                    Segment::Member(ref name) => write!(f, "{}", name),
+                    Segment::Index(index) => write!(f, "[{}]", index),
+                    Segment::Flatten => write!(f, "[]"),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_segments(alternative: &str, expression: &str) -> Result<Vec<Segment>, ParseError> {
+    let invalid = || ParseError::InvalidPath(expression.to_string());
+    if alternative.is_empty() {
+        return Err(invalid());
+    }
+    let mut segments = vec!();
+    for part in alternative.split('.') {
+        let (name, mut brackets) = match part.find('[') {
+            Some(i) => (&part[..i], &part[i..]),
+            None => (part, ""),
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(invalid());
+        }
+        segments.push(Segment::Member(name.to_string()));
+        while !brackets.is_empty() {
+            let close = try!(brackets.find(']').ok_or(invalid()));
+            let inner = &brackets[1..close];
+            if inner.is_empty() {
+                segments.push(Segment::Flatten);
+            } else {
+                let index = try!(inner.parse::<i64>().map_err(|_| invalid()));
+                segments.push(Segment::Index(index));
+            }
+            brackets = &brackets[close + 1..];
+            if !brackets.is_empty() && !brackets.starts_with('[') {
+                return Err(invalid());
+            }
+        }
+    }
+    Ok(segments)
+}
+
+/// Takes one step from the shape named `shape_name`, returning the name of the shape
+/// it leads to and, for a `Segment::Member`, the member followed. Generators use this
+/// to walk a path while writing the code that reads it.
+pub fn step<'a>(service: &'a ServiceDefinition, shape_name: &str, segment: &Segment)
+                -> Result<(&'a str, Option<&'a Member>), String> {
+    let shape = try!(service.shape(shape_name).ok_or(format!("unknown shape {}", shape_name)));
+    match (&shape.shape_type, segment) {
+        (&ShapeType::Structure(Structure(ref members)), &Segment::Member(ref name)) |
+        (&ShapeType::Exception(Exception { ref members, .. }), &Segment::Member(ref name)) => {
+            members.iter()
+                .find(|member| member.name == *name)
+                .map(|member| (&member.shape[..], Some(member)))
+                .ok_or(format!("{} is not a member of {}", name, shape_name))
+        }
+        (&ShapeType::List(List { member: ref element, .. }), &Segment::Index(_)) |
+        (&ShapeType::List(List { member: ref element, .. }), &Segment::Flatten) => Ok((&element[..], None)),
+        (_, &Segment::Member(ref name)) => Err(format!("{} is not a member of {} (not a structure)", name, shape_name)),
+        (_, _) => Err(format!("{} is not a list", shape_name)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::error::ParseError;
+    use ::testhelpers::fixture_service;
+
+    #[test]
+    fn member() {
+        assert_eq!(Path::parse("NextMarker"), Ok(Path {
+            alternatives: vec!(vec!(Segment::Member("NextMarker".to_string()))),
+        }));
+    }
+
+    #[test]
+    fn alternatives_and_indexes() {
+        assert_eq!(Path::parse("NextMarker || Contents[-1].Key"), Ok(Path {
+            alternatives: vec!(
+                vec!(Segment::Member("NextMarker".to_string())),
+                vec!(Segment::Member("Contents".to_string()),
+                     Segment::Index(-1),
+                     Segment::Member("Key".to_string())),
+            ),
+        }));
+        let expression = "NextMarker || Contents[-1].Key || Reservations[].Instances[].State.Name";
+        assert_eq!(Path::parse(expression).unwrap().to_string(), expression);
+    }
+
+    #[test]
+    fn flatten() {
+        assert_eq!(Path::parse("Reservations[].Instances[].State.Name").unwrap().alternatives[0], vec!(
+            Segment::Member("Reservations".to_string()),
+            Segment::Flatten,
+            Segment::Member("Instances".to_string()),
+            Segment::Flatten,
+            Segment::Member("State".to_string()),
+            Segment::Member("Name".to_string()),
+        ));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Path::parse("Foo..Bar"), Err(ParseError::InvalidPath("Foo..Bar".to_string())));
+        assert_eq!(Path::parse("Foo[x]"), Err(ParseError::InvalidPath("Foo[x]".to_string())));
+        assert_eq!(Path::parse("Foo ||"), Err(ParseError::InvalidPath("Foo ||".to_string())));
+    }
+
+    #[test]
+    fn resolve() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let path = Path::parse("NextMarker || Functions[-1].FunctionName").unwrap();
+        assert_eq!(path.resolve(&service, "ListFunctionsResponse"),
+                   Ok(vec!("String".to_string(), "FunctionName".to_string())));
+    }
+
+    #[test]
+    fn resolve_unknown_member() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let path = Path::parse("Functions[].Nope").unwrap();
+        assert_eq!(path.resolve(&service, "ListFunctionsResponse"),
+                   Err("Nope is not a member of FunctionConfiguration".to_string()));
+    }
+
+    #[test]
+    fn resolve_index_on_non_list() {
+        let service = fixture_service("services/lambda-2015-03-31");
+        let path = Path::parse("NextMarker[0]").unwrap();
+        assert_eq!(path.resolve(&service, "ListFunctionsResponse"), Err("String is not a list".to_string()));
+    }
+}
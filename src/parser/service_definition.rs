@@ -2,16 +2,20 @@ extern crate serde;
 extern crate serde_json;
 
 use std::io::Read;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use super::error::ParseError;
 use super::shape::Shape;
+use super::shape_type::*;
 use super::operation::Operation;
+use super::paginators::{Paginator, Paginators};
+use super::waiters::{Waiter, Waiters};
+use super::examples::{Example, Examples};
 use serde_json::Value;
 
 #[derive(Deserialize,Debug)]
 /// Intermediate representation of service definition as parsed by serde_json.
 struct PartialServiceDefinition {
-    version: f64,
+    version: String,
     documentation: String,
     metadata: Metadata,
     operations: BTreeMap<String, Operation>,
@@ -19,41 +23,178 @@ struct PartialServiceDefinition {
     examples: Value,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct ServiceDefinition {
-    version: f64,
-    documentation: String,
-    metadata: Metadata,
-    operations: Vec<String>,
-    shapes: Vec<Shape>,
+    pub version: String,
+    pub documentation: String,
+    pub metadata: Metadata,
+    pub operations: Vec<Operation>,
+    pub shapes: Vec<Shape>,
+    pub paginators: Vec<Paginator>,
+    pub waiters: Vec<Waiter>,
+    pub examples: Vec<Example>,
+    /// Traits an overlay has structures, enums and exceptions derive, by shape name.
+    pub derives: BTreeMap<String, Vec<String>>,
 }
 
-#[derive(Deserialize,Debug)]
+#[derive(Deserialize,Debug,PartialEq)]
 /// Metadata about this service.
 pub struct Metadata {
-    apiVersion: String, // Convert to date object
-    endpointPrefix: String,
-    serviceFullName: String,
-    signatureVersion: String, // TODO enum
-    protocol: String, // TODO enum
+    pub apiVersion: String, // Convert to date object
+    pub endpointPrefix: String,
+    pub serviceFullName: String,
+    pub globalEndpoint: Option<String>,
+    pub signatureVersion: String, // TODO enum
+    pub signingName: Option<String>,
+    pub protocol: String, // TODO enum
+}
+
+impl Metadata {
+    /// The service name used in the SigV4 credential scope. Most services sign
+    /// with their endpoint prefix, but some override it with `signingName`.
+    pub fn signing_name(&self) -> &str {
+        match self.signingName {
+            Some(ref name) => name,
+            None => &self.endpointPrefix,
+        }
+    }
 }
 
 impl ServiceDefinition {
-    pub fn parse<R: Read>(fd: R) -> Result<ServiceDefinition, ParseError> {
-        let partial: PartialServiceDefinition = match serde_json::from_reader(fd) {
+    /// Parses a service definition in botocore's format or, when it has a `smithy`
+    /// version, as a Smithy JSON AST model.
+    pub fn parse<R: Read>(mut fd: R) -> Result<ServiceDefinition, ParseError> {
+        let mut contents = String::new();
+        try!(fd.read_to_string(&mut contents).map_err(|_| ParseError::SerdeError));
+        let json: Value = try!(serde_json::from_str(&contents).map_err(|_| ParseError::SerdeError));
+        if json.find("smithy").is_some() {
+            return ServiceDefinition::from_smithy(&json);
+        }
+        let partial: PartialServiceDefinition = match serde_json::from_str(&contents) {
             Ok(p) => p,
             Err(_) => return Err(ParseError::SerdeError),
 
         };
         let obj = try!(partial.shapes.as_object().ok_or(ParseError::ServiceDefinitionInvalidShapes));
         let shapes = try!(ServiceDefinition::parse_shapes(&obj));
-        Ok(ServiceDefinition {
+        try!(ServiceDefinition::validate_streaming(&shapes));
+        let Examples(examples) = try!(Examples::parse_section(&partial.examples));
+        let mut service = ServiceDefinition {
             version: partial.version,
             documentation: partial.documentation,
             metadata: partial.metadata,
-            operations: vec!(), // TODO implement me!
+            operations: partial.operations.into_iter().map(|(_, operation)| operation).collect(),
             shapes: shapes,
-        })
+            paginators: vec!(),
+            waiters: vec!(),
+            examples: vec!(),
+            derives: BTreeMap::new(),
+        };
+        // An example that does not fit the operation is only documentation gone stale.
+        service.add_examples(Examples(examples));
+        Ok(service)
+    }
+
+    pub fn shape(&self, name: &str) -> Option<&Shape> {
+        self.shapes.iter().find(|shape| shape.name == name)
+    }
+
+    pub fn operation(&self, name: &str) -> Option<&Operation> {
+        self.operations.iter().find(|operation| operation.name == name)
+    }
+
+    /// The names of `shapes` and of every shape they refer to, however indirectly.
+    pub fn reachable<'a, I: IntoIterator<Item = &'a str>>(&'a self, shapes: I) -> BTreeSet<&'a str> {
+        let mut reachable = BTreeSet::new();
+        let mut pending: Vec<&str> = shapes.into_iter().collect();
+        while let Some(name) = pending.pop() {
+            if reachable.insert(name) {
+                if let Some(shape) = self.shape(name) {
+                    pending.extend(shape.shape_type.references().into_iter().map(|(_, target)| target));
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Removes operations, with their paginators, waiters and examples. Shapes stay.
+    pub fn remove_operations(&mut self, names: &[String]) {
+        self.operations.retain(|operation| !names.contains(&operation.name));
+        self.paginators.retain(|paginator| !names.contains(&paginator.operation));
+        self.waiters.retain(|waiter| !names.contains(&waiter.operation));
+        self.examples.retain(|example| !names.contains(&example.operation));
+    }
+
+    /// Keeps only `operations`, and only the shapes their inputs, outputs and errors
+    /// reach, so that the rest of the service is not generated.
+    pub fn subset<S: AsRef<str>>(&mut self, operations: &[S]) -> Result<(), ParseError> {
+        for name in operations {
+            if self.operation(name.as_ref()).is_none() {
+                return Err(ParseError::UnknownOperation(name.as_ref().to_string()));
+            }
+        }
+        let removed: Vec<String> = self.operations.iter()
+            .filter(|operation| !operations.iter().any(|name| name.as_ref() == operation.name))
+            .map(|operation| operation.name.clone())
+            .collect();
+        self.remove_operations(&removed);
+
+        let kept: BTreeSet<String> = {
+            let roots = self.operations.iter().flat_map(|operation| {
+                let mut shapes = vec!(&operation.input.shape[..]);
+                shapes.extend(operation.output.as_ref().map(|output| &output.shape[..]));
+                shapes.extend(operation.errors.iter().map(|error| &error.shape[..]));
+                shapes
+            });
+            self.reachable(roots).into_iter().map(|name| name.to_string()).collect()
+        };
+        self.shapes.retain(|shape| kept.contains(&shape.name));
+        self.derives = self.derives.iter().filter(|&(name, _)| kept.contains(name))
+            .map(|(name, derives)| (name.clone(), derives.clone())).collect();
+        Ok(())
+    }
+
+    /// Attaches the companion `paginators-1.json` after checking it against this service.
+    pub fn add_paginators(&mut self, paginators: Paginators) -> Result<(), ParseError> {
+        for paginator in &paginators.0 {
+            try!(paginator.validate(self));
+        }
+        self.paginators = paginators.0;
+        Ok(())
+    }
+
+    pub fn paginator(&self, operation: &str) -> Option<&Paginator> {
+        self.paginators.iter().find(|paginator| paginator.operation == operation)
+    }
+
+    /// Attaches the companion `waiters-2.json` after checking it against this service.
+    pub fn add_waiters(&mut self, waiters: Waiters) -> Result<(), ParseError> {
+        for waiter in &waiters.0 {
+            try!(waiter.validate(self));
+        }
+        self.waiters = waiters.0;
+        Ok(())
+    }
+
+    pub fn waiter(&self, name: &str) -> Option<&Waiter> {
+        self.waiters.iter().find(|waiter| waiter.name == name)
+    }
+
+    /// Adds examples, from `examples-1.json` or the embedded section, leaving out
+    /// those that do not fit the operation shapes. Returns why each was left out.
+    pub fn add_examples(&mut self, examples: Examples) -> Vec<ParseError> {
+        let mut dropped = vec!();
+        for example in examples.0 {
+            match example.validate(self) {
+                Ok(()) => self.examples.push(example),
+                Err(err) => dropped.push(err),
+            }
+        }
+        dropped
+    }
+
+    pub fn examples_for(&self, operation: &str) -> Vec<&Example> {
+        self.examples.iter().filter(|example| example.operation == operation).collect()
     }
 
     pub fn parse_shapes(obj: &BTreeMap<String, Value>) -> Result<Vec<Shape>, ParseError> {
@@ -64,6 +205,31 @@ impl ServiceDefinition {
         }
         Ok(shapes)
     }
+
+    /// Streaming blobs cannot be buffered, so the only place one may appear is as
+    /// a structure's payload, where it is the whole HTTP body.
+    pub fn validate_streaming(shapes: &[Shape]) -> Result<(), ParseError> {
+        let is_stream = |name: &str| shapes.iter().any(|shape| {
+            shape.name == name && shape.shape_type == ShapeType::Blob(Blob(true))
+        });
+        for shape in shapes {
+            match shape.shape_type {
+                ShapeType::Structure(Structure(ref members)) |
+                ShapeType::Exception(Exception { ref members, .. }) => {
+                    for member in members {
+                        if is_stream(&member.shape) && member.location != Location::Payload {
+                            return Err(ParseError::InvalidPayload(format!("{}.{}", shape.name, member.name)));
+                        }
+                    }
+                }
+                ShapeType::List(List { member: ref element, .. }) if is_stream(element) => {
+                    return Err(ParseError::InvalidPayload(shape.name.clone()));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -74,6 +240,7 @@ mod test {
     use super::*;
     use super::PartialServiceDefinition;
     use ::testhelpers::fixture_reader;
+    use super::super::error::ParseError;
 
     #[test]
     #[allow(unused_mut)]
@@ -81,4 +248,46 @@ mod test {
         let mut fd = fixture_reader("services/lambda-2015-03-31");
         let _: PartialServiceDefinition = serde_json::from_reader(fd).unwrap();
     }
+
+    #[test]
+    fn lambda() {
+        let service = ServiceDefinition::parse(fixture_reader("services/lambda-2015-03-31")).unwrap();
+        assert_eq!(service.version, "2.0");
+        assert_eq!(service.metadata.endpointPrefix, "lambda");
+        assert_eq!(service.operations.len(), 24);
+        assert_eq!(service.shapes.len(), 85);
+        assert_eq!(service.operation("ListFunctions").unwrap().input.shape, "ListFunctionsRequest");
+        assert!(service.shape("ListFunctionsResponse").is_some());
+    }
+
+    #[test]
+    fn subset() {
+        let mut service = ServiceDefinition::parse(fixture_reader("services/lambda-2015-03-31")).unwrap();
+        service.derives.insert("Runtime".to_string(), vec!("Hash".to_string()));
+        service.derives.insert("AliasConfiguration".to_string(), vec!("Clone".to_string()));
+        service.subset(&["Invoke", "GetFunction"]).unwrap();
+        let operations: Vec<&str> = service.operations.iter().map(|operation| &operation.name[..]).collect();
+        assert_eq!(operations, vec!("GetFunction", "Invoke"));
+        assert!(service.shape("InvocationRequest").is_some());
+        assert!(service.shape("Runtime").is_some());
+        assert!(service.shape("ResourceNotFoundException").is_some());
+        assert!(service.shape("AliasConfiguration").is_none());
+        assert!(service.shape("InvokeAsyncRequest").is_none());
+        assert!(service.examples.iter().all(|example| service.operation(&example.operation).is_some()));
+        assert_eq!(service.derives.keys().collect::<Vec<_>>(), vec!("Runtime"));
+        for shape in &service.shapes {
+            for (_, target) in shape.shape_type.references() {
+                assert!(service.shape(target).is_some(), "{} refers to missing {}", shape.name, target);
+            }
+        }
+
+        let error = service.subset(&["InvokeAsync"]).unwrap_err();
+        assert_eq!(error, ParseError::UnknownOperation("InvokeAsync".to_string()));
+    }
+
+    #[test]
+    fn streaming_blob_must_be_payload() {
+        let output = ServiceDefinition::parse(fixture_reader("services/stream-not-payload"));
+        assert_eq!(output.unwrap_err(), ParseError::InvalidPayload("UploadRequest.Body".to_string()));
+    }
 }
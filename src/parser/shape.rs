@@ -3,12 +3,14 @@ extern crate serde_json;
 
 use serde_json::Value;
 use super::shape_type::ShapeType;
+use super::deprecation::Deprecation;
 use super::error::ParseError;
 
 #[derive(Debug, PartialEq)]
 pub struct Shape {
     pub shape_type: ShapeType,
     pub name: String,
+    pub deprecated: Option<Deprecation>,
 }
 
 impl Shape {
@@ -25,7 +27,8 @@ impl Shape {
         };
         Ok(Shape {
             name: name.to_string(),
-            shape_type: shape_type
+            shape_type: shape_type,
+            deprecated: try!(Deprecation::parse(obj)),
         })
     }
 }
@@ -43,12 +46,26 @@ mod test {
         fixture_btreemap("shapes/primitive-shapes").get(name).unwrap().clone()
     }
 
+    #[test]
+    fn blob() {
+        let output = Shape::parse("Blob", &primitive_shape("Blob"));
+        assert_eq!(output, Ok(Shape {
+            name:"Blob".to_string(),
+            shape_type: ShapeType::Blob(Blob(false)),
+            deprecated: None,
+        }));
+        let output = Shape::parse("BlobStream", &primitive_shape("BlobStream"));
+        assert_eq!(output.unwrap().shape_type, ShapeType::Blob(Blob(true)));
+        assert_eq!(Shape::parse("BadStream", &primitive_shape("BadStream")), Err(ParseError::InvalidStreaming));
+    }
+
     #[test]
     fn boolean() {
         let output = Shape::parse("Boolean", &primitive_shape("Boolean"));
         assert_eq!(output, Ok(Shape {
             name:"Boolean".to_string(),
             shape_type: ShapeType::Boolean,
+            deprecated: None,
         }));
     }
 
@@ -58,6 +75,7 @@ mod test {
         assert_eq!(output, Ok(Shape {
             name:"Double".to_string(),
             shape_type: ShapeType::Double,
+            deprecated: None,
         }));
     }
 
@@ -67,6 +85,7 @@ mod test {
         assert_eq!(output, Ok(Shape {
             name:"Date".to_string(),
             shape_type: ShapeType::Timestamp,
+            deprecated: None,
         }));
     }
 
@@ -76,6 +95,7 @@ mod test {
         assert_eq!(output, Ok(Shape {
             name:"Long".to_string(),
             shape_type: ShapeType::Long,
+            deprecated: None,
         }));
     }
 
@@ -85,6 +105,7 @@ mod test {
         assert_eq!(output, Ok(Shape {
             name:"Float".to_string(),
             shape_type: ShapeType::Float,
+            deprecated: None,
         }));
     }
 }
@@ -1,6 +1,7 @@
 use serde_json::Value;
 use std::collections::{BTreeMap, HashMap};
 use super::error::ParseError;
+use super::deprecation::Deprecation;
 
 #[derive(Debug, PartialEq)]
 pub enum ShapeType {
@@ -38,14 +39,51 @@ impl ShapeType {
             _ => Err(ParseError::InvalidTypeString)
         }
     }
+
+    /// A short name for the kind of shape, as `inspect` lists it.
+    pub fn kind(&self) -> &'static str {
+        match *self {
+            ShapeType::Blob(_) => "blob",
+            ShapeType::Boolean => "boolean",
+            ShapeType::Double => "double",
+            ShapeType::Float => "float",
+            ShapeType::Integer(_) => "integer",
+            ShapeType::List(_) => "list",
+            ShapeType::Long => "long",
+            ShapeType::StringEnum(_) => "enum",
+            ShapeType::StringPattern(_) => "string",
+            ShapeType::Structure(_) => "structure",
+            ShapeType::Exception(_) => "exception",
+            ShapeType::Timestamp => "timestamp",
+        }
+    }
+
+    /// The shapes this one refers to, each with the name of the member that does:
+    /// a structure's members, or `member` for a list's elements.
+    pub fn references(&self) -> Vec<(&str, &str)> {
+        match *self {
+            ShapeType::Structure(Structure(ref members)) => {
+                members.iter().map(|member| (&member.name[..], &member.shape[..])).collect()
+            }
+            ShapeType::Exception(ref exception) => {
+                exception.members.iter().map(|member| (&member.name[..], &member.shape[..])).collect()
+            }
+            ShapeType::List(List { ref member, .. }) => vec!(("member", &member[..])),
+            _ => vec!(),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
-pub struct Blob(bool);
+/// A blob; `true` when it is `streaming` and so may not fit in memory.
+pub struct Blob(pub bool);
 
 impl Blob {
     pub fn parse(obj: &BTreeMap<String, Value>) -> Result<ShapeType, ParseError> {
-        let streaming = obj.contains_key("streaming");
+        let streaming = match obj.get("streaming") {
+            Some(json) => try!(json.as_boolean().ok_or(ParseError::InvalidStreaming)),
+            None => false,
+        };
         Ok(ShapeType::Blob(Blob(streaming)))
     }
 }
@@ -80,7 +118,11 @@ impl Integer {
 }
 
 #[derive(Debug, PartialEq)]
-pub struct List(pub String);
+pub struct List {
+    pub member: String,
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+}
 
 impl List {
     pub fn parse(obj: &BTreeMap<String, Value>) -> Result<ShapeType, ParseError> {
@@ -88,7 +130,19 @@ impl List {
         let member = try!(json.as_object().ok_or(ParseError::InvalidListMember));
         let json = try!(member.get("shape").ok_or(ParseError::MissingListShape));
         let shape = try!(json.as_string().ok_or(ParseError::InvalidListShape));
-        Ok(ShapeType::List(List(shape.to_string())))
+        let max = match obj.get("max") {
+            Some(json) => Some(try!(json.as_i64().ok_or(ParseError::InvalidListMax))),
+            None => None,
+        };
+        let min = match obj.get("min") {
+            Some(json) => Some(try!(json.as_i64().ok_or(ParseError::InvalidListMin))),
+            None => None,
+        };
+        Ok(ShapeType::List(List {
+            member: shape.to_string(),
+            min: min,
+            max: max,
+        }))
     }
 }
 
@@ -165,7 +219,7 @@ pub fn parse_structure_or_exception(obj: &BTreeMap<String, Value>) -> Result<Sha
 }
 
 #[derive(Debug, PartialEq)]
-// TODO - refactor to have members and payload: Option<String> -- validate string is a member name...or do Option<&Member>
+/// The structure's `payload`, if any, is the member with `Location::Payload`.
 pub struct Structure(pub Vec<Member>);
 
 impl Structure {
@@ -190,6 +244,14 @@ impl Structure {
             let member = try!(Member::parse(name, required, raw_member));
             members.push(member);
         }
+
+        // The payload member is sent as the whole HTTP body.
+        if let Some(json) = obj.get("payload") {
+            let payload = try!(json.as_string().ok_or(ParseError::InvalidPayload("payload".to_string())));
+            let member = try!(members.iter_mut().find(|member| member.name == payload)
+                .ok_or(ParseError::InvalidPayload(payload.to_string())));
+            member.location = Location::Payload;
+        }
         Ok(ShapeType::Structure(Structure(members)))
     }
 }
@@ -233,6 +295,7 @@ pub struct Member {
     pub documentation: Option<String>,
     pub name: String,
     pub location: Location,
+    pub deprecated: Option<Deprecation>,
 }
 
 impl Member {
@@ -245,6 +308,7 @@ impl Member {
         let shape = try!(shape_json.as_string().ok_or(ParseError::InvalidMember(name.to_string())));
         let documentation = obj.get("documentation").map(|d| d.as_string().unwrap().to_string());
         let location = try!(Location::parse(obj.get("location"), obj.get("locationName")));
+        let deprecated = try!(Deprecation::parse(obj).map_err(|_| ParseError::InvalidMember(name.to_string())));
 
         Ok(Member {
             name: name.to_string(),
@@ -252,6 +316,7 @@ impl Member {
             documentation: documentation,
             shape: shape.to_string(),
             location: location,
+            deprecated: deprecated,
         })
     }
 }
@@ -259,6 +324,7 @@ impl Member {
 #[derive(Debug, PartialEq)]
 pub enum Location {
     Body,
+    Payload,
     StatusCode,
     URI(String),
     QueryString(String),
@@ -364,7 +430,21 @@ mod test {
     #[test]
     fn list() {
         let output = ShapeType::parse(&fixture_btreemap("shape-types/list"));
-        assert_eq!(output, Ok(ShapeType::List(List("AliasConfiguration".to_string()))));
+        assert_eq!(output, Ok(ShapeType::List(List {
+            member: "AliasConfiguration".to_string(),
+            min: None,
+            max: None,
+        })));
+    }
+
+    #[test]
+    fn list_length() {
+        let output = ShapeType::parse(&fixture_btreemap("shape-types/list-length"));
+        assert_eq!(output, Ok(ShapeType::List(List {
+            member: "SubnetId".to_string(),
+            min: Some(1),
+            max: Some(16),
+        })));
     }
 
     #[test]
@@ -442,6 +522,30 @@ mod test {
         panic!("Member not found: {:?}", needle);
     }
 
+    #[test]
+    fn structure_payload() {
+        let output = ShapeType::parse(&fixture_btreemap("shape-types/structure-invoke-async-request"));
+        match output.unwrap() {
+            ShapeType::Structure(Structure(members)) => {
+                assert_has_member(&members, Member {
+                    name: "InvokeArgs".to_string(),
+                    required: true,
+                    documentation: Some("<p>JSON that you want to provide to your Lambda function as input.</p>".to_string()),
+                    shape: "BlobStream".to_string(),
+                    location: Location::Payload,
+                    deprecated: None,
+                });
+            }
+            _ => panic!("Not a structure!"),
+        }
+    }
+
+    #[test]
+    fn structure_unknown_payload() {
+        let output = ShapeType::parse(&fixture_btreemap("shape-types/structure-unknown-payload"));
+        assert_eq!(output, Err(ParseError::InvalidPayload("Body".to_string())));
+    }
+
     #[test]
     fn structure_add_permission_request() {
         let output = ShapeType::parse(&fixture_btreemap("shape-types/structure-add-permission-request"));
@@ -453,6 +557,7 @@ mod test {
                     documentation: Some("<p>Name of the Lambda function whose resource policy you are updating by adding a new permission.</p> <p> You can specify an unqualified function name (for example, \"Thumbnail\") or you can specify Amazon Resource Name (ARN) of the function (for example, \"arn:aws:lambda:us-west-2:account-id:function:ThumbNail\"). AWS Lambda also allows you to specify only the account ID qualifier (for example, \"account-id:Thumbnail\"). Note that the length constraint applies only to the ARN. If you specify only the function name, it is limited to 64 character in length. </p>".to_string()),
                     shape: "FunctionName".to_string(),
                     location: Location::URI("FunctionName".to_string()),
+                    deprecated: None,
                 });
                 assert_has_member(&members, Member {
                     name: "StatementId".to_string(),
@@ -460,6 +565,7 @@ mod test {
                     documentation: Some("<p>A unique statement identifier.</p>".to_string()),
                     shape: "StatementId".to_string(),
                     location: Location::Body,
+                    deprecated: None,
                 });
                 assert_has_member(&members, Member {
                     name: "Action".to_string(),
@@ -467,6 +573,7 @@ mod test {
                     documentation: Some("<p>The AWS Lambda action you want to allow in this statement. Each Lambda action is a string starting with \"lambda:\" followed by the API name (see <a>Operations</a>). For example, \"lambda:CreateFunction\". You can use wildcard (\"lambda:*\") to grant permission for all AWS Lambda actions. </p>".to_string()),
                     shape: "Action".to_string(),
                     location: Location::Body,
+                    deprecated: None,
                 });
                 assert_has_member(&members, Member {
                     name: "Principal".to_string(),
@@ -474,6 +581,7 @@ mod test {
                     documentation: Some("<p>The principal who is getting this permission. It can be Amazon S3 service Principal (\"s3.amazonaws.com\") if you want Amazon S3 to invoke the function, an AWS account ID if you are granting cross-account permission, or any valid AWS service principal such as \"sns.amazonaws.com\". For example, you might want to allow a custom application in another AWS account to push events to AWS Lambda by invoking your function. </p>".to_string()),
                     shape: "Principal".to_string(),
                     location: Location::Body,
+                    deprecated: None,
                 });
                 assert_has_member(&members, Member {
                     name: "SourceArn".to_string(),
@@ -481,6 +589,7 @@ mod test {
                     documentation: Some("<p>This is optional; however, when granting Amazon S3 permission to invoke your function, you should specify this field with the bucket Amazon Resource Name (ARN) as its value. This ensures that only events generated from the specified bucket can invoke the function. </p> <important>If you add a permission for the Amazon S3 principal without providing the source ARN, any AWS account that creates a mapping to your function ARN can send events to invoke your Lambda function from Amazon S3.</important>".to_string()),
                     shape: "Arn".to_string(),
                     location: Location::Body,
+                    deprecated: None,
                 });
                 assert_has_member(&members, Member {
                     name: "SourceAccount".to_string(),
@@ -488,6 +597,7 @@ mod test {
                     documentation: Some("<p>The AWS account ID (without a hyphen) of the source owner. For example, if the <code>SourceArn</code> identifies a bucket, then this is the bucket owner's account ID. You can use this additional condition to ensure the bucket you specify is owned by a specific account (it is possible the bucket owner deleted the bucket and some other AWS account created the bucket). You can also use this condition to specify all sources (that is, you don't specify the <code>SourceArn</code>) owned by a specific account. </p>".to_string()),
                     shape: "SourceOwner".to_string(),
                     location: Location::Body,
+                    deprecated: None,
                 });
                 assert_has_member(&members, Member {
                     name: "Qualifier".to_string(),
@@ -495,6 +605,7 @@ mod test {
                     documentation: Some("<p>You can specify this optional query parameter to specify function version or alias name. The permission will then apply to the specific qualified ARN. For example, if you specify function version 2 as the qualifier, then permission applies only when request is made using qualified function ARN: </p> <p><code>arn:aws:lambda:aws-region:acct-id:function:function-name:2</code></p> <p>If you specify alias name, for example \"PROD\", then the permission is valid only for requests made using the alias ARN:</p> <p><code>arn:aws:lambda:aws-region:acct-id:function:function-name:PROD</code></p> <p>If the qualifier is not specified, the permission is valid only when requests is made using unqualified function ARN. </p> <p><code>arn:aws:lambda:aws-region:acct-id:function:function-name</code></p>".to_string()),
                     shape: "Qualifier".to_string(),
                     location: Location::QueryString("Qualifier".to_string()),
+                    deprecated: None,
                 });
             }
             _ => panic!("Wrong type")
@@ -515,6 +626,7 @@ mod test {
                     shape: "String".to_string(),
                     documentation: Some("<p>The number of seconds the caller should wait before retrying.</p>".to_string()),
                     location: Location::Header("Retry-After".to_string()),
+                    deprecated: None,
                 });
                 assert_has_member(&e.members, Member {
                     name: "Type".to_string(),
@@ -522,6 +634,7 @@ mod test {
                     shape: "String".to_string(),
                     documentation: None,
                     location: Location::Body,
+                    deprecated: None,
                 });
                 assert_has_member(&e.members, Member {
                     name: "message".to_string(),
@@ -529,6 +642,7 @@ mod test {
                     shape: "String".to_string(),
                     documentation: None,
                     location: Location::Body,
+                    deprecated: None,
                 });
             }
             _ => panic!("Wrong type!")
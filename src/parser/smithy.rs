@@ -0,0 +1,517 @@
+//! Reads Smithy JSON AST models, which AWS now publishes instead of botocore's, into
+//! the same types, so that everything downstream works on either.
+
+use std::collections::{BTreeMap, BTreeSet};
+use serde_json::Value;
+use super::deprecation::Deprecation;
+use super::error::ParseError;
+use super::operation::{self, Input, Operation, Output, HTTP, HTTPError};
+use super::service_definition::{Metadata, ServiceDefinition};
+use super::shape::Shape;
+use super::shape_type::*;
+
+/// The namespace of Smithy's prelude: `String`, `Integer`, `Unit` and so on.
+const PRELUDE: &'static str = "smithy.api#";
+
+/// The protocol traits, each with the `protocol` botocore calls it.
+const PROTOCOLS: &'static [(&'static str, &'static str)] = &[
+    ("aws.protocols#restJson1", "rest-json"),
+    ("aws.protocols#restXml", "rest-xml"),
+    ("aws.protocols#awsJson1_0", "json"),
+    ("aws.protocols#awsJson1_1", "json"),
+    ("aws.protocols#awsQuery", "query"),
+    ("aws.protocols#ec2Query", "ec2"),
+];
+
+impl ServiceDefinition {
+    /// Lowers a Smithy JSON AST model (`"smithy": "2.0"` or `"1.0"`) with one service
+    /// in it. Shapes lose their namespace, so `com.amazonaws.lambda#Runtime` becomes
+    /// `Runtime`, and only those the service's operations reach are kept, prelude
+    /// shapes included. A prelude shape whose name the model gives one of its own
+    /// shapes is prefixed instead, so `smithy.api#String` becomes `SmithyString` next
+    /// to a `com.amazonaws.budgets#String`. Traits become the fields botocore has for them: `required`,
+    /// the `http` bindings as `Location`s, and `error` and `httpError` as `Exception`s.
+    ///
+    /// Maps, unions, documents and big numbers have no `ShapeType` yet, so a service
+    /// that uses them is an error, as it is in botocore's format.
+    pub fn from_smithy(json: &Value) -> Result<ServiceDefinition, ParseError> {
+        let shapes = try!(json.find("shapes").and_then(|shapes| shapes.as_object())
+            .ok_or(ParseError::ServiceDefinitionMissingShapes));
+        let model = Model { shapes: shapes };
+        let services: Vec<&String> = shapes.iter().filter(|&(_, shape)| type_of(shape) == "service")
+                                           .map(|(id, _)| id).collect();
+        if services.len() != 1 {
+            return Err(invalid(format!("expected one service, found {}", services.len())));
+        }
+        let service_id = services[0];
+        let service = &shapes[service_id];
+
+        let mut operation_ids = vec!();
+        try!(model.collect_operations(service, &mut operation_ids));
+        let common_errors = targets(service, "errors");
+        let local = shapes.keys().filter(|id| prelude(id).is_none()).map(|id| local_name(id).to_string()).collect();
+        let mut names = Names(BTreeMap::new(), local);
+        let mut operations = vec!();
+        for id in operation_ids {
+            operations.push(try!(model.lower_operation(id, &common_errors, &mut names)));
+        }
+        operations.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut lowered = vec!();
+        let mut pending: Vec<String> = names.0.values().cloned().collect();
+        while let Some(id) = pending.pop() {
+            let name = try!(names.name(&id));
+            if lowered.iter().any(|shape: &Shape| shape.name == name) {
+                continue;
+            }
+            let shape_type = try!(model.lower_shape(&id, &mut names));
+            for (_, target) in shape_type.references() {
+                pending.push(names.0[target].clone());
+            }
+            let deprecated = match prelude(&id) {
+                Some(_) => None,
+                None => deprecation(try!(model.shape(&id))),
+            };
+            lowered.push(Shape {
+                name: name,
+                shape_type: shape_type,
+                deprecated: deprecated,
+            });
+        }
+        for operation in &operations {
+            if !lowered.iter().any(|shape| shape.name == operation.input.shape) {
+                // An operation without input still takes an (empty) structure.
+                lowered.push(Shape {
+                    name: operation.input.shape.clone(),
+                    shape_type: ShapeType::Structure(Structure(vec!())),
+                    deprecated: None,
+                });
+            }
+        }
+        lowered.sort_by(|a, b| a.name.cmp(&b.name));
+        try!(ServiceDefinition::validate_streaming(&lowered));
+
+        Ok(ServiceDefinition {
+            version: json.find("smithy").and_then(|version| version.as_string()).unwrap_or("").to_string(),
+            documentation: string_trait(service, "smithy.api#documentation").unwrap_or("").to_string(),
+            metadata: try!(metadata(service_id, service)),
+            operations: operations,
+            shapes: lowered,
+            paginators: vec!(),
+            waiters: vec!(),
+            examples: vec!(),
+            derives: BTreeMap::new(),
+        })
+    }
+}
+
+struct Model<'a> {
+    shapes: &'a BTreeMap<String, Value>,
+}
+
+/// The absolute shape ID behind each name handed out so far, and the local names
+/// of the model's own shapes.
+struct Names(BTreeMap<String, String>, BTreeSet<String>);
+
+impl Names {
+    /// The name of the shape `id`, which must not be taken by a shape from another namespace.
+    fn name(&mut self, id: &str) -> Result<String, ParseError> {
+        let name = match prelude(id) {
+            Some(_) if self.1.contains(local_name(id)) => format!("Smithy{}", local_name(id)),
+            _ => local_name(id).to_string(),
+        };
+        match self.0.get(&name) {
+            Some(other) if other != id => {
+                return Err(invalid(format!("{} and {} would both be named {}", other, id, name)))
+            }
+            _ => {}
+        }
+        self.0.insert(name.clone(), id.to_string());
+        Ok(name)
+    }
+}
+
+impl<'a> Model<'a> {
+    fn shape(&self, id: &str) -> Result<&'a Value, ParseError> {
+        self.shapes.get(id).ok_or(invalid(format!("{} is not in the model", id)))
+    }
+
+    /// The operations of the service or resource `json`, and of its resources, in order.
+    fn collect_operations(&self, json: &'a Value, operations: &mut Vec<&'a str>) -> Result<(), ParseError> {
+        for lifecycle in &["create", "put", "read", "update", "delete", "list"] {
+            if let Some(id) = json.find_path(&[lifecycle, "target"]).and_then(|id| id.as_string()) {
+                operations.push(id);
+            }
+        }
+        operations.extend(targets(json, "operations"));
+        operations.extend(targets(json, "collectionOperations"));
+        for resource in targets(json, "resources") {
+            try!(self.collect_operations(try!(self.shape(resource)), operations));
+        }
+        Ok(())
+    }
+
+    fn lower_operation(&self, id: &str, common_errors: &[&str], names: &mut Names) -> Result<Operation, ParseError> {
+        let json = try!(self.shape(id));
+        let name = local_name(id).to_string();
+        let input = match json.find_path(&["input", "target"]).and_then(|id| id.as_string()) {
+            Some(id) if id != "smithy.api#Unit" => try!(names.name(id)),
+            _ => format!("{}Request", name),
+        };
+        let output = match json.find_path(&["output", "target"]).and_then(|id| id.as_string()) {
+            Some(id) if id != "smithy.api#Unit" => Some(Output {
+                shape: try!(names.name(id)),
+                documentation: None,
+                deprecated: None,
+            }),
+            _ => None,
+        };
+        let mut errors = vec!();
+        for error in targets(json, "errors").into_iter().chain(common_errors.iter().cloned()) {
+            errors.push(operation::Error {
+                shape: try!(names.name(error)),
+                error: HTTPError { httpStatusCode: try!(status_code(error, try!(self.shape(error)))) as i32 },
+                exception: true,
+                documentation: None,
+                deprecated: None,
+            });
+        }
+        // Operations without an `http` trait (the JSON and query protocols) all post to `/`.
+        let http = json.find_path(&["traits", "smithy.api#http"]);
+        let field = |key: &str| http.and_then(|http| http.find(key));
+        Ok(Operation {
+            name: name,
+            http: HTTP {
+                method: field("method").and_then(|method| method.as_string()).unwrap_or("POST").to_string(),
+                requestUri: field("uri").and_then(|uri| uri.as_string()).unwrap_or("/").to_string(),
+                responseCode: field("code").and_then(|code| code.as_i64()).map(|code| code as i32),
+            },
+            input: Input {
+                shape: input,
+                deprecated: None,
+            },
+            output: output,
+            errors: errors,
+            deprecated: if has_trait(json, "smithy.api#deprecated") { Some(true) } else { None },
+            deprecatedMessage: deprecation(json).and_then(|deprecation| deprecation.message),
+            documentation: string_trait(json, "smithy.api#documentation").map(|doc| doc.to_string()),
+        })
+    }
+
+    fn lower_shape(&self, id: &str, names: &mut Names) -> Result<ShapeType, ParseError> {
+        let json = match prelude(id) {
+            Some(json) => json,
+            None => try!(self.shape(id)).clone(),
+        };
+        let json = &json;
+        let shape_type = match type_of(json) {
+            "blob" => ShapeType::Blob(Blob(has_trait(json, "smithy.api#streaming"))),
+            "boolean" => ShapeType::Boolean,
+            "byte" | "short" | "integer" | "intEnum" => ShapeType::Integer(Integer {
+                min: try!(bound(id, json, "smithy.api#range", "min")),
+                max: try!(bound(id, json, "smithy.api#range", "max")),
+            }),
+            "long" => ShapeType::Long,
+            "float" => ShapeType::Float,
+            "double" => ShapeType::Double,
+            "timestamp" => ShapeType::Timestamp,
+            "string" => match json.find_path(&["traits", "smithy.api#enum"]).and_then(|values| values.as_array()) {
+                Some(definitions) => {
+                    let mut values = vec!();
+                    for definition in definitions {
+                        let value = try!(definition.find("value").and_then(|value| value.as_string())
+                            .ok_or(ParseError::InvalidStringVariant));
+                        values.push(value.to_string());
+                    }
+                    ShapeType::StringEnum(StringEnum(values))
+                }
+                None => ShapeType::StringPattern(StringPattern {
+                    pattern: string_trait(json, "smithy.api#pattern").unwrap_or(".*").to_string(),
+                    // As in botocore's format, a minimum length of 0 is no minimum.
+                    min: try!(bound(id, json, "smithy.api#length", "min")).and_then(|min| {
+                        if min == 0 { None } else { Some(min) }
+                    }),
+                    max: try!(bound(id, json, "smithy.api#length", "max")),
+                }),
+            },
+            "enum" => {
+                // serde_json keeps members sorted by name, so values come out in that order.
+                let members = try!(json.find("members").and_then(|members| members.as_object())
+                    .ok_or(ParseError::InvalidStringEnum));
+                let values = members.iter().map(|(name, member)| {
+                    string_trait(member, "smithy.api#enumValue").unwrap_or(name).to_string()
+                }).collect();
+                ShapeType::StringEnum(StringEnum(values))
+            }
+            "list" | "set" => {
+                let member = try!(json.find_path(&["member", "target"]).and_then(|target| target.as_string())
+                    .ok_or(ParseError::MissingListShape));
+                ShapeType::List(List {
+                    member: try!(names.name(member)),
+                    min: try!(bound(id, json, "smithy.api#length", "min")),
+                    max: try!(bound(id, json, "smithy.api#length", "max")),
+                })
+            }
+            "structure" => {
+                let members = try!(self.lower_members(id, json, names));
+                if has_trait(json, "smithy.api#error") {
+                    ShapeType::Exception(Exception {
+                        members: members,
+                        status_code: try!(status_code(id, json)),
+                        documentation: string_trait(json, "smithy.api#documentation").map(|doc| doc.to_string()),
+                    })
+                } else {
+                    ShapeType::Structure(Structure(members))
+                }
+            }
+            other => return Err(invalid(format!("{} is a {}, which has no shape type here", id, other))),
+        };
+        Ok(shape_type)
+    }
+
+    fn lower_members(&self, id: &str, json: &Value, names: &mut Names) -> Result<Vec<Member>, ParseError> {
+        let members = try!(json.find("members").and_then(|members| members.as_object())
+            .ok_or(ParseError::StructureHasNoMembers));
+        let mut lowered = vec!();
+        for (name, member) in members {
+            let target = try!(member.find("target").and_then(|target| target.as_string())
+                .ok_or(ParseError::InvalidMember(format!("{}${}", id, name))));
+            let location = if let Some(header) = string_trait(member, "smithy.api#httpHeader") {
+                Location::Header(header.to_string())
+            } else if let Some(query) = string_trait(member, "smithy.api#httpQuery") {
+                Location::QueryString(query.to_string())
+            } else if has_trait(member, "smithy.api#httpLabel") {
+                Location::URI(name.clone())
+            } else if has_trait(member, "smithy.api#httpPayload") {
+                Location::Payload
+            } else if has_trait(member, "smithy.api#httpResponseCode") {
+                Location::StatusCode
+            } else {
+                Location::Body
+            };
+            lowered.push(Member {
+                shape: try!(names.name(target)),
+                required: has_trait(member, "smithy.api#required"),
+                documentation: string_trait(member, "smithy.api#documentation").map(|doc| doc.to_string()),
+                name: name.clone(),
+                location: location,
+                deprecated: deprecation(member),
+            });
+        }
+        Ok(lowered)
+    }
+}
+
+fn metadata(id: &str, service: &Value) -> Result<Metadata, ParseError> {
+    let aws = |key: &str| service.find_path(&["traits", "aws.api#service", key]).and_then(|value| value.as_string());
+    let endpoint_prefix = aws("endpointPrefix").or(aws("arnNamespace")).map(|prefix| prefix.to_string())
+                                               .unwrap_or(local_name(id).to_lowercase());
+    let signing_name = service.find_path(&["traits", "aws.auth#sigv4", "name"]).and_then(|name| name.as_string());
+    let protocol = try!(PROTOCOLS.iter().find(|&&(protocol, _)| has_trait(service, protocol))
+        .ok_or(invalid(format!("{} has no protocol trait botocore knows", id))));
+    Ok(Metadata {
+        apiVersion: try!(service.find("version").and_then(|version| version.as_string())
+            .ok_or(invalid(format!("{} has no version", id)))).to_string(),
+        serviceFullName: string_trait(service, "smithy.api#title").or(aws("sdkId")).unwrap_or(local_name(id))
+                                                                   .to_string(),
+        globalEndpoint: None,
+        signatureVersion: if signing_name.is_some() { "v4" } else { "none" }.to_string(),
+        signingName: signing_name.and_then(|name| if name == endpoint_prefix { None } else { Some(name.to_string()) }),
+        endpointPrefix: endpoint_prefix,
+        protocol: protocol.1.to_string(),
+    })
+}
+
+/// The prelude shapes as the model would declare them, or `None` if `id` is not one.
+fn prelude(id: &str) -> Option<Value> {
+    if !id.starts_with(PRELUDE) {
+        return None;
+    }
+    let shape_type = match &id[PRELUDE.len()..] {
+        "String" => "string",
+        "Blob" => "blob",
+        "Boolean" | "PrimitiveBoolean" => "boolean",
+        "Byte" | "PrimitiveByte" | "Short" | "PrimitiveShort" | "Integer" | "PrimitiveInteger" => "integer",
+        "Long" | "PrimitiveLong" => "long",
+        "Float" | "PrimitiveFloat" => "float",
+        "Double" | "PrimitiveDouble" => "double",
+        "Timestamp" => "timestamp",
+        "BigInteger" => "bigInteger",
+        "BigDecimal" => "bigDecimal",
+        _ => "document",
+    };
+    let mut shape = BTreeMap::new();
+    shape.insert("type".to_string(), Value::String(shape_type.to_string()));
+    Some(Value::Object(shape))
+}
+
+/// The HTTP status of an error: its `httpError` trait, or else 400 for the client's
+/// fault and 500 for the server's.
+fn status_code(id: &str, json: &Value) -> Result<i64, ParseError> {
+    if let Some(status) = json.find_path(&["traits", "smithy.api#httpError"]).and_then(|status| status.as_i64()) {
+        return Ok(status);
+    }
+    match string_trait(json, "smithy.api#error") {
+        Some("client") => Ok(400),
+        Some("server") => Ok(500),
+        _ => Err(invalid(format!("{} is not an error", id))),
+    }
+}
+
+/// A `length` or `range` bound, which Smithy allows to be written as a float.
+fn bound(id: &str, json: &Value, constraint: &str, key: &str) -> Result<Option<i64>, ParseError> {
+    match json.find_path(&["traits", constraint, key]) {
+        None => Ok(None),
+        Some(value) => match (value.as_i64(), value.as_f64()) {
+            (Some(bound), _) => Ok(Some(bound)),
+            (None, Some(bound)) if bound.fract() == 0.0 => Ok(Some(bound as i64)),
+            _ => Err(invalid(format!("{}: {} {} is not a whole number", id, constraint, key))),
+        },
+    }
+}
+
+fn targets<'a>(json: &'a Value, key: &str) -> Vec<&'a str> {
+    json.find(key).and_then(|list| list.as_array()).map_or(vec!(), |list| {
+        list.iter().filter_map(|reference| reference.find("target").and_then(|target| target.as_string())).collect()
+    })
+}
+
+fn has_trait(json: &Value, name: &str) -> bool {
+    json.find_path(&["traits", name]).is_some()
+}
+
+fn deprecation(json: &Value) -> Option<Deprecation> {
+    json.find_path(&["traits", "smithy.api#deprecated"]).map(|deprecated| Deprecation {
+        message: deprecated.find("message").and_then(|message| message.as_string()).map(|message| message.to_string()),
+    })
+}
+
+fn string_trait<'a>(json: &'a Value, name: &str) -> Option<&'a str> {
+    json.find_path(&["traits", name]).and_then(|value| value.as_string())
+}
+
+fn type_of(json: &Value) -> &str {
+    json.find("type").and_then(|shape_type| shape_type.as_string()).unwrap_or("")
+}
+
+fn local_name(id: &str) -> &str {
+    id.rsplit('#').next().unwrap_or(id)
+}
+
+fn invalid(message: String) -> ParseError {
+    ParseError::InvalidSmithy(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use ::parser::*;
+    use ::testhelpers::fixture_service;
+
+    #[test]
+    fn same_as_botocore() {
+        let botocore = fixture_service("services/recursive");
+        let smithy = fixture_service("smithy/recursive");
+        assert_eq!(smithy.metadata, botocore.metadata);
+        assert_eq!(smithy.documentation, botocore.documentation);
+        assert_eq!(smithy.shapes, botocore.shapes);
+        let (smithy, botocore) = (&smithy.operations[0], &botocore.operations[0]);
+        assert_eq!((&smithy.name, &smithy.http.method, &smithy.http.requestUri),
+                   (&botocore.name, &botocore.http.method, &botocore.http.requestUri));
+        assert_eq!(smithy.input.shape, botocore.input.shape);
+        assert_eq!(smithy.output.as_ref().map(|output| &output.shape),
+                   botocore.output.as_ref().map(|output| &output.shape));
+    }
+
+    #[test]
+    fn lambda_traits() {
+        let service = fixture_service("smithy/lambda");
+        assert_eq!(service.metadata.serviceFullName, "AWS Lambda");
+        assert_eq!(service.metadata.protocol, "rest-json");
+        assert_eq!(service.metadata.signingName, None);
+        let names: Vec<&str> = service.operations.iter().map(|operation| &operation.name[..]).collect();
+        assert_eq!(names, vec!("GetAccountSettings", "GetPolicy", "Invoke", "InvokeAsync"));
+
+        let get_policy = service.operation("GetPolicy").unwrap();
+        assert_eq!(get_policy.http.requestUri, "/2015-03-31/functions/{FunctionName}/policy");
+        let errors: Vec<(&str, i32)> = get_policy.errors.iter()
+            .map(|error| (&error.shape[..], error.error.httpStatusCode)).collect();
+        assert_eq!(errors, vec!(("ResourceNotFoundException", 404), ("ServiceException", 500)));
+        assert_eq!(service.operation("InvokeAsync").unwrap().deprecated, Some(true));
+        assert_eq!(service.operation("InvokeAsync").unwrap().http.responseCode, Some(202));
+
+        let settings = service.operation("GetAccountSettings").unwrap();
+        assert_eq!(settings.input.shape, "GetAccountSettingsRequest");
+        assert_eq!(service.shape("GetAccountSettingsRequest").unwrap().shape_type,
+                   ShapeType::Structure(Structure(vec!())));
+
+        let members = match service.shape("GetPolicyRequest").unwrap().shape_type {
+            ShapeType::Structure(Structure(ref members)) => members,
+            ref other => panic!("{:?}", other),
+        };
+        assert_eq!(members[0].location, Location::URI("FunctionName".to_string()));
+        assert!(members[0].required);
+        assert_eq!(members[0].documentation, Some("<p>The name of the function.</p>".to_string()));
+        assert_eq!(members[1].location, Location::QueryString("Qualifier".to_string()));
+        assert!(!members[1].required);
+
+        let members = match service.shape("InvocationResponse").unwrap().shape_type {
+            ShapeType::Structure(Structure(ref members)) => members,
+            ref other => panic!("{:?}", other),
+        };
+        let locations: Vec<&Location> = members.iter().map(|member| &member.location).collect();
+        assert_eq!(locations, vec!(&Location::Header("X-Amz-Function-Error".to_string()), &Location::Payload,
+                                   &Location::StatusCode));
+
+        match service.shape("ResourceNotFoundException").unwrap().shape_type {
+            ShapeType::Exception(ref exception) => assert_eq!(exception.status_code, 404),
+            ref other => panic!("{:?}", other),
+        }
+        assert_eq!(service.shape("MemorySize").unwrap().shape_type,
+                   ShapeType::Integer(Integer { min: Some(128), max: Some(10240) }));
+        assert_eq!(service.shape("Runtime").unwrap().shape_type,
+                   ShapeType::StringEnum(StringEnum(vec!("nodejs".to_string(), "java8".to_string(),
+                                                         "python2.7".to_string()))));
+        assert_eq!(service.shape("InvocationType").unwrap().shape_type,
+                   ShapeType::StringEnum(StringEnum(vec!("DryRun".to_string(), "Event".to_string(),
+                                                         "RequestResponse".to_string()))));
+        assert_eq!(service.shape("BlobStream").unwrap().shape_type, ShapeType::Blob(Blob(true)));
+        assert_eq!(service.shape("Long").unwrap().shape_type, ShapeType::Long);
+        assert!(service.shape("Unit").is_none());
+    }
+
+    #[test]
+    fn prelude_names() {
+        let service = fixture_service("smithy/prelude");
+        let members = match service.shape("ListBudgetsRequest").unwrap().shape_type {
+            ShapeType::Structure(Structure(ref members)) => members,
+            ref other => panic!("{:?}", other),
+        };
+        let shapes: Vec<(&str, &str)> = members.iter().map(|member| (&member.name[..], &member.shape[..])).collect();
+        assert_eq!(shapes, vec!(("AccountId", "String"), ("Detailed", "Boolean"), ("MaxResults", "Integer"),
+                                ("NextToken", "SmithyString"), ("Page", "SmithyInteger")));
+        assert_eq!(service.shape("String").unwrap().shape_type, ShapeType::StringPattern(StringPattern {
+            pattern: "\\d{12}".to_string(),
+            min: Some(12),
+            max: Some(12),
+        }));
+        assert_eq!(service.shape("Integer").unwrap().shape_type,
+                   ShapeType::Integer(Integer { min: Some(1), max: Some(100) }));
+        assert_eq!(service.shape("SmithyString").unwrap().shape_type, ShapeType::StringPattern(StringPattern {
+            pattern: ".*".to_string(),
+            min: None,
+            max: None,
+        }));
+        assert_eq!(service.shape("SmithyInteger").unwrap().shape_type,
+                   ShapeType::Integer(Integer { min: None, max: None }));
+        assert_eq!(service.shape("Boolean").unwrap().shape_type, ShapeType::Boolean);
+    }
+
+    #[test]
+    fn generates() {
+        let service = fixture_service("smithy/lambda");
+        let mut buffer = Vec::new();
+        assert!(service.generate_module(&mut buffer, None).is_ok());
+        let generated = String::from_utf8(buffer).unwrap();
+        assert!(generated.contains("    fn get_policy(&self, input: &GetPolicyRequest) -> Result<GetPolicyResponse, Self::Error>;"));
+    }
+}
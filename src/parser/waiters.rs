@@ -0,0 +1,218 @@
+extern crate serde;
+extern crate serde_json;
+
+use std::io::Read;
+use std::collections::BTreeMap;
+use serde_json::Value;
+use super::error::ParseError;
+use super::path::Path;
+use super::service_definition::ServiceDefinition;
+
+/// The contents of a `waiters-2.json` companion file.
+#[derive(Debug, PartialEq)]
+pub struct Waiters(pub Vec<Waiter>);
+
+#[derive(Debug, PartialEq)]
+pub struct Waiter {
+    pub name: String,
+    pub operation: String,
+    pub delay: i64,
+    pub max_attempts: i64,
+    pub description: Option<String>,
+    pub acceptors: Vec<Acceptor>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Acceptor {
+    pub state: AcceptorState,
+    pub matcher: Matcher,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AcceptorState {
+    Success,
+    Failure,
+    Retry,
+}
+
+/// What an acceptor looks at. `expected` is kept as JSON since it may be a
+/// string, number or boolean depending on the shape `argument` points to.
+#[derive(Debug, PartialEq)]
+pub enum Matcher {
+    Path { argument: Path, expected: Value },      // the value equals expected
+    PathAll { argument: Path, expected: Value },   // every projected value equals expected
+    PathAny { argument: Path, expected: Value },   // at least one projected value does
+    Status(i64),                                   // the HTTP status code
+    Error(String),                                 // the error code
+}
+
+impl Waiters {
+    pub fn parse<R: Read>(fd: R) -> Result<Waiters, ParseError> {
+        let json: Value = match serde_json::from_reader(fd) {
+            Ok(json) => json,
+            Err(_) => return Err(ParseError::SerdeError),
+        };
+        let obj = try!(json.as_object().ok_or(ParseError::ExpectedObject));
+        let waiters = try!(obj.get("waiters").and_then(|w| w.as_object())
+            .ok_or(ParseError::InvalidWaiter("waiters".to_string())));
+        let mut parsed = vec!();
+        for (name, raw) in waiters.iter() {
+            parsed.push(try!(Waiter::parse(name, raw)));
+        }
+        Ok(Waiters(parsed))
+    }
+}
+
+impl Waiter {
+    fn parse(name: &str, json: &Value) -> Result<Waiter, ParseError> {
+        let invalid = |field: &str| ParseError::InvalidWaiter(format!("{}: {}", name, field));
+        let obj = try!(json.as_object().ok_or(invalid("expected object")));
+        let operation = try!(obj.get("operation").and_then(|o| o.as_string()).ok_or(invalid("operation")));
+        let delay = try!(obj.get("delay").and_then(|d| d.as_i64()).ok_or(invalid("delay")));
+        let max_attempts = try!(obj.get("maxAttempts").and_then(|m| m.as_i64()).ok_or(invalid("maxAttempts")));
+        let description = match obj.get("description") {
+            Some(json) => Some(try!(json.as_string().ok_or(invalid("description"))).to_string()),
+            None => None,
+        };
+        let raw_acceptors = try!(obj.get("acceptors").and_then(|a| a.as_array()).ok_or(invalid("acceptors")));
+        let mut acceptors = vec!();
+        for raw in raw_acceptors {
+            let acceptor = try!(raw.as_object().ok_or(invalid("acceptors")));
+            acceptors.push(try!(Acceptor::parse(name, acceptor)));
+        }
+        Ok(Waiter {
+            name: name.to_string(),
+            operation: operation.to_string(),
+            delay: delay,
+            max_attempts: max_attempts,
+            description: description,
+            acceptors: acceptors,
+        })
+    }
+
+    /// Checks that the operation exists and that every path acceptor resolves
+    /// against its output shape.
+    pub fn validate(&self, service: &ServiceDefinition) -> Result<(), ParseError> {
+        let operation = try!(service.operation(&self.operation)
+            .ok_or(ParseError::UnknownOperation(self.operation.clone())));
+        for acceptor in &self.acceptors {
+            let argument = match acceptor.matcher {
+                Matcher::Path { ref argument, .. } |
+                Matcher::PathAll { ref argument, .. } |
+                Matcher::PathAny { ref argument, .. } => argument,
+                Matcher::Status(_) | Matcher::Error(_) => continue,
+            };
+            let output = try!(operation.output.as_ref().ok_or(ParseError::InvalidWaiter(
+                format!("{}: {} has no output to match a path against", self.name, self.operation))));
+            try!(argument.resolve(service, &output.shape)
+                .map_err(|message| ParseError::InvalidWaiter(format!("{}: {}", self.name, message))));
+        }
+        Ok(())
+    }
+}
+
+impl Acceptor {
+    fn parse(waiter: &str, obj: &BTreeMap<String, Value>) -> Result<Acceptor, ParseError> {
+        let invalid = |field: &str| ParseError::InvalidWaiter(format!("{}: acceptor {}", waiter, field));
+        let state = match obj.get("state").and_then(|s| s.as_string()) {
+            Some("success") => AcceptorState::Success,
+            Some("failure") => AcceptorState::Failure,
+            Some("retry") => AcceptorState::Retry,
+            _ => return Err(invalid("state")),
+        };
+        let expected = try!(obj.get("expected").ok_or(invalid("expected"))).clone();
+        let argument = || -> Result<Path, ParseError> {
+            let argument = try!(obj.get("argument").and_then(|a| a.as_string()).ok_or(invalid("argument")));
+            Path::parse(argument)
+        };
+        let matcher = match obj.get("matcher").and_then(|m| m.as_string()) {
+            Some("path") => Matcher::Path { argument: try!(argument()), expected: expected },
+            Some("pathAll") => Matcher::PathAll { argument: try!(argument()), expected: expected },
+            Some("pathAny") => Matcher::PathAny { argument: try!(argument()), expected: expected },
+            Some("status") => Matcher::Status(try!(expected.as_i64().ok_or(invalid("expected")))),
+            Some("error") => Matcher::Error(try!(expected.as_string().ok_or(invalid("expected"))).to_string()),
+            _ => return Err(invalid("matcher")),
+        };
+        Ok(Acceptor {
+            state: state,
+            matcher: matcher,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::Value;
+    use super::super::error::ParseError;
+    use super::super::path::Path;
+    use ::testhelpers::{fixture_reader, fixture_service};
+
+    fn waiters(fixture: &str) -> Waiters {
+        Waiters::parse(fixture_reader(&format!("waiters/{}", fixture))).unwrap()
+    }
+
+    #[test]
+    fn status_and_error() {
+        let Waiters(waiters) = waiters("lambda-2015-03-31");
+        assert_eq!(waiters.len(), 3);
+        assert_eq!(waiters[2], Waiter {
+            name: "FunctionExists".to_string(),
+            operation: "GetFunction".to_string(),
+            delay: 1,
+            max_attempts: 20,
+            description: None,
+            acceptors: vec!(
+                Acceptor { state: AcceptorState::Success, matcher: Matcher::Status(200) },
+                Acceptor {
+                    state: AcceptorState::Retry,
+                    matcher: Matcher::Error("ResourceNotFoundException".to_string()),
+                },
+            ),
+        });
+    }
+
+    #[test]
+    fn path_matchers() {
+        let Waiters(waiters) = waiters("lambda-2015-03-31");
+        assert_eq!(waiters[0].acceptors, vec!(
+            Acceptor {
+                state: AcceptorState::Success,
+                matcher: Matcher::PathAll {
+                    argument: Path::parse("Functions[].Runtime").unwrap(),
+                    expected: Value::String("java8".to_string()),
+                },
+            },
+            Acceptor {
+                state: AcceptorState::Failure,
+                matcher: Matcher::PathAny {
+                    argument: Path::parse("Functions[].MemorySize").unwrap(),
+                    expected: Value::U64(128),
+                },
+            },
+        ));
+        assert_eq!(waiters[1].description, Some("Waits for an event source mapping to finish enabling.".to_string()));
+    }
+
+    #[test]
+    fn validate_lambda() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(service.add_waiters(waiters("lambda-2015-03-31")), Ok(()));
+        assert_eq!(service.waiter("FunctionExists").unwrap().operation, "GetFunction");
+    }
+
+    #[test]
+    fn validate_unknown_operation() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(service.add_waiters(waiters("unknown-operation")),
+                   Err(ParseError::UnknownOperation("GetLayerVersion".to_string())));
+    }
+
+    #[test]
+    fn validate_unknown_path() {
+        let mut service = fixture_service("services/lambda-2015-03-31");
+        assert_eq!(service.add_waiters(waiters("unknown-path")), Err(ParseError::InvalidWaiter(
+            "FunctionActive: State is not a member of FunctionConfiguration".to_string())));
+        assert!(service.waiters.is_empty());
+    }
+}
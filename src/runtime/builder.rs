@@ -0,0 +1,32 @@
+use std::error::Error;
+use std::fmt;
+
+/// Returned by a request builder's `build()` when required members were not set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MissingMembers {
+    pub shape: &'static str,
+    pub members: Vec<&'static str>,
+}
+
+impl fmt::Display for MissingMembers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is missing required members: {}", self.shape, self.members.join(", "))
+    }
+}
+
+impl Error for MissingMembers {
+    fn description(&self) -> &str {
+        "a request is missing required members"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let err = MissingMembers { shape: "CreateAliasRequest", members: vec!("FunctionName", "Name") };
+        assert_eq!(err.to_string(), "CreateAliasRequest is missing required members: FunctionName, Name");
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+/// The base URL of a service endpoint, e.g. `https://lambda.us-east-1.amazonaws.com`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Url {
+    pub scheme: String,
+    pub host: String,
+}
+
+impl Url {
+    pub fn new(scheme: &str, host: &str) -> Url {
+        Url {
+            scheme: scheme.to_string(),
+            host: host.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Url {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}://{}", self.scheme, self.host)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let url = Url::new("https", "lambda.us-east-1.amazonaws.com");
+        assert_eq!(url.to_string(), "https://lambda.us-east-1.amazonaws.com");
+    }
+}
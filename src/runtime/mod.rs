@@ -0,0 +1,10 @@
+//! Support code that generated service clients depend on at runtime.
+
+pub mod builder;
+pub mod endpoint;
+pub mod hash;
+pub mod pattern;
+pub mod signature;
+pub mod stream;
+pub mod validate;
+pub mod waiter;
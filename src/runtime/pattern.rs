@@ -0,0 +1,420 @@
+//! Whole-string matching for the `pattern` constraints in service definitions.
+//!
+//! The patterns are Java regular expressions, and some rely on lookaround, such as
+//! `(?!^[0-9]+$)([a-zA-Z0-9-_]+)`, so this is a small backtracking matcher rather than
+//! a dependency. It understands literals, `.`, `\uXXXX`, classes, `\d \s \w` and their
+//! negations, groups with alternation, quantifiers, anchors, lookahead and lookbehind.
+
+#[derive(Debug, PartialEq)]
+enum Node {
+    Char(char),
+    Any,
+    Class(Vec<(char, char)>, bool),
+    Group(Vec<Vec<Node>>),
+    Repeat(Box<Node>, u32, Option<u32>),
+    Start,
+    End,
+    Look { behind: bool, negated: bool, alternatives: Vec<Vec<Node>> },
+}
+
+/// A compiled pattern.
+#[derive(Debug, PartialEq)]
+pub struct Pattern(Vec<Vec<Node>>);
+
+impl Pattern {
+    /// Fails with a description of the first construct that isn't supported.
+    pub fn new(pattern: &str) -> Result<Pattern, String> {
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut parser = Parser { chars: &chars, position: 0 };
+        let alternatives = try!(parser.alternatives());
+        if parser.position < chars.len() {
+            return Err(format!("unmatched ) at {}", parser.position));
+        }
+        Ok(Pattern(alternatives))
+    }
+
+    /// Whether all of `value` matches, as with Java's `String.matches()`.
+    pub fn is_match(&self, value: &str) -> bool {
+        let input: Vec<char> = value.chars().collect();
+        let matcher = Matcher { input: &input };
+        matcher.alternatives(&self.0, 0, &mut |end| end == input.len())
+    }
+}
+
+struct Parser<'a> {
+    chars: &'a [char],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).cloned()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        if c.is_some() {
+            self.position += 1;
+        }
+        c
+    }
+
+    fn eat(&mut self, expected: &str) -> bool {
+        let expected: Vec<char> = expected.chars().collect();
+        if self.chars[self.position..].starts_with(&expected) {
+            self.position += expected.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn number(&mut self) -> Option<u32> {
+        let start = self.position;
+        while self.peek().map_or(false, |c| c.is_digit(10)) {
+            self.position += 1;
+        }
+        let digits: String = self.chars[start..self.position].iter().cloned().collect();
+        digits.parse().ok()
+    }
+
+    fn alternatives(&mut self) -> Result<Vec<Vec<Node>>, String> {
+        let mut alternatives = vec!(try!(self.sequence()));
+        while self.eat("|") {
+            alternatives.push(try!(self.sequence()));
+        }
+        Ok(alternatives)
+    }
+
+    fn sequence(&mut self) -> Result<Vec<Node>, String> {
+        let mut nodes = vec!();
+        loop {
+            match self.peek() {
+                None | Some('|') | Some(')') => return Ok(nodes),
+                _ => {}
+            }
+            let atom = try!(self.atom());
+            nodes.push(try!(self.quantified(atom)));
+        }
+    }
+
+    fn atom(&mut self) -> Result<Node, String> {
+        match self.next() {
+            Some('.') => Ok(Node::Any),
+            Some('^') => Ok(Node::Start),
+            Some('$') => Ok(Node::End),
+            Some('[') => self.class(),
+            Some('(') => self.group(),
+            Some('\\') => {
+                let (ranges, negated) = try!(self.escape());
+                match single(&ranges) {
+                    Some(c) if !negated => Ok(Node::Char(c)),
+                    _ => Ok(Node::Class(ranges, negated)),
+                }
+            }
+            Some(c @ '*') | Some(c @ '+') | Some(c @ '?') | Some(c @ '{') => {
+                Err(format!("nothing to repeat before {} at {}", c, self.position - 1))
+            }
+            Some(c) => Ok(Node::Char(c)),
+            None => Err("unexpected end".to_string()),
+        }
+    }
+
+    /// The characters an escape stands for, and whether they are negated.
+    fn escape(&mut self) -> Result<(Vec<(char, char)>, bool), String> {
+        let digits = vec!(('0', '9'));
+        let spaces = vec!((' ', ' '), ('\t', '\r'));
+        let words = vec!(('a', 'z'), ('A', 'Z'), ('0', '9'), ('_', '_'));
+        Ok(match self.next() {
+            Some('d') => (digits, false),
+            Some('D') => (digits, true),
+            Some('s') => (spaces, false),
+            Some('S') => (spaces, true),
+            Some('w') => (words, false),
+            Some('W') => (words, true),
+            Some('t') => (vec!(('\t', '\t')), false),
+            Some('n') => (vec!(('\n', '\n')), false),
+            Some('r') => (vec!(('\r', '\r')), false),
+            Some('f') => (vec!(('\x0C', '\x0C')), false),
+            Some('u') => {
+                let start = self.position - 2;
+                let digits: String = self.chars[self.position..].iter().take(4).cloned().collect();
+                let hex = digits.len() == 4 && digits.chars().all(|c| c.is_digit(16));
+                let c = try!(u32::from_str_radix(&digits, 16).ok().and_then(::std::char::from_u32)
+                    .and_then(|c| if hex { Some(c) } else { None })
+                    .ok_or(format!("bad \\u escape at {}", start)));
+                self.position += 4;
+                (vec!((c, c)), false)
+            }
+            Some(c) if c.is_alphanumeric() => return Err(format!("unsupported escape \\{}", c)),
+            Some(c) => (vec!((c, c)), false),
+            None => return Err("trailing \\".to_string()),
+        })
+    }
+
+    fn class(&mut self) -> Result<Node, String> {
+        let negated = self.eat("^");
+        let mut ranges = vec!();
+        let mut first = true;
+        loop {
+            let c = try!(self.next().ok_or("unterminated [".to_string()));
+            if c == ']' && !first {
+                break;
+            }
+            first = false;
+            if c == '[' {
+                return Err(format!("nested class at {}", self.position - 1));
+            }
+            let low = if c == '\\' {
+                let (escaped, negated) = try!(self.escape());
+                if negated {
+                    return Err(format!("negated escape inside a class at {}", self.position - 2));
+                }
+                match single(&escaped) {
+                    Some(low) => low,
+                    None => {
+                        ranges.extend(escaped);
+                        continue;
+                    }
+                }
+            } else {
+                c
+            };
+            let is_range = self.peek() == Some('-') &&
+                           self.chars.get(self.position + 1).map_or(false, |&c| c != ']');
+            if is_range {
+                self.position += 1;
+                let high = match try!(self.next().ok_or("unterminated [".to_string())) {
+                    '\\' => {
+                        let (escaped, negated) = try!(self.escape());
+                        try!(single(&escaped).and_then(|c| if negated { None } else { Some(c) })
+                            .ok_or(format!("bad range at {}", self.position - 2)))
+                    }
+                    c => c,
+                };
+                if high < low {
+                    return Err(format!("bad range {}-{}", low, high));
+                }
+                ranges.push((low, high));
+            } else {
+                ranges.push((low, low));
+            }
+        }
+        Ok(Node::Class(ranges, negated))
+    }
+
+    fn group(&mut self) -> Result<Node, String> {
+        let look = if self.eat("?:") {
+            None
+        } else if self.eat("?=") {
+            Some((false, false))
+        } else if self.eat("?!") {
+            Some((false, true))
+        } else if self.eat("?<=") {
+            Some((true, false))
+        } else if self.eat("?<!") {
+            Some((true, true))
+        } else if self.peek() == Some('?') {
+            return Err(format!("unsupported group at {}", self.position));
+        } else {
+            None
+        };
+        let alternatives = try!(self.alternatives());
+        if !self.eat(")") {
+            return Err("unterminated (".to_string());
+        }
+        Ok(match look {
+            Some((behind, negated)) => Node::Look { behind: behind, negated: negated, alternatives: alternatives },
+            None => Node::Group(alternatives),
+        })
+    }
+
+    fn quantified(&mut self, atom: Node) -> Result<Node, String> {
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                let start = self.position;
+                self.position += 1;
+                let min = try!(self.number().ok_or(format!("bad repetition at {}", start)));
+                let max = if self.eat(",") { self.number() } else { Some(min) };
+                if self.peek() != Some('}') || max.map_or(false, |max| max < min) {
+                    return Err(format!("bad repetition at {}", start));
+                }
+                (min, max)
+            }
+            _ => return Ok(atom),
+        };
+        self.position += 1;
+        if self.peek() == Some('+') {
+            return Err(format!("possessive quantifier at {}", self.position));
+        }
+        // Laziness changes which match is found, not whether there is one.
+        self.eat("?");
+        Ok(Node::Repeat(Box::new(atom), min, max))
+    }
+}
+
+/// The character an escape stands for, if it is just the one.
+fn single(ranges: &[(char, char)]) -> Option<char> {
+    if ranges.len() == 1 && ranges[0].0 == ranges[0].1 {
+        Some(ranges[0].0)
+    } else {
+        None
+    }
+}
+
+struct Matcher<'a> {
+    input: &'a [char],
+}
+
+impl<'a> Matcher<'a> {
+    fn alternatives(&self, alternatives: &[Vec<Node>], position: usize, next: &mut FnMut(usize) -> bool) -> bool {
+        for sequence in alternatives {
+            if self.sequence(sequence, position, next) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn sequence(&self, nodes: &[Node], position: usize, next: &mut FnMut(usize) -> bool) -> bool {
+        match nodes.split_first() {
+            None => next(position),
+            Some((node, rest)) => self.node(node, position, &mut |after| self.sequence(rest, after, &mut *next)),
+        }
+    }
+
+    fn node(&self, node: &Node, position: usize, next: &mut FnMut(usize) -> bool) -> bool {
+        match *node {
+            Node::Char(_) | Node::Any | Node::Class(..) => match self.input.get(position) {
+                Some(&c) => matches_char(node, c) && next(position + 1),
+                None => false,
+            },
+            Node::Group(ref alternatives) => self.alternatives(alternatives, position, next),
+            Node::Repeat(ref node, min, max) => self.repeat(node, min, max, 0, position, next),
+            Node::Start => position == 0 && next(position),
+            Node::End => position == self.input.len() && next(position),
+            Node::Look { behind, negated, ref alternatives } => {
+                let found = if behind {
+                    (0..position + 1).any(|start| self.alternatives(alternatives, start, &mut |end| end == position))
+                } else {
+                    self.alternatives(alternatives, position, &mut |_| true)
+                };
+                found != negated && next(position)
+            }
+        }
+    }
+
+    fn repeat(&self, node: &Node, min: u32, max: Option<u32>, count: u32, position: usize,
+              next: &mut FnMut(usize) -> bool) -> bool {
+        if let Node::Char(_) | Node::Any | Node::Class(..) = *node {
+            return self.repeat_char(node, min, max, position, next);
+        }
+        if max.map_or(true, |max| count < max) {
+            // An empty match only counts towards the minimum, or this would never end.
+            let matched = self.node(node, position, &mut |after| {
+                (after != position || count < min) && self.repeat(node, min, max, count + 1, after, &mut *next)
+            });
+            if matched {
+                return true;
+            }
+        }
+        count >= min && next(position)
+    }
+
+    /// `repeat` for a node matching one character: takes as many as it can, then
+    /// gives them back one at a time, so long inputs need neither deep recursion nor
+    /// a closure per character.
+    fn repeat_char(&self, node: &Node, min: u32, max: Option<u32>, position: usize,
+                   next: &mut FnMut(usize) -> bool) -> bool {
+        let mut end = position;
+        while max.map_or(true, |max| end - position < max as usize) &&
+              self.input.get(end).map_or(false, |&c| matches_char(node, c)) {
+            end += 1;
+        }
+        let min = position + min as usize;
+        while end >= min {
+            if next(end) {
+                return true;
+            }
+            if end == position {
+                break;
+            }
+            end -= 1;
+        }
+        false
+    }
+}
+
+/// Whether `c` matches a `Char`, `Any` or `Class` node.
+fn matches_char(node: &Node, c: char) -> bool {
+    match *node {
+        Node::Char(expected) => c == expected,
+        Node::Any => c != '\n' && c != '\r',
+        Node::Class(ref ranges, negated) => ranges.iter().any(|&(low, high)| low <= c && c <= high) != negated,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn matches(pattern: &str, value: &str) -> bool {
+        Pattern::new(pattern).unwrap().is_match(value)
+    }
+
+    #[test]
+    fn whole_string() {
+        assert!(matches("\\d{12}", "123456789012"));
+        assert!(!matches("\\d{12}", "1234567890123"));
+        assert!(!matches("[^\\s]+", "two words"));
+        assert!(matches("(|[a-zA-Z0-9$_]+)", ""));
+        assert!(matches("(\\$LATEST|[0-9]+)", "$LATEST"));
+        assert!(!matches("(\\$LATEST|[0-9]+)", "LATEST"));
+    }
+
+    #[test]
+    fn lambda_patterns() {
+        let function_name = "(arn:aws:lambda:)?([a-z]{2}-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?";
+        assert!(matches(function_name, "my-function"));
+        assert!(matches(function_name, "arn:aws:lambda:us-west-2:123456789012:function:my-function:$LATEST"));
+        assert!(!matches(function_name, "my function"));
+        let role = "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+";
+        assert!(matches(role, "arn:aws:iam::123456789012:role/service-role/lambda"));
+        assert!(!matches(role, "arn:aws:iam::12345:role/lambda"));
+    }
+
+    #[test]
+    fn lookaround() {
+        let alias = "(?!^[0-9]+$)([a-zA-Z0-9-_]+)";
+        assert!(matches(alias, "prod"));
+        assert!(matches(alias, "v2"));
+        assert!(!matches(alias, "42"));
+        let s3_key = "^[0-9A-Za-z\\.\\-_]*(?<!\\.)$";
+        assert!(matches(s3_key, "lambda.zip"));
+        assert!(!matches(s3_key, "lambda."));
+    }
+
+    #[test]
+    fn long_input() {
+        let value: String = (0..100000).map(|i| (0x20 + (i % 0xE0) as u8) as char).collect();
+        assert!(matches("[\\u0020-\\u00FF]+", &value));
+        assert!(!matches("[\\u0020-\\u00FF]+", &format!("{}\u{100}", value)));
+        let letters: String = (0..100000).map(|_| 'a').collect();
+        assert!(matches("[a-z]*b", &format!("{}b", letters)));
+        assert!(!matches("a*b", &letters));
+    }
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(Pattern::new("\\p{Alpha}+"), Err("unsupported escape \\p".to_string()));
+        assert_eq!(Pattern::new("\\u00G0"), Err("bad \\u escape at 0".to_string()));
+        assert_eq!(Pattern::new("a++"), Err("possessive quantifier at 2".to_string()));
+        assert_eq!(Pattern::new("(?i)abc"), Err("unsupported group at 1".to_string()));
+        assert_eq!(Pattern::new("(abc"), Err("unterminated (".to_string()));
+        assert_eq!(Pattern::new("abc)"), Err("unmatched ) at 3".to_string()));
+    }
+}
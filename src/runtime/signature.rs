@@ -0,0 +1,287 @@
+//! AWS Signature Version 4 request signing.
+//!
+//! See http://docs.aws.amazon.com/general/latest/gr/signature-version-4.html for the
+//! algorithm. Requests are built up as a `SignedRequest`, then `sign()` adds the
+//! `Authorization` header (and `X-Amz-Date` / `X-Amz-Security-Token` as needed).
+
+use std::collections::BTreeMap;
+use super::hash::{sha256, hmac_sha256, to_hex};
+
+const ALGORITHM: &'static str = "AWS4-HMAC-SHA256";
+
+/// The AWS credentials used to sign a request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Credentials {
+    pub access_key: String,
+    pub secret_key: String,
+    pub token: Option<String>,
+}
+
+impl Credentials {
+    pub fn new(access_key: &str, secret_key: &str) -> Credentials {
+        Credentials {
+            access_key: access_key.to_string(),
+            secret_key: secret_key.to_string(),
+            token: None,
+        }
+    }
+}
+
+/// An HTTP request that has not been sent yet.
+///
+/// Header names are stored lowercased since SigV4 treats them case-insensitively.
+/// `path` is the unencoded request path; it is URI encoded when signing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignedRequest {
+    pub method: String,
+    pub path: String,
+    pub params: Vec<(String, String)>,
+    pub headers: BTreeMap<String, Vec<String>>,
+    pub payload: Vec<u8>,
+}
+
+impl SignedRequest {
+    pub fn new(method: &str, path: &str) -> SignedRequest {
+        SignedRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            params: vec!(),
+            headers: BTreeMap::new(),
+            payload: vec!(),
+        }
+    }
+
+    pub fn add_param(&mut self, key: &str, value: &str) {
+        self.params.push((key.to_string(), value.to_string()));
+    }
+
+    pub fn add_header(&mut self, name: &str, value: &str) {
+        self.headers.entry(name.to_lowercase()).or_insert(vec!()).push(value.to_string());
+    }
+
+    pub fn set_payload(&mut self, payload: &[u8]) {
+        self.payload = payload.to_vec();
+    }
+
+    /// Step 1: the canonical form of this request.
+    pub fn canonical_request(&self) -> String {
+        format!("{}\n{}\n{}\n{}\n{}\n{}",
+                self.method,
+                canonical_uri(&self.path),
+                self.canonical_query_string(),
+                self.canonical_headers(),
+                self.signed_headers(),
+                to_hex(&sha256(&self.payload)))
+    }
+
+    /// Step 2: the string to sign for `date` (formatted as `20150830T123600Z`).
+    pub fn string_to_sign(&self, date: &str, scope: &Scope) -> String {
+        format!("{}\n{}\n{}\n{}",
+                ALGORITHM,
+                date,
+                scope,
+                to_hex(&sha256(self.canonical_request().as_bytes())))
+    }
+
+    /// Step 3 and 4: calculates the signature and returns the `Authorization` header value.
+    pub fn authorization(&self, credentials: &Credentials, date: &str, scope: &Scope) -> String {
+        let key = scope.signing_key(&credentials.secret_key);
+        let signature = hmac_sha256(&key, self.string_to_sign(date, scope).as_bytes());
+        format!("{} Credential={}/{}, SignedHeaders={}, Signature={}",
+                ALGORITHM,
+                credentials.access_key,
+                scope,
+                self.signed_headers(),
+                to_hex(&signature))
+    }
+
+    /// Signs this request in place for `service` in `region` at `date`.
+    pub fn sign(&mut self, credentials: &Credentials, region: &str, service: &str, date: &str) {
+        if !self.headers.contains_key("x-amz-date") {
+            self.add_header("X-Amz-Date", date);
+        }
+        if let Some(ref token) = credentials.token {
+            if !self.headers.contains_key("x-amz-security-token") {
+                self.add_header("X-Amz-Security-Token", token);
+            }
+        }
+        self.headers.remove("authorization");
+        let scope = Scope::new(date, region, service);
+        let authorization = self.authorization(credentials, date, &scope);
+        self.add_header("Authorization", &authorization);
+    }
+
+    fn canonical_query_string(&self) -> String {
+        let mut params: Vec<(String, String)> = self.params.iter()
+            .map(|&(ref k, ref v)| (uri_encode(k, true), uri_encode(v, true)))
+            .collect();
+        params.sort();
+        let pairs: Vec<String> = params.iter().map(|&(ref k, ref v)| format!("{}={}", k, v)).collect();
+        pairs.join("&")
+    }
+
+    fn canonical_headers(&self) -> String {
+        let mut canonical = String::new();
+        for (name, values) in self.headers.iter() {
+            let values: Vec<String> = values.iter().map(|v| canonical_header_value(v)).collect();
+            canonical.push_str(&format!("{}:{}\n", name, values.join(",")));
+        }
+        canonical
+    }
+
+    fn signed_headers(&self) -> String {
+        let names: Vec<&str> = self.headers.keys().map(|k| &k[..]).collect();
+        names.join(";")
+    }
+}
+
+/// The credential scope: `<date>/<region>/<service>/aws4_request`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scope {
+    pub date: String,
+    pub region: String,
+    pub service: String,
+}
+
+impl Scope {
+    /// Builds a scope from a full `20150830T123600Z` timestamp (only the date is kept).
+    pub fn new(date: &str, region: &str, service: &str) -> Scope {
+        Scope {
+            date: date.chars().take(8).collect(),
+            region: region.to_string(),
+            service: service.to_string(),
+        }
+    }
+
+    /// Derives the signing key from the secret access key.
+    pub fn signing_key(&self, secret_key: &str) -> [u8; 32] {
+        let k_secret = format!("AWS4{}", secret_key);
+        let k_date = hmac_sha256(k_secret.as_bytes(), self.date.as_bytes());
+        let k_region = hmac_sha256(&k_date, self.region.as_bytes());
+        let k_service = hmac_sha256(&k_region, self.service.as_bytes());
+        hmac_sha256(&k_service, b"aws4_request")
+    }
+}
+
+impl ::std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}/{}/{}/aws4_request", self.date, self.region, self.service)
+    }
+}
+
+fn canonical_uri(path: &str) -> String {
+    if path.is_empty() {
+        return "/".to_string();
+    }
+    uri_encode(path, false)
+}
+
+/// Trims the value and collapses sequential spaces into one.
+fn canonical_header_value(value: &str) -> String {
+    let words: Vec<&str> = value.split_whitespace().collect();
+    words.join(" ")
+}
+
+/// Percent-encodes everything except the unreserved characters `A-Za-z0-9-_.~`.
+/// `/` is left alone unless `encode_slash` is true.
+pub fn uri_encode(input: &str, encode_slash: bool) -> String {
+    let mut encoded = String::new();
+    for byte in input.bytes() {
+        match byte {
+            b'A'...b'Z' | b'a'...b'z' | b'0'...b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::testhelpers::fixture_string;
+
+    const DATE: &'static str = "20150830T123600Z";
+
+    fn credentials() -> Credentials {
+        Credentials::new("AKIDEXAMPLE", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY")
+    }
+
+    fn scope() -> Scope {
+        Scope::new(DATE, "us-east-1", "service")
+    }
+
+    /// Reads a raw `.req` file from the SigV4 test suite into a `SignedRequest`.
+    fn request(vector: &str) -> SignedRequest {
+        let raw = fixture_string(&format!("sigv4/{0}/{0}.req", vector));
+        let (head, body) = match raw.find("\n\n") {
+            Some(i) => (&raw[..i], &raw[i + 2..]),
+            None => (&raw[..], ""),
+        };
+        let mut lines = head.lines();
+        let request_line = lines.next().unwrap();
+        let method_end = request_line.find(' ').unwrap();
+        let target_end = request_line.rfind(' ').unwrap();
+        let target = &request_line[method_end + 1..target_end];
+        let (path, query) = match target.find('?') {
+            Some(i) => (&target[..i], &target[i + 1..]),
+            None => (target, ""),
+        };
+
+        let mut request = SignedRequest::new(&request_line[..method_end], path);
+        for pair in query.split('&').filter(|p| !p.is_empty()) {
+            let mut kv = pair.splitn(2, '=');
+            request.add_param(kv.next().unwrap(), kv.next().unwrap_or(""));
+        }
+        for line in lines {
+            let colon = line.find(':').unwrap();
+            request.add_header(&line[..colon], &line[colon + 1..]);
+        }
+        request.set_payload(body.as_bytes());
+        request
+    }
+
+    fn expected(vector: &str, extension: &str) -> String {
+        fixture_string(&format!("sigv4/{0}/{0}.{1}", vector, extension))
+    }
+
+    macro_rules! vector {
+        ($test:ident, $vector:expr) => {
+            #[test]
+            fn $test() {
+                let request = request($vector);
+                assert_eq!(request.canonical_request(), expected($vector, "creq"));
+                assert_eq!(request.string_to_sign(DATE, &scope()), expected($vector, "sts"));
+                assert_eq!(request.authorization(&credentials(), DATE, &scope()), expected($vector, "authz"));
+            }
+        };
+    }
+
+    vector!(get_vanilla, "get-vanilla");
+    vector!(get_vanilla_query_order_key_case, "get-vanilla-query-order-key-case");
+    vector!(get_vanilla_query_unreserved, "get-vanilla-query-unreserved");
+    vector!(get_header_value_trim, "get-header-value-trim");
+    vector!(get_utf8, "get-utf8");
+    vector!(post_vanilla, "post-vanilla");
+    vector!(post_x_www_form_urlencoded, "post-x-www-form-urlencoded");
+
+    #[test]
+    fn sign_adds_authorization() {
+        let mut request = request("get-vanilla");
+        request.sign(&credentials(), "us-east-1", "service", DATE);
+        assert_eq!(request.headers.get("authorization"), Some(&vec!(expected("get-vanilla", "authz"))));
+    }
+
+    #[test]
+    fn sign_adds_date_and_token() {
+        let mut request = SignedRequest::new("GET", "/");
+        request.add_header("Host", "example.amazonaws.com");
+        let mut credentials = credentials();
+        credentials.token = Some("session".to_string());
+        request.sign(&credentials, "us-east-1", "service", DATE);
+        assert_eq!(request.headers.get("x-amz-date"), Some(&vec!(DATE.to_string())));
+        assert_eq!(request.headers.get("x-amz-security-token"), Some(&vec!("session".to_string())));
+        assert!(request.headers.get("authorization").unwrap()[0]
+                .contains("SignedHeaders=host;x-amz-date;x-amz-security-token,"));
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt;
+use std::io::{self, Cursor, Read};
+
+/// The body of a streaming payload, such as S3 `GetObject.Body`. It is read as the
+/// bytes arrive instead of being collected into memory first.
+pub struct ByteStream(Box<Read + Send>);
+
+impl ByteStream {
+    pub fn new<R: Read + Send + 'static>(reader: R) -> ByteStream {
+        ByteStream(Box::new(reader))
+    }
+}
+
+impl Read for ByteStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl Default for ByteStream {
+    fn default() -> ByteStream {
+        ByteStream::new(io::empty())
+    }
+}
+
+impl From<Vec<u8>> for ByteStream {
+    fn from(bytes: Vec<u8>) -> ByteStream {
+        ByteStream::new(Cursor::new(bytes))
+    }
+}
+
+impl fmt::Debug for ByteStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ByteStream")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn reads_through() {
+        let mut stream = ByteStream::from(b"streamed".to_vec());
+        let mut body = String::new();
+        stream.read_to_string(&mut body).unwrap();
+        assert_eq!(body, "streamed");
+    }
+
+    #[test]
+    fn default_is_empty() {
+        let mut body = vec!();
+        ByteStream::default().read_to_end(&mut body).unwrap();
+        assert!(body.is_empty());
+    }
+}
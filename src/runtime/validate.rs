@@ -0,0 +1,216 @@
+//! The checks behind the generated `validate()` methods, which report every way a
+//! request breaks its shapes' constraints before it is sent.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::rc::Rc;
+use super::pattern::Pattern;
+
+thread_local! {
+    /// The patterns compiled so far, or `None` for those `Pattern` does not
+    /// understand, so that each is parsed once rather than on every check.
+    static PATTERNS: RefCell<HashMap<String, Option<Rc<Pattern>>>> = RefCell::new(HashMap::new());
+}
+
+/// One broken constraint, e.g. `Code.S3Key: length 0 < min 1`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    /// The member path from the request, such as `Code.S3Key` or `Items[2].Name`.
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Every constraint a request broke, in member order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violations(pub Vec<Violation>);
+
+impl fmt::Display for Violations {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let violations: Vec<String> = self.0.iter().map(|violation| violation.to_string()).collect();
+        write!(f, "{}", violations.join("; "))
+    }
+}
+
+impl Error for Violations {
+    fn description(&self) -> &str {
+        "a request breaks the constraints of its shapes"
+    }
+}
+
+/// Implemented for the structures that operation inputs are made of.
+pub trait Validate {
+    /// Adds a violation for each constraint broken by this value, found at `path`.
+    fn check(&self, path: &str, violations: &mut Vec<Violation>);
+}
+
+impl<T: Validate> Validate for Box<T> {
+    fn check(&self, path: &str, violations: &mut Vec<Violation>) {
+        (**self).check(path, violations)
+    }
+}
+
+/// Checks a whole request.
+pub fn validate<T: Validate>(request: &T) -> Result<(), Violations> {
+    let mut violations = vec!();
+    request.check("", &mut violations);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(Violations(violations))
+    }
+}
+
+/// The path of `member` within the structure at `path`.
+pub fn member(path: &str, member: &str) -> String {
+    if path.is_empty() {
+        member.to_string()
+    } else {
+        format!("{}.{}", path, member)
+    }
+}
+
+/// The path of element `index` of the list at `path`.
+pub fn index(path: &str, index: usize) -> String {
+    format!("{}[{}]", path, index)
+}
+
+/// Reports a required member that was not set.
+pub fn missing(path: &str, name: &str, violations: &mut Vec<Violation>) {
+    violations.push(violation(&member(path, name), "required but not set".to_string()));
+}
+
+/// Checks the length of a string, in characters, or of a list.
+pub fn length(path: &str, length: usize, min: Option<i64>, max: Option<i64>, violations: &mut Vec<Violation>) {
+    let length = length as i64;
+    if let Some(min) = min {
+        if length < min {
+            violations.push(violation(path, format!("length {} < min {}", length, min)));
+        }
+    }
+    if let Some(max) = max {
+        if length > max {
+            violations.push(violation(path, format!("length {} > max {}", length, max)));
+        }
+    }
+}
+
+/// Checks a number against its bounds.
+pub fn range(path: &str, value: i64, min: Option<i64>, max: Option<i64>, violations: &mut Vec<Violation>) {
+    if let Some(min) = min {
+        if value < min {
+            violations.push(violation(path, format!("{} < min {}", value, min)));
+        }
+    }
+    if let Some(max) = max {
+        if value > max {
+            violations.push(violation(path, format!("{} > max {}", value, max)));
+        }
+    }
+}
+
+/// Checks that all of a string matches `pattern`. The generator only emits this
+/// for patterns that `Pattern` understands.
+pub fn pattern(path: &str, value: &str, pattern: &str, violations: &mut Vec<Violation>) {
+    let compiled = PATTERNS.with(|patterns| {
+        let mut patterns = patterns.borrow_mut();
+        if !patterns.contains_key(pattern) {
+            patterns.insert(pattern.to_string(), Pattern::new(pattern).ok().map(Rc::new));
+        }
+        patterns[pattern].clone()
+    });
+    if let Some(compiled) = compiled {
+        if !compiled.is_match(value) {
+            violations.push(violation(path, format!("{:?} does not match {}", value, pattern)));
+        }
+    }
+}
+
+fn violation(path: &str, message: String) -> Violation {
+    Violation {
+        path: path.to_string(),
+        message: message,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct FunctionCode {
+        s3_key: Option<String>,
+    }
+
+    impl Validate for FunctionCode {
+        fn check(&self, path: &str, violations: &mut Vec<Violation>) {
+            if let Some(ref value) = self.s3_key {
+                let path = member(path, "S3Key");
+                length(&path, value.chars().count(), Some(1), Some(1024), violations);
+            }
+        }
+    }
+
+    struct CreateFunctionRequest {
+        code: Box<FunctionCode>,
+        function_name: String,
+        memory_size: Option<i32>,
+    }
+
+    impl Validate for CreateFunctionRequest {
+        fn check(&self, path: &str, violations: &mut Vec<Violation>) {
+            {
+                let value = &self.code;
+                let path = member(path, "Code");
+                Validate::check(value, &path, violations);
+            }
+            {
+                let value = &self.function_name;
+                let path = member(path, "FunctionName");
+                pattern(&path, value, "[a-zA-Z0-9-_]+", violations);
+            }
+            if let Some(ref value) = self.memory_size {
+                let path = member(path, "MemorySize");
+                range(&path, *value as i64, Some(128), Some(1536), violations);
+            }
+        }
+    }
+
+    #[test]
+    fn every_violation() {
+        let request = CreateFunctionRequest {
+            code: Box::new(FunctionCode { s3_key: Some(String::new()) }),
+            function_name: "my function".to_string(),
+            memory_size: Some(64),
+        };
+        let err = validate(&request).unwrap_err();
+        assert_eq!(err.to_string(), "Code.S3Key: length 0 < min 1; \
+                                     FunctionName: \"my function\" does not match [a-zA-Z0-9-_]+; \
+                                     MemorySize: 64 < min 128");
+    }
+
+    #[test]
+    fn valid() {
+        let request = CreateFunctionRequest {
+            code: Box::new(FunctionCode { s3_key: None }),
+            function_name: "my-function".to_string(),
+            memory_size: None,
+        };
+        assert_eq!(validate(&request), Ok(()));
+    }
+
+    #[test]
+    fn list_paths() {
+        let mut violations = vec!();
+        length(&index(&member("", "SubnetIds"), 2), 0, Some(1), None, &mut violations);
+        assert_eq!(violations[0].to_string(), "SubnetIds[2]: length 0 < min 1");
+        missing(&member("", "Code"), "Runtime", &mut violations);
+        assert_eq!(violations[1].to_string(), "Code.Runtime: required but not set");
+    }
+}
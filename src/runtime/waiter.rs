@@ -0,0 +1,91 @@
+//! Polling support for generated `wait_until_*` helpers.
+
+use std::thread;
+use std::time::Duration;
+
+/// What a waiter acceptor decided about one attempt.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum State {
+    Success,
+    Failure,
+    Retry,
+}
+
+/// Lets acceptors with `status` and `error` matchers inspect a failed call.
+pub trait ServiceError {
+    /// The AWS error code, e.g. `ResourceNotFoundException`.
+    fn code(&self) -> &str;
+    /// The HTTP status code of the response.
+    fn status(&self) -> u16;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum WaiterError<E> {
+    /// An acceptor moved the waiter into its failure state.
+    Failure,
+    /// The call failed with an error no acceptor matched.
+    Service(E),
+    /// `max_attempts` calls were made without reaching a final state.
+    TimedOut,
+}
+
+/// Calls `attempt` up to `max_attempts` times, sleeping `delay` seconds in between,
+/// until `accept` reports success or failure. A result no acceptor matches is
+/// retried when it succeeded and returned as `WaiterError::Service` when it failed.
+pub fn wait<T, E, F, A>(delay: u64, max_attempts: u32, mut attempt: F, accept: A) -> Result<(), WaiterError<E>>
+    where F: FnMut() -> Result<T, E>,
+          A: Fn(&Result<T, E>) -> Option<State>
+{
+    for n in 0..max_attempts {
+        if n > 0 {
+            thread::sleep(Duration::from_secs(delay));
+        }
+        let result = attempt();
+        let state = accept(&result);
+        match (state, result) {
+            (Some(State::Success), _) => return Ok(()),
+            (Some(State::Failure), _) => return Err(WaiterError::Failure),
+            (None, Err(err)) => return Err(WaiterError::Service(err)),
+            (Some(State::Retry), _) | (None, Ok(_)) => {},
+        }
+    }
+    Err(WaiterError::TimedOut)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn success_after_retries() {
+        let mut calls = 0;
+        let result = wait(0, 5, || { calls += 1; Ok::<u32, ()>(calls) }, |result| {
+            match *result {
+                Ok(3) => Some(State::Success),
+                _ => None,
+            }
+        });
+        assert_eq!(result, Ok(()));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn failure() {
+        let result = wait(0, 5, || Ok::<u32, ()>(1), |_| Some(State::Failure));
+        assert_eq!(result, Err(WaiterError::Failure));
+    }
+
+    #[test]
+    fn unmatched_error() {
+        let result = wait(0, 5, || Err::<u32, &str>("boom"), |_| None);
+        assert_eq!(result, Err(WaiterError::Service("boom")));
+    }
+
+    #[test]
+    fn matched_error_is_retried() {
+        let mut calls = 0;
+        let result = wait(0, 4, || { calls += 1; Err::<u32, &str>("missing") }, |_| Some(State::Retry));
+        assert_eq!(result, Err(WaiterError::TimedOut));
+        assert_eq!(calls, 4);
+    }
+}
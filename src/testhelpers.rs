@@ -5,6 +5,7 @@ use serde_json::Value;
 use std::fs::File;
 use std::collections::BTreeMap;
 use std::io::Read;
+use ::parser::ServiceDefinition;
 
 /// Returns a File for a given path (relative to project src/fixtures dir).
 /// This method panics on error, which is exactly should happen in tests.
@@ -41,3 +42,8 @@ pub fn fixture_string(path: &str) -> String {
     fd.read_to_string(&mut fixture).unwrap();
     fixture
 }
+
+/// Parses a service definition fixture or panics.
+pub fn fixture_service(path: &str) -> ServiceDefinition {
+    ServiceDefinition::parse(fixture_reader(path)).unwrap()
+}